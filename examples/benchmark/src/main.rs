@@ -0,0 +1,330 @@
+//! Measure the frame times of the renderer with a set of heavy scenes.
+//!
+//! Every scene is laid out, drawn, and rendered offscreen for a number of
+//! frames. Then, the time spent in each stage is reported, together with the
+//! rendering statistics of the last frame.
+//!
+//! The `render` stage measures the time spent recording the GPU commands of a
+//! frame, while the `submit` stage measures the time the GPU takes to run
+//! them. The same offscreen texture is reused for every frame, and pixels are
+//! never read back while measuring.
+//!
+//! Run it in release mode to obtain meaningful numbers:
+//!
+//! ```text
+//! cargo run --release --package benchmark -- [SCENE]... [--frames N]
+//! ```
+use iced_native::{
+    Background, Cache, Color, Column, Element, Length, Point, Row, Size, Space,
+    Text, UserInterface,
+};
+use iced_wgpu::{
+    canvas::{self, Canvas, Fill, Frame, Path},
+    container,
+    offscreen::Offscreen,
+    triangle, Container, Renderer, Settings,
+};
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const SIZE: Size = Size {
+    width: 1024.0,
+    height: 768.0,
+};
+
+const DEFAULT_FRAMES: usize = 200;
+
+pub fn main() {
+    let mut scenes = Vec::new();
+    let mut frames = DEFAULT_FRAMES;
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        if arg == "--frames" {
+            frames = args
+                .next()
+                .and_then(|frames| frames.parse().ok())
+                .expect("--frames expects a number");
+        } else {
+            match Scene::ALL.iter().find(|scene| scene.name() == arg) {
+                Some(scene) => scenes.push(*scene),
+                None => {
+                    eprintln!("Unknown scene: {}", arg);
+                    eprintln!("Available scenes:");
+
+                    for scene in Scene::ALL {
+                        eprintln!("  {}", scene.name());
+                    }
+
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+
+    if scenes.is_empty() {
+        scenes.extend_from_slice(Scene::ALL);
+    }
+
+    let mut offscreen = Offscreen::new(Settings::default())
+        .expect("No graphics adapter available");
+
+    for scene in scenes {
+        let report = scene.run(&mut offscreen, frames);
+
+        println!("{}", report);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Scene {
+    Quads,
+    Meshes,
+    Text,
+    Nesting,
+}
+
+impl Scene {
+    const ALL: &'static [Scene] =
+        &[Scene::Quads, Scene::Meshes, Scene::Text, Scene::Nesting];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Scene::Quads => "quads",
+            Scene::Meshes => "meshes",
+            Scene::Text => "text",
+            Scene::Nesting => "nesting",
+        }
+    }
+
+    fn view<'a>(&self, frame: usize) -> Element<'a, (), Renderer> {
+        match self {
+            Scene::Quads => {
+                // 80 x 60 = 4800 quads
+                (0..60)
+                    .fold(Column::new(), |column, i| {
+                        column.push((0..80).fold(Row::new(), |row, j| {
+                            row.push(
+                                Container::new(Space::new(
+                                    Length::Units(10),
+                                    Length::Units(10),
+                                ))
+                                .style(Cell((i + j + frame) % 10)),
+                            )
+                        }))
+                    })
+                    .spacing(2)
+                    .into()
+            }
+            Scene::Meshes => Canvas::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .push(Circles { frame })
+                .into(),
+            Scene::Text => (0..50)
+                .fold(Column::new(), |column, i| {
+                    column.push(
+                        Text::new(format!(
+                            "{:>4} {}",
+                            frame + i,
+                            "The quick brown fox jumps over the lazy dog. "
+                                .repeat(3)
+                        ))
+                        .size(14),
+                    )
+                })
+                .into(),
+            Scene::Nesting => (0..200).fold(
+                Text::new(frame.to_string()).into(),
+                |content: Element<'a, (), Renderer>, i| {
+                    if i % 2 == 0 {
+                        Column::new().padding(1).push(content).into()
+                    } else {
+                        Container::new(content).padding(1).into()
+                    }
+                },
+            ),
+        }
+    }
+
+    fn run(&self, offscreen: &mut Offscreen, frames: usize) -> Report {
+        let size = (SIZE.width as u32, SIZE.height as u32);
+
+        let mut layout = Vec::with_capacity(frames);
+        let mut draw = Vec::with_capacity(frames);
+        let mut render = Vec::with_capacity(frames);
+        let mut submit = Vec::with_capacity(frames);
+
+        for frame in 0..frames {
+            let output = {
+                let renderer = offscreen.renderer_mut();
+
+                let start = Instant::now();
+                let user_interface = UserInterface::build(
+                    self.view(frame),
+                    SIZE,
+                    Cache::default(),
+                    renderer,
+                );
+                layout.push(start.elapsed());
+
+                let start = Instant::now();
+                let output = user_interface.draw(renderer);
+                draw.push(start.elapsed());
+
+                output
+            };
+
+            let start = Instant::now();
+            offscreen.draw(&output, size, 1.0);
+            render.push(start.elapsed());
+
+            let start = Instant::now();
+            offscreen.submit();
+            submit.push(start.elapsed());
+        }
+
+        // Read the last frame back outside of the measurements, so the pixels
+        // are really produced without making the benchmark pay for the copy
+        let _ = offscreen.read().expect("Read last frame");
+
+        Report {
+            scene: self.name(),
+            layout: Timings::new(layout),
+            draw: Timings::new(draw),
+            render: Timings::new(render),
+            submit: Timings::new(submit),
+            statistics: offscreen.renderer_mut().statistics().clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Cell(usize);
+
+impl container::StyleSheet for Cell {
+    fn style(&self) -> container::Style {
+        let shade = self.0 as f32 / 10.0;
+
+        container::Style {
+            background: Some(Background::Color(Color::from_rgb(
+                shade,
+                0.5,
+                1.0 - shade,
+            ))),
+            border_radius: 2,
+            ..container::Style::default()
+        }
+    }
+}
+
+/// Thousands of circles, tessellated every frame into a single large mesh.
+#[derive(Debug)]
+struct Circles {
+    frame: usize,
+}
+
+impl canvas::Layer for Circles {
+    fn draw(&self, bounds: Size) -> Arc<triangle::Mesh2D> {
+        let mut frame = Frame::new(bounds.width, bounds.height);
+        let offset = self.frame as f32;
+
+        for i in 0..5_000 {
+            let t = i as f32 * 0.618;
+
+            let center = Point::new(
+                (t * 97.0 + offset) % bounds.width,
+                (t * 57.0 + offset * 0.5) % bounds.height,
+            );
+
+            let circle = Path::new(|p| p.circle(center, 4.0 + (i % 5) as f32));
+
+            frame.fill(
+                &circle,
+                Fill::Color(Color {
+                    r: t.sin().abs(),
+                    g: 0.4,
+                    b: 0.8,
+                    a: 0.5,
+                }),
+            );
+        }
+
+        Arc::new(frame.into_mesh())
+    }
+}
+
+struct Timings {
+    durations: Vec<Duration>,
+}
+
+impl Timings {
+    fn new(mut durations: Vec<Duration>) -> Self {
+        durations.sort();
+
+        Timings { durations }
+    }
+
+    fn mean(&self) -> Duration {
+        if self.durations.is_empty() {
+            return Duration::default();
+        }
+
+        self.durations.iter().sum::<Duration>() / self.durations.len() as u32
+    }
+
+    fn percentile(&self, percentile: usize) -> Duration {
+        if self.durations.is_empty() {
+            return Duration::default();
+        }
+
+        let index = (self.durations.len() - 1) * percentile / 100;
+
+        self.durations[index]
+    }
+}
+
+impl std::fmt::Display for Timings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "mean {:>9.3?}  min {:>9.3?}  p95 {:>9.3?}  max {:>9.3?}",
+            self.mean(),
+            self.percentile(0),
+            self.percentile(95),
+            self.percentile(100),
+        )
+    }
+}
+
+struct Report {
+    scene: &'static str,
+    layout: Timings,
+    draw: Timings,
+    render: Timings,
+    submit: Timings,
+    statistics: iced_native::window::Statistics,
+}
+
+impl std::fmt::Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} ({} frames)", self.scene, self.layout.durations.len())?;
+        writeln!(f, "  layout  {}", self.layout)?;
+        writeln!(f, "  draw    {}", self.draw)?;
+        writeln!(f, "  render  {}", self.render)?;
+        writeln!(f, "  submit  {}", self.submit)?;
+
+        for (kind, amount) in &self.statistics.primitives {
+            writeln!(f, "  {:<8}{}", kind, amount)?;
+        }
+
+        writeln!(f, "  calls   {}", self.statistics.draw_calls)?;
+
+        for (purpose, size) in &self.statistics.buffers {
+            writeln!(f, "  {:<8}{} KiB", purpose, size / 1024)?;
+        }
+
+        Ok(())
+    }
+}
@@ -175,10 +175,7 @@ mod bezier {
 
             let mesh = Primitive::Mesh2D {
                 origin: Point::new(bounds.x, bounds.y),
-                buffers: Arc::new(Mesh2D {
-                    vertices: buffer.vertices,
-                    indices: buffer.indices,
-                }),
+                buffers: Arc::new(Mesh2D::new(buffer.vertices, buffer.indices)),
             };
 
             (
@@ -55,3 +55,28 @@ impl Sandbox for Counter {
             .into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::Size;
+    use iced_test::{Application, Selector, Simulator};
+
+    #[test]
+    fn counts_presses() {
+        let mut simulator = match Simulator::<Application<Counter>>::application(
+            Size::new(800.0, 600.0),
+        ) {
+            Some(simulator) => simulator,
+            // There is no graphics adapter to lay out text with
+            None => return,
+        };
+
+        simulator.click_on(&Selector::label("Increment"));
+        simulator.click_on(&Selector::label("Increment"));
+        simulator.click_on(&Selector::label("Decrement"));
+
+        assert_eq!(simulator.program().value, 1);
+        assert!(simulator.find(&Selector::label("1")).is_some());
+    }
+}
@@ -87,8 +87,8 @@ mod rainbow {
             (
                 Primitive::Mesh2D {
                     origin: Point::new(b.x, b.y),
-                    buffers: std::sync::Arc::new(Mesh2D {
-                        vertices: vec![
+                    buffers: std::sync::Arc::new(Mesh2D::new(
+                        vec![
                             Vertex2D {
                                 position: posn_center,
                                 color: [1.0, 1.0, 1.0, 1.0],
@@ -126,7 +126,7 @@ mod rainbow {
                                 color: color_v,
                             },
                         ],
-                        indices: vec![
+                        vec![
                             0, 1, 2, // TL
                             0, 2, 3, // T
                             0, 3, 4, // TR
@@ -136,7 +136,7 @@ mod rainbow {
                             0, 7, 8, // BL
                             0, 8, 1, // L
                         ],
-                    }),
+                    )),
                 },
                 MouseCursor::OutOfBounds,
             )
@@ -5,9 +5,9 @@ use controls::Controls;
 use scene::Scene;
 
 use iced_wgpu::{
-    wgpu, window::SwapChain, Primitive, Renderer, Settings, Target,
+    wgpu, window::SwapChain, Pipeline, Renderer, Settings, Target,
 };
-use iced_winit::{winit, Cache, Clipboard, MouseCursor, Size, UserInterface};
+use iced_winit::{program, window::Overlay, winit, Clipboard, Size};
 
 use winit::{
     event::{DeviceEvent, Event, ModifiersState, WindowEvent},
@@ -49,16 +49,20 @@ pub fn main() {
     };
     let mut resized = false;
 
+    // Initialize scene and GUI controls
+    let scene = Scene::new(&device);
+    let controls = Controls::new();
+
     // Initialize iced
-    let mut events = Vec::new();
-    let mut cache = Some(Cache::default());
-    let mut renderer = Renderer::new(&mut device, Settings::default());
-    let mut output = (Primitive::None, MouseCursor::OutOfBounds);
+    let mut renderer =
+        Renderer::new(Pipeline::new(&mut device, Settings::default()));
     let clipboard = Clipboard::new(&window);
 
-    // Initialize scene and GUI controls
-    let mut scene = Scene::new(&device);
-    let mut controls = Controls::new();
+    let mut state = program::State::new(
+        controls,
+        Size::new(logical_size.width, logical_size.height),
+        &mut renderer,
+    );
 
     // Run event loop
     event_loop.run(move |event, _, control_flow| {
@@ -85,76 +89,35 @@ pub fn main() {
                     _ => {}
                 }
 
-                // Map window event to iced event
+                // Map window event to iced event and queue it.
+                //
+                // Hosts that do not use winit can build the events directly
+                // instead, like `iced_winit::Event::Mouse(
+                // mouse::Event::CursorMoved { x, y })`.
                 if let Some(event) = iced_winit::conversion::window_event(
                     event,
                     window.scale_factor(),
                     modifiers,
                 ) {
-                    events.push(event);
+                    state.queue_event(event);
                 }
             }
             Event::MainEventsCleared => {
                 // If no relevant events happened, we can simply skip this
-                if events.is_empty() {
+                if state.is_queue_empty() {
                     return;
                 }
 
-                // We need to:
-                // 1. Process events of our user interface.
-                // 2. Update state as a result of any interaction.
-                // 3. Generate a new output for our renderer.
-
-                // First, we build our user interface.
-                let mut user_interface = UserInterface::build(
-                    controls.view(&scene),
+                // We process the queued events, updating our controls if
+                // any interaction produced messages, and generate a new
+                // output for our renderer.
+                let _ = state.update(
                     Size::new(logical_size.width, logical_size.height),
-                    cache.take().unwrap(),
                     &mut renderer,
-                );
-
-                // Then, we process the events, obtaining messages in return.
-                let messages = user_interface.update(
-                    events.drain(..),
                     clipboard.as_ref().map(|c| c as _),
-                    &renderer,
                 );
 
-                let user_interface = if messages.is_empty() {
-                    // If there are no messages, no interactions we care about have
-                    // happened. We can simply leave our user interface as it is.
-                    user_interface
-                } else {
-                    // If there are messages, we need to update our state
-                    // accordingly and rebuild our user interface.
-                    // We can only do this if we drop our user interface first
-                    // by turning it into its cache.
-                    cache = Some(user_interface.into_cache());
-
-                    // In this example, `Controls` is the only part that cares
-                    // about messages, so updating our state is pretty
-                    // straightforward.
-                    for message in messages {
-                        controls.update(message, &mut scene);
-                    }
-
-                    // Once the state has been changed, we rebuild our updated
-                    // user interface.
-                    UserInterface::build(
-                        controls.view(&scene),
-                        Size::new(logical_size.width, logical_size.height),
-                        cache.take().unwrap(),
-                        &mut renderer,
-                    )
-                };
-
-                // Finally, we just need to draw a new output for our renderer,
-                output = user_interface.draw(&mut renderer);
-
-                // update our cache,
-                cache = Some(user_interface.into_cache());
-
-                // and request a redraw
+                // Finally, we request a redraw
                 window.request_redraw();
             }
             Event::RedrawRequested(_) => {
@@ -176,20 +139,35 @@ pub fn main() {
                     &wgpu::CommandEncoderDescriptor { todo: 0 },
                 );
 
+                let program = state.program();
+
                 // We draw the scene first
-                scene.draw(&mut encoder, &frame.view);
+                scene.draw(
+                    &mut encoder,
+                    &frame.view,
+                    program.background_color(),
+                );
 
                 // And then iced on top
                 let mouse_cursor = renderer.draw(
-                    &mut device,
-                    &mut encoder,
-                    Target {
-                        texture: &frame.view,
-                        viewport,
+                    state.primitive(),
+                    viewport.dimensions(),
+                    &Overlay {
+                        lines: vec![String::from("Some debug information!")],
+                        ..Overlay::default()
+                    },
+                    |pipeline, layers| {
+                        pipeline.draw(
+                            &mut device,
+                            &mut encoder,
+                            Target {
+                                texture: &frame.view,
+                                viewport,
+                            },
+                            layers,
+                            window.scale_factor(),
+                        )
                     },
-                    &output,
-                    window.scale_factor(),
-                    &["Some debug information!"],
                 );
 
                 // Then we submit the work
@@ -95,6 +95,7 @@ impl Sandbox for Styling {
         let scrollable = Scrollable::new(&mut self.scroll)
             .height(Length::Units(100))
             .style(self.theme)
+            .visibility(scrollable::Visibility::Always)
             .push(Text::new("Scroll me!"))
             .push(Space::with_height(Length::Units(800)))
             .push(Text::new("You did it!"));
@@ -400,6 +401,7 @@ mod style {
                         border_width: 0,
                         border_color: Color::TRANSPARENT,
                     },
+                    ..scrollable::Scrollbar::default()
                 }
             }
 
@@ -0,0 +1,69 @@
+//! Write your own renderer backend.
+//!
+//! A [`Renderer`] produces a [`Primitive`] for every widget out-of-the-box.
+//! The only thing it needs from a [`Backend`] is a way to measure text and,
+//! optionally, images. Therefore, implementing the traits of this module is
+//! enough to reuse the built-in widgets with a completely different graphics
+//! library.
+//!
+//! Drawing is entirely up to the backend. [`Renderer::draw`] flattens the
+//! produced [`Primitive`] into a list of layers that are easy to draw in
+//! order, and hands them to the backend.
+//!
+//! [`Renderer`]: ../struct.Renderer.html
+//! [`Primitive`]: ../enum.Primitive.html
+//! [`Backend`]: trait.Backend.html
+//! [`Renderer::draw`]: ../struct.Renderer.html#method.draw
+use iced_native::{image, svg, Font, Size};
+
+/// The graphics backend of a [`Renderer`].
+///
+/// [`Renderer`]: ../struct.Renderer.html
+pub trait Backend {
+    /// Measures the text with the given contents, size, and font.
+    ///
+    /// It returns the width and height of the text, wrapped inside the given
+    /// bounds.
+    fn measure(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> (f32, f32);
+
+    /// Returns the width of a single space with the given size, using the
+    /// default font.
+    fn space_width(&self, size: f32) -> f32;
+
+    /// Trims the measurements cache.
+    ///
+    /// This method is called after every layout pass.
+    fn trim_measurements(&mut self) {}
+
+    /// Returns the size of the buffers in use, in bytes, grouped by purpose.
+    ///
+    /// It is reported in the [`Statistics`] of every frame. By default, it
+    /// reports nothing.
+    ///
+    /// [`Statistics`]: ../../iced_native/window/struct.Statistics.html
+    fn buffer_sizes(&self) -> Vec<(&'static str, u64)> {
+        Vec::new()
+    }
+}
+
+/// A graphics backend that supports raster images.
+pub trait Image {
+    /// Returns the dimensions of the provided raster image.
+    fn dimensions(&self, handle: &image::Handle) -> (u32, u32);
+
+    /// Starts loading the provided raster image in the background, if
+    /// needed, and returns its dimensions once it is ready.
+    fn load(&self, handle: &image::Handle) -> Option<(u32, u32)>;
+}
+
+/// A graphics backend that supports vector images.
+pub trait Svg {
+    /// Returns the viewport dimensions of the provided vector image.
+    fn viewport_dimensions(&self, handle: &svg::Handle) -> (u32, u32);
+}
@@ -0,0 +1,17 @@
+//! Use the fonts bundled with the built-in widgets.
+use iced_native::Font;
+
+/// The font of the icons used by the built-in widgets.
+///
+/// Backends load it like any other [`Font::External`].
+///
+/// [`Font::External`]: ../../iced_native/enum.Font.html#variant.External
+pub const ICONS: Font = Font::External {
+    name: "iced_graphics icons",
+    bytes: include_bytes!("font/icons.ttf"),
+};
+
+/// The `char` of the checkmark icon in the [`ICONS`] font.
+///
+/// [`ICONS`]: constant.ICONS.html
+pub const CHECKMARK_ICON: char = '\u{F00C}';
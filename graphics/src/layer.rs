@@ -0,0 +1,374 @@
+//! Organize rendering primitives into a flattened list of layers.
+use crate::{triangle, Primitive};
+use iced_native::{
+    image, svg, window::Overlay, Background, Font, HorizontalAlignment, Point,
+    Rectangle, Vector, VerticalAlignment,
+};
+
+use std::sync::Arc;
+
+/// A group of primitives that should be clipped together.
+///
+/// Backends draw every [`Layer`] in order, on top of the previous one.
+///
+/// [`Layer`]: struct.Layer.html
+#[derive(Debug, Clone)]
+pub struct Layer<'a> {
+    /// The clipping bounds of the [`Layer`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub bounds: Rectangle<u32>,
+
+    /// The scrolling offset of the contents of the [`Layer`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub offset: Vector<u32>,
+
+    /// The quads of the [`Layer`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub quads: Vec<Quad>,
+
+    /// The images of the [`Layer`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub images: Vec<Image>,
+
+    /// The triangle meshes of the [`Layer`], together with their origin.
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub meshes: Vec<(Point, Arc<triangle::Mesh2D>)>,
+
+    /// The text of the [`Layer`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub text: Vec<Text<'a>>,
+}
+
+impl<'a> Layer<'a> {
+    /// Creates a new empty [`Layer`] with the given clipping bounds and
+    /// offset.
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn new(bounds: Rectangle<u32>, offset: Vector<u32>) -> Self {
+        Self {
+            bounds,
+            offset,
+            quads: Vec::new(),
+            images: Vec::new(),
+            meshes: Vec::new(),
+            text: Vec::new(),
+        }
+    }
+
+    /// Flattens the given [`Primitive`] into a list of layers, covering a
+    /// viewport of the given dimensions.
+    ///
+    /// The provided [`Overlay`] will be placed in a last layer, on top of
+    /// everything else.
+    ///
+    /// [`Primitive`]: ../enum.Primitive.html
+    /// [`Overlay`]: ../../iced_native/window/struct.Overlay.html
+    pub fn generate(
+        primitive: &'a Primitive,
+        (width, height): (u32, u32),
+        overlay: &'a Overlay,
+    ) -> Vec<Layer<'a>> {
+        let mut layers = Vec::new();
+
+        layers.push(Layer::new(
+            Rectangle {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            },
+            Vector::new(0, 0),
+        ));
+
+        Self::process_primitive(primitive, &mut layers);
+        Self::process_overlay(overlay, &mut layers);
+
+        layers
+    }
+
+    fn process_primitive(primitive: &'a Primitive, layers: &mut Vec<Self>) {
+        let layer = layers.last_mut().unwrap();
+
+        match primitive {
+            Primitive::None => {}
+            Primitive::Group { primitives } => {
+                // TODO: Inspect a bit and regroup (?)
+                for primitive in primitives {
+                    Self::process_primitive(primitive, layers)
+                }
+            }
+            Primitive::Text {
+                content,
+                bounds,
+                size,
+                color,
+                font,
+                horizontal_alignment,
+                vertical_alignment,
+            } => {
+                layer.text.push(Text {
+                    content,
+                    bounds: Rectangle {
+                        x: bounds.x - layer.offset.x as f32,
+                        y: bounds.y - layer.offset.y as f32,
+                        ..*bounds
+                    },
+                    size: *size,
+                    color: color.into_linear(),
+                    font: *font,
+                    horizontal_alignment: *horizontal_alignment,
+                    vertical_alignment: *vertical_alignment,
+                });
+            }
+            Primitive::Quad {
+                bounds,
+                background,
+                border_radius,
+                border_width,
+                border_color,
+            } => {
+                // TODO: Move some of this computations to the GPU (?)
+                layer.quads.push(Quad {
+                    position: [
+                        bounds.x - layer.offset.x as f32,
+                        bounds.y - layer.offset.y as f32,
+                    ],
+                    scale: [bounds.width, bounds.height],
+                    color: match background {
+                        Background::Color(color) => color.into_linear(),
+                    },
+                    border_radius: *border_radius as f32,
+                    border_width: *border_width as f32,
+                    border_color: border_color.into_linear(),
+                });
+            }
+            Primitive::Image { handle, bounds } => {
+                layer.images.push(Image::Raster {
+                    handle: handle.clone(),
+                    bounds: *bounds,
+                });
+            }
+            Primitive::Svg { handle, bounds } => {
+                layer.images.push(Image::Vector {
+                    handle: handle.clone(),
+                    bounds: *bounds,
+                });
+            }
+            Primitive::Mesh2D { origin, buffers } => {
+                layer.meshes.push((*origin, buffers.clone()));
+            }
+            Primitive::Clip {
+                bounds,
+                offset,
+                content,
+            } => {
+                let layer_bounds: Rectangle<f32> = layer.bounds.into();
+
+                let clip = Rectangle {
+                    x: bounds.x - layer.offset.x as f32,
+                    y: bounds.y - layer.offset.y as f32,
+                    ..*bounds
+                };
+
+                // Only draw visible content
+                if let Some(clip_bounds) = layer_bounds.intersection(&clip) {
+                    let clip_layer =
+                        Layer::new(clip_bounds.into(), layer.offset + *offset);
+                    let new_layer = Layer::new(layer.bounds, layer.offset);
+
+                    layers.push(clip_layer);
+                    Self::process_primitive(content, layers);
+                    layers.push(new_layer);
+                }
+            }
+        }
+    }
+
+    fn process_overlay(overlay: &'a Overlay, layers: &mut Vec<Self>) {
+        const GRAPH_HEIGHT: f32 = 60.0;
+        const BAR_WIDTH: f32 = 2.0;
+
+        let first = layers.first().unwrap();
+        let lines = &overlay.lines;
+        let graph = &overlay.graph;
+        let mut overlay = Layer::new(first.bounds, Vector::new(0, 0));
+
+        for (i, line) in lines.iter().enumerate() {
+            let text = Text {
+                content: line,
+                bounds: Rectangle {
+                    x: 11.0,
+                    y: 11.0 + 25.0 * i as f32,
+                    width: std::f32::INFINITY,
+                    height: std::f32::INFINITY,
+                },
+                size: 20.0,
+                color: [0.9, 0.9, 0.9, 1.0],
+                font: Font::Default,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+            };
+
+            overlay.text.push(text);
+
+            overlay.text.push(Text {
+                bounds: Rectangle {
+                    x: text.bounds.x - 1.0,
+                    y: text.bounds.y - 1.0,
+                    ..text.bounds
+                },
+                color: [0.0, 0.0, 0.0, 1.0],
+                ..text
+            });
+        }
+
+        if !graph.is_empty() {
+            let x = 10.0;
+            let y = 16.0 + 25.0 * lines.len() as f32;
+            let max = graph.iter().cloned().fold(std::f32::EPSILON, f32::max);
+
+            overlay.quads.push(Quad {
+                position: [x, y],
+                scale: [BAR_WIDTH * graph.len() as f32, GRAPH_HEIGHT],
+                color: [0.0, 0.0, 0.0, 0.5],
+                border_color: [0.0, 0.0, 0.0, 0.0],
+                border_radius: 0.0,
+                border_width: 0.0,
+            });
+
+            for (i, value) in graph.iter().enumerate() {
+                let height = GRAPH_HEIGHT * value.max(0.0) / max;
+
+                overlay.quads.push(Quad {
+                    position: [
+                        x + BAR_WIDTH * i as f32,
+                        y + GRAPH_HEIGHT - height,
+                    ],
+                    scale: [BAR_WIDTH, height],
+                    color: [0.3, 0.9, 0.4, 1.0],
+                    border_color: [0.0, 0.0, 0.0, 0.0],
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                });
+            }
+        }
+
+        layers.push(overlay);
+    }
+}
+
+/// A colored rectangle with a border.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct Quad {
+    /// The position of the [`Quad`].
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub position: [f32; 2],
+
+    /// The size of the [`Quad`].
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub scale: [f32; 2],
+
+    /// The color of the [`Quad`], in a linear RGB color space.
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub color: [f32; 4],
+
+    /// The border color of the [`Quad`], in a linear RGB color space.
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub border_color: [f32; 4],
+
+    /// The border radius of the [`Quad`].
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub border_radius: f32,
+
+    /// The border width of the [`Quad`].
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub border_width: f32,
+}
+
+/// An image, either raster or vector.
+#[derive(Debug, Clone)]
+pub enum Image {
+    /// A raster image.
+    Raster {
+        /// The handle of the image.
+        handle: image::Handle,
+
+        /// The bounds of the image.
+        bounds: Rectangle,
+    },
+
+    /// A vector image.
+    Vector {
+        /// The handle of the image.
+        handle: svg::Handle,
+
+        /// The bounds of the image.
+        bounds: Rectangle,
+    },
+}
+
+impl Image {
+    /// Returns the bounds of the [`Image`].
+    ///
+    /// [`Image`]: enum.Image.html
+    pub fn bounds(&self) -> Rectangle {
+        match self {
+            Image::Raster { bounds, .. } | Image::Vector { bounds, .. } => {
+                *bounds
+            }
+        }
+    }
+}
+
+/// A paragraph of text.
+#[derive(Debug, Clone, Copy)]
+pub struct Text<'a> {
+    /// The content of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    pub content: &'a str,
+
+    /// The layout bounds of the [`Text`], relative to the [`Layer`] offset.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Layer`]: struct.Layer.html
+    pub bounds: Rectangle,
+
+    /// The size of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    pub size: f32,
+
+    /// The color of the [`Text`], in a linear RGB color space.
+    ///
+    /// [`Text`]: struct.Text.html
+    pub color: [f32; 4],
+
+    /// The font of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    pub font: Font,
+
+    /// The horizontal alignment of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    pub horizontal_alignment: HorizontalAlignment,
+
+    /// The vertical alignment of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    pub vertical_alignment: VerticalAlignment,
+}
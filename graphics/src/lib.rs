@@ -0,0 +1,46 @@
+//! A bunch of backend-agnostic types that can be leveraged to build a renderer
+//! for [`iced_native`].
+//!
+//! `iced_graphics` provides a [`Renderer`] that implements the `Renderer`
+//! trait of every built-in widget. It produces a [`Primitive`] for every
+//! widget and only asks a [`Backend`] to measure text and images.
+//!
+//! Therefore, writing a new renderer only requires implementing a [`Backend`]
+//! and drawing the [`Layer`] list produced by [`Layer::generate`]. This is how
+//! [`iced_wgpu`] works.
+//!
+//! [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+//! [`iced_wgpu`]: https://github.com/hecrj/iced/tree/master/wgpu
+//! [`Renderer`]: struct.Renderer.html
+//! [`Primitive`]: enum.Primitive.html
+//! [`Backend`]: backend/trait.Backend.html
+//! [`Layer`]: layer/struct.Layer.html
+//! [`Layer::generate`]: layer/struct.Layer.html#method.generate
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![deny(unused_results)]
+#![forbid(unsafe_code)]
+#![forbid(rust_2018_idioms)]
+pub mod backend;
+pub mod defaults;
+pub mod font;
+pub mod layer;
+pub mod triangle;
+pub mod widget;
+
+mod primitive;
+mod renderer;
+mod transformation;
+mod viewport;
+
+pub use backend::Backend;
+pub use defaults::Defaults;
+pub use layer::Layer;
+pub use primitive::Primitive;
+pub use renderer::Renderer;
+pub use transformation::Transformation;
+pub use viewport::Viewport;
+
+#[cfg(feature = "canvas")]
+#[doc(no_inline)]
+pub use widget::*;
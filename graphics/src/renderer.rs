@@ -0,0 +1,288 @@
+use crate::{Backend, Defaults, Layer, Primitive};
+use iced_native::{
+    layout, window, Background, Color, Font, HorizontalAlignment, Layout,
+    MouseCursor, Point, Rectangle, Size, VerticalAlignment, Widget,
+};
+
+mod widget;
+
+/// A renderer for [`iced_native`], generic over a graphics [`Backend`].
+///
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+/// [`Backend`]: backend/trait.Backend.html
+#[derive(Debug)]
+pub struct Renderer<B: Backend> {
+    backend: B,
+    statistics: window::Statistics,
+}
+
+impl<B: Backend> Renderer<B> {
+    /// Creates a new [`Renderer`] from the given [`Backend`].
+    ///
+    /// [`Renderer`]: struct.Renderer.html
+    /// [`Backend`]: backend/trait.Backend.html
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            statistics: window::Statistics::default(),
+        }
+    }
+
+    /// Returns a reference to the [`Backend`] of the [`Renderer`].
+    ///
+    /// [`Renderer`]: struct.Renderer.html
+    /// [`Backend`]: backend/trait.Backend.html
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Returns a mutable reference to the [`Backend`] of the [`Renderer`].
+    ///
+    /// [`Renderer`]: struct.Renderer.html
+    /// [`Backend`]: backend/trait.Backend.html
+    pub fn backend_mut(&mut self) -> &mut B {
+        &mut self.backend
+    }
+
+    /// Returns the [`Statistics`] of the last frame drawn by the
+    /// [`Renderer`].
+    ///
+    /// [`Statistics`]: ../iced_native/window/struct.Statistics.html
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn statistics(&self) -> &window::Statistics {
+        &self.statistics
+    }
+
+    /// Draws the provided primitives in a viewport of the given dimensions.
+    ///
+    /// The primitives are flattened into a list of layers, which are handed
+    /// to the given closure together with the [`Backend`]. The closure draws
+    /// them and returns the amount of draw calls issued.
+    ///
+    /// The provided [`Overlay`] will be rendered on top of the primitives.
+    /// This is useful for rendering debug information.
+    ///
+    /// [`Backend`]: backend/trait.Backend.html
+    /// [`Overlay`]: ../iced_native/window/struct.Overlay.html
+    pub fn draw<F>(
+        &mut self,
+        (primitive, mouse_cursor): &(Primitive, MouseCursor),
+        dimensions: (u32, u32),
+        overlay: &window::Overlay,
+        draw: F,
+    ) -> MouseCursor
+    where
+        F: FnOnce(&mut B, &[Layer<'_>]) -> usize,
+    {
+        log::debug!("Drawing");
+
+        let layers = {
+            #[cfg(feature = "trace")]
+            let span = tracing::info_span!("layers");
+            #[cfg(feature = "trace")]
+            let _guard = span.enter();
+
+            Layer::generate(primitive, dimensions, overlay)
+        };
+
+        let draw_calls = {
+            #[cfg(feature = "trace")]
+            let span = tracing::info_span!("backend");
+            #[cfg(feature = "trace")]
+            let _guard = span.enter();
+
+            draw(&mut self.backend, &layers)
+        };
+
+        let count = |amount: fn(&Layer<'_>) -> usize| {
+            layers.iter().map(amount).sum::<usize>()
+        };
+
+        self.statistics = window::Statistics {
+            primitives: vec![
+                ("Layers", layers.len()),
+                ("Quads", count(|layer| layer.quads.len())),
+                ("Images", count(|layer| layer.images.len())),
+                ("Meshes", count(|layer| layer.meshes.len())),
+                ("Text", count(|layer| layer.text.len())),
+            ],
+            draw_calls,
+            buffers: self.backend.buffer_sizes(),
+        };
+
+        *mouse_cursor
+    }
+}
+
+impl<B: Backend> iced_native::Renderer for Renderer<B> {
+    type Output = (Primitive, MouseCursor);
+    type Defaults = Defaults;
+
+    fn layout<'a, Message>(
+        &mut self,
+        element: &iced_native::Element<'a, Message, Self>,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        let node = element.layout(self, limits);
+
+        self.backend.trim_measurements();
+
+        node
+    }
+}
+
+impl<B: Backend> layout::Debugger for Renderer<B> {
+    fn explain<Message>(
+        &mut self,
+        defaults: &Defaults,
+        widget: &dyn Widget<Message, Self>,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        color: Color,
+        nodes: &[layout::WidgetNode],
+    ) -> Self::Output {
+        let mut primitives = Vec::new();
+        let (primitive, cursor) =
+            widget.draw(self, defaults, layout, cursor_position);
+
+        explain_layout(layout, color, &mut primitives);
+        primitives.push(primitive);
+
+        if let Some(index) = layout::WidgetNode::hovered(nodes, cursor_position)
+        {
+            self.describe(&nodes[index], layout.bounds(), &mut primitives);
+        }
+
+        (Primitive::Group { primitives }, cursor)
+    }
+
+    fn inspect<Message>(
+        &mut self,
+        defaults: &Defaults,
+        widget: &dyn Widget<Message, Self>,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        nodes: &[layout::WidgetNode],
+    ) -> Self::Output {
+        let (primitive, cursor) =
+            widget.draw(self, defaults, layout, cursor_position);
+
+        let mut primitives = vec![primitive];
+
+        for node in nodes {
+            primitives.push(Primitive::Quad {
+                bounds: node.bounds,
+                background: Background::Color(Color::TRANSPARENT),
+                border_radius: 0,
+                border_width: 1,
+                border_color: [0.2, 0.5, 1.0, 0.4].into(),
+            });
+        }
+
+        if let Some(index) = layout::WidgetNode::hovered(nodes, cursor_position)
+        {
+            self.describe(&nodes[index], layout.bounds(), &mut primitives);
+        }
+
+        (Primitive::Group { primitives }, cursor)
+    }
+}
+
+impl<B: Backend> Renderer<B> {
+    /// Highlights the given node and describes it in a floating label that
+    /// stays inside the viewport.
+    fn describe(
+        &self,
+        node: &layout::WidgetNode,
+        viewport: Rectangle,
+        primitives: &mut Vec<Primitive>,
+    ) {
+        const LABEL_SIZE: f32 = 14.0;
+        const LABEL_PADDING: f32 = 4.0;
+
+        primitives.push(Primitive::Quad {
+            bounds: node.bounds,
+            background: Background::Color([0.2, 0.5, 1.0, 0.25].into()),
+            border_radius: 0,
+            border_width: 1,
+            border_color: [0.2, 0.5, 1.0, 1.0].into(),
+        });
+
+        let content = format!(
+            "{} {}x{} at ({}, {})\nwidth: {:?}, height: {:?}, padding: {}",
+            node.name,
+            node.bounds.width,
+            node.bounds.height,
+            node.bounds.x,
+            node.bounds.y,
+            node.width,
+            node.height,
+            node.padding,
+        );
+
+        let (width, height) = self.backend.measure(
+            &content,
+            LABEL_SIZE,
+            Font::Default,
+            Size::INFINITY,
+        );
+
+        let label_height = height + LABEL_PADDING * 2.0;
+
+        // Place the label above the node, unless it does not fit
+        let y = if node.bounds.y - label_height >= viewport.y {
+            node.bounds.y - label_height
+        } else {
+            (node.bounds.y + node.bounds.height)
+                .min(viewport.y + viewport.height - label_height)
+        };
+
+        let label = Rectangle {
+            x: node.bounds.x,
+            y,
+            width: width + LABEL_PADDING * 2.0,
+            height: label_height,
+        };
+
+        primitives.push(Primitive::Quad {
+            bounds: label,
+            background: Background::Color([0.1, 0.1, 0.1, 0.9].into()),
+            border_radius: 2,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        });
+
+        primitives.push(Primitive::Text {
+            content,
+            bounds: Rectangle {
+                x: label.x + LABEL_PADDING,
+                y: label.y + LABEL_PADDING,
+                width,
+                height,
+            },
+            size: LABEL_SIZE,
+            color: Color::WHITE,
+            font: Font::Default,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+        });
+    }
+}
+
+fn explain_layout(
+    layout: Layout<'_>,
+    color: Color,
+    primitives: &mut Vec<Primitive>,
+) {
+    primitives.push(Primitive::Quad {
+        bounds: layout.bounds(),
+        background: Background::Color(Color::TRANSPARENT),
+        border_radius: 0,
+        border_width: 1,
+        border_color: [0.6, 0.6, 0.6, 0.5].into(),
+    });
+
+    for child in layout.children() {
+        explain_layout(child, color, primitives);
+    }
+}
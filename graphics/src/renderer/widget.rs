@@ -2,17 +2,13 @@ mod button;
 mod checkbox;
 mod column;
 mod container;
+mod image;
 mod progress_bar;
 mod radio;
 mod row;
 mod scrollable;
 mod slider;
 mod space;
+mod svg;
 mod text;
 mod text_input;
-
-#[cfg(feature = "svg")]
-mod svg;
-
-#[cfg(feature = "image")]
-mod image;
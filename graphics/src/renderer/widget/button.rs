@@ -1,9 +1,13 @@
-use crate::{button::StyleSheet, defaults, Defaults, Primitive, Renderer};
+use crate::{defaults, Backend, Defaults, Primitive, Renderer};
 use iced_native::{
     Background, Color, Element, Layout, MouseCursor, Point, Rectangle, Vector,
 };
+use iced_style::button::StyleSheet;
 
-impl iced_native::button::Renderer for Renderer {
+impl<B> iced_native::button::Renderer for Renderer<B>
+where
+    B: Backend,
+{
     const DEFAULT_PADDING: u16 = 5;
 
     type Style = Box<dyn StyleSheet>;
@@ -15,6 +19,7 @@ impl iced_native::button::Renderer for Renderer {
         cursor_position: Point,
         is_disabled: bool,
         is_pressed: bool,
+        is_focused: bool,
         style: &Box<dyn StyleSheet>,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
@@ -29,6 +34,8 @@ impl iced_native::button::Renderer for Renderer {
             } else {
                 style.hovered()
             }
+        } else if is_focused {
+            style.focused()
         } else {
             style.active()
         };
@@ -1,9 +1,13 @@
-use crate::{checkbox::StyleSheet, Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer};
 use iced_native::{
     checkbox, HorizontalAlignment, MouseCursor, Rectangle, VerticalAlignment,
 };
+use iced_style::checkbox::StyleSheet;
 
-impl checkbox::Renderer for Renderer {
+impl<B> checkbox::Renderer for Renderer<B>
+where
+    B: Backend,
+{
     type Style = Box<dyn StyleSheet>;
 
     const DEFAULT_SIZE: u16 = 20;
@@ -14,10 +18,13 @@ impl checkbox::Renderer for Renderer {
         bounds: Rectangle,
         is_checked: bool,
         is_mouse_over: bool,
+        is_focused: bool,
         (label, _): Self::Output,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let style = if is_mouse_over {
+        let style = if is_focused {
+            style_sheet.focused(is_checked)
+        } else if is_mouse_over {
             style_sheet.hovered(is_checked)
         } else {
             style_sheet.active(is_checked)
@@ -35,8 +42,8 @@ impl checkbox::Renderer for Renderer {
             Primitive::Group {
                 primitives: if is_checked {
                     let check = Primitive::Text {
-                        content: crate::text::CHECKMARK_ICON.to_string(),
-                        font: crate::text::BUILTIN_ICONS,
+                        content: crate::font::CHECKMARK_ICON.to_string(),
+                        font: crate::font::ICONS,
                         size: bounds.height * 0.7,
                         bounds: bounds,
                         color: style.checkmark_color,
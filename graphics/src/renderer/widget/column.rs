@@ -1,7 +1,10 @@
-use crate::{Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer};
 use iced_native::{column, Element, Layout, MouseCursor, Point};
 
-impl column::Renderer for Renderer {
+impl<B> column::Renderer for Renderer<B>
+where
+    B: Backend,
+{
     fn draw<Message>(
         &mut self,
         defaults: &Self::Defaults,
@@ -1,7 +1,11 @@
-use crate::{container, defaults, Defaults, Primitive, Renderer};
+use crate::{defaults, Backend, Defaults, Primitive, Renderer};
 use iced_native::{Background, Color, Element, Layout, Point, Rectangle};
+use iced_style::container;
 
-impl iced_native::container::Renderer for Renderer {
+impl<B> iced_native::container::Renderer for Renderer<B>
+where
+    B: Backend,
+{
     type Style = Box<dyn container::StyleSheet>;
 
     fn draw<Message>(
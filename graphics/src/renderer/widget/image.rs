@@ -0,0 +1,46 @@
+use crate::{backend, Backend, Primitive, Renderer};
+use iced_native::{image, Background, Color, Layout, MouseCursor};
+
+impl<B> image::Renderer for Renderer<B>
+where
+    B: Backend + backend::Image,
+{
+    fn dimensions(&self, handle: &image::Handle) -> (u32, u32) {
+        self.backend.dimensions(handle)
+    }
+
+    fn load(&self, handle: &image::Handle) -> Option<(u32, u32)> {
+        self.backend.load(handle)
+    }
+
+    fn draw(
+        &mut self,
+        handle: image::Handle,
+        layout: Layout<'_>,
+    ) -> Self::Output {
+        (
+            Primitive::Image {
+                handle,
+                bounds: layout.bounds(),
+            },
+            MouseCursor::OutOfBounds,
+        )
+    }
+
+    fn draw_placeholder(
+        &mut self,
+        placeholder: Background,
+        layout: Layout<'_>,
+    ) -> Self::Output {
+        (
+            Primitive::Quad {
+                bounds: layout.bounds(),
+                background: placeholder,
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            },
+            MouseCursor::OutOfBounds,
+        )
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{Backend, Primitive, Renderer};
+use iced_native::{progress_bar, Color, MouseCursor, Rectangle};
+use iced_style::progress_bar::StyleSheet;
+
+/// The width of the sweeping segment of an indeterminate progress bar,
+/// relative to the width of the bar.
+const SEGMENT_WIDTH: f32 = 0.3;
+
+impl<B> progress_bar::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_HEIGHT: u16 = 30;
+
+    fn draw(
+        &self,
+        bounds: Rectangle,
+        range: std::ops::RangeInclusive<f32>,
+        value: f32,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let (range_start, range_end) = range.into_inner();
+        let active_progress_width = bounds.width
+            * ((value - range_start) / (range_end - range_start).max(1.0));
+
+        let background = Primitive::Group {
+            primitives: vec![Primitive::Quad {
+                bounds: Rectangle { ..bounds },
+                background: style.background,
+                border_radius: style.border_radius,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            }],
+        };
+
+        (
+            if active_progress_width > 0.0 {
+                let bar = Primitive::Quad {
+                    bounds: Rectangle {
+                        width: active_progress_width,
+                        ..bounds
+                    },
+                    background: style.bar,
+                    border_radius: style.border_radius,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                };
+
+                Primitive::Group {
+                    primitives: vec![background, bar],
+                }
+            } else {
+                background
+            },
+            MouseCursor::OutOfBounds,
+        )
+    }
+
+    fn draw_indeterminate(
+        &self,
+        bounds: Rectangle,
+        phase: f32,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        // The segment enters from the left and leaves through the right
+        let segment_width = bounds.width * SEGMENT_WIDTH;
+        let start =
+            bounds.x - segment_width + (bounds.width + segment_width) * phase;
+
+        let left = start.max(bounds.x);
+        let right = (start + segment_width).min(bounds.x + bounds.width);
+
+        (
+            if right > left {
+                let segment = Primitive::Quad {
+                    bounds: Rectangle {
+                        x: left,
+                        width: right - left,
+                        ..bounds
+                    },
+                    background: style.bar,
+                    border_radius: style.border_radius,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                };
+
+                Primitive::Group {
+                    primitives: vec![background, segment],
+                }
+            } else {
+                background
+            },
+            MouseCursor::OutOfBounds,
+        )
+    }
+}
@@ -1,10 +1,14 @@
-use crate::{radio::StyleSheet, Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer};
 use iced_native::{radio, Background, Color, MouseCursor, Rectangle};
+use iced_style::radio::StyleSheet;
 
 const SIZE: f32 = 28.0;
 const DOT_SIZE: f32 = SIZE / 2.0;
 
-impl radio::Renderer for Renderer {
+impl<B> radio::Renderer for Renderer<B>
+where
+    B: Backend,
+{
     type Style = Box<dyn StyleSheet>;
 
     fn default_size(&self) -> u32 {
@@ -16,10 +20,13 @@ impl radio::Renderer for Renderer {
         bounds: Rectangle,
         is_selected: bool,
         is_mouse_over: bool,
+        is_focused: bool,
         (label, _): Self::Output,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let style = if is_mouse_over {
+        let style = if is_focused {
+            style_sheet.focused()
+        } else if is_mouse_over {
             style_sheet.hovered()
         } else {
             style_sheet.active()
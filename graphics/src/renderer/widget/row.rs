@@ -1,7 +1,10 @@
-use crate::{Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer};
 use iced_native::{row, Element, Layout, MouseCursor, Point};
 
-impl row::Renderer for Renderer {
+impl<B> row::Renderer for Renderer<B>
+where
+    B: Backend,
+{
     fn draw<Message>(
         &mut self,
         defaults: &Self::Defaults,
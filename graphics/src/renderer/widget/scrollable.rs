@@ -0,0 +1,147 @@
+use crate::{Backend, Primitive, Renderer};
+use iced_native::{
+    scrollable, Background, Color, MouseCursor, Rectangle, Vector,
+};
+
+impl<B> scrollable::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn iced_style::scrollable::StyleSheet>;
+
+    fn scrollbar(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        offset: u32,
+        style_sheet: &Self::Style,
+    ) -> Option<scrollable::Scrollbar> {
+        if content_bounds.height > bounds.height {
+            let style = style_sheet.active();
+            let width = f32::from(style.width);
+            let margin = f32::from(style.margin);
+
+            let scrollbar_bounds = Rectangle {
+                x: bounds.x + bounds.width - (width + 2.0 * margin),
+                y: bounds.y,
+                width: width + 2.0 * margin,
+                height: bounds.height,
+            };
+
+            let ratio = bounds.height / content_bounds.height;
+            let scrollbar_height = bounds.height * ratio;
+            let y_offset = offset as f32 * ratio;
+
+            let scroller_bounds = Rectangle {
+                x: scrollbar_bounds.x + margin,
+                y: scrollbar_bounds.y + y_offset,
+                width,
+                height: scrollbar_height,
+            };
+
+            Some(scrollable::Scrollbar {
+                bounds: scrollbar_bounds,
+                scroller: scrollable::Scroller {
+                    bounds: scroller_bounds,
+                },
+            })
+        } else {
+            None
+        }
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _is_mouse_over: bool,
+        offset: u32,
+        scrollbar: scrollable::ScrollbarState<'_, Self::Style>,
+        (content, mouse_cursor): Self::Output,
+    ) -> Self::Output {
+        let scrollable::ScrollbarState {
+            scrollbar,
+            is_mouse_over: is_mouse_over_scrollbar,
+            is_grabbed,
+            opacity,
+            style: style_sheet,
+        } = scrollbar;
+
+        let clip = Primitive::Clip {
+            bounds,
+            offset: Vector::new(0, offset),
+            content: Box::new(content),
+        };
+
+        (
+            match scrollbar {
+                Some(scrollbar) if opacity > 0.0 => {
+                    let style = if is_grabbed {
+                        style_sheet.dragging()
+                    } else if is_mouse_over_scrollbar {
+                        style_sheet.hovered()
+                    } else {
+                        style_sheet.active()
+                    };
+
+                    let margin = f32::from(style_sheet.active().margin);
+
+                    let is_scrollbar_visible =
+                        style.background.is_some() || style.border_width > 0;
+
+                    let scroller = Primitive::Quad {
+                        bounds: scrollbar.scroller.bounds,
+                        background: Background::Color(fade(
+                            style.scroller.color,
+                            opacity,
+                        )),
+                        border_radius: style.scroller.border_radius,
+                        border_width: style.scroller.border_width,
+                        border_color: fade(
+                            style.scroller.border_color,
+                            opacity,
+                        ),
+                    };
+
+                    let scrollbar = if is_scrollbar_visible {
+                        Primitive::Quad {
+                            bounds: Rectangle {
+                                x: scrollbar.bounds.x + margin,
+                                width: scrollbar.bounds.width - 2.0 * margin,
+                                ..scrollbar.bounds
+                            },
+                            background: match style.background {
+                                Some(Background::Color(color)) => {
+                                    Background::Color(fade(color, opacity))
+                                }
+                                None => Background::Color(Color::TRANSPARENT),
+                            },
+                            border_radius: style.border_radius,
+                            border_width: style.border_width,
+                            border_color: fade(style.border_color, opacity),
+                        }
+                    } else {
+                        Primitive::None
+                    };
+
+                    Primitive::Group {
+                        primitives: vec![clip, scrollbar, scroller],
+                    }
+                }
+                _ => clip,
+            },
+            if is_mouse_over_scrollbar || is_grabbed {
+                MouseCursor::Idle
+            } else {
+                mouse_cursor
+            },
+        )
+    }
+}
+
+fn fade(color: Color, opacity: f32) -> Color {
+    Color {
+        a: color.a * opacity,
+        ..color
+    }
+}
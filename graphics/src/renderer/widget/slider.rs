@@ -1,12 +1,13 @@
-use crate::{
-    slider::{HandleShape, StyleSheet},
-    Primitive, Renderer,
-};
+use crate::{Backend, Primitive, Renderer};
 use iced_native::{slider, Background, Color, MouseCursor, Point, Rectangle};
+use iced_style::slider::{HandleShape, StyleSheet};
 
 const HANDLE_HEIGHT: f32 = 22.0;
 
-impl slider::Renderer for Renderer {
+impl<B> slider::Renderer for Renderer<B>
+where
+    B: Backend,
+{
     type Style = Box<dyn StyleSheet>;
 
     fn height(&self) -> u32 {
@@ -19,13 +20,15 @@ impl slider::Renderer for Renderer {
         cursor_position: Point,
         range: std::ops::RangeInclusive<f32>,
         value: f32,
-        is_dragging: bool,
+        state: &slider::State,
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
 
-        let style = if is_dragging {
+        let style = if state.is_dragging() {
             style_sheet.dragging()
+        } else if state.is_focused() {
+            style_sheet.focused()
         } else if is_mouse_over {
             style_sheet.hovered()
         } else {
@@ -94,7 +97,7 @@ impl slider::Renderer for Renderer {
             Primitive::Group {
                 primitives: vec![rail_top, rail_bottom, handle],
             },
-            if is_dragging {
+            if state.is_dragging() {
                 MouseCursor::Grabbing
             } else if is_mouse_over {
                 MouseCursor::Grab
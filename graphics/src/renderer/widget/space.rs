@@ -1,7 +1,10 @@
-use crate::{Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer};
 use iced_native::{space, MouseCursor, Rectangle};
 
-impl space::Renderer for Renderer {
+impl<B> space::Renderer for Renderer<B>
+where
+    B: Backend,
+{
     fn draw(&mut self, _bounds: Rectangle) -> Self::Output {
         (Primitive::None, MouseCursor::OutOfBounds)
     }
@@ -1,9 +1,12 @@
-use crate::{Primitive, Renderer};
+use crate::{backend, Backend, Primitive, Renderer};
 use iced_native::{svg, Layout, MouseCursor};
 
-impl svg::Renderer for Renderer {
+impl<B> svg::Renderer for Renderer<B>
+where
+    B: Backend + backend::Svg,
+{
     fn dimensions(&self, handle: &svg::Handle) -> (u32, u32) {
-        self.image_pipeline.viewport_dimensions(handle)
+        self.backend.viewport_dimensions(handle)
     }
 
     fn draw(
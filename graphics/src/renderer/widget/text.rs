@@ -1,4 +1,4 @@
-use crate::{Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer};
 use iced_native::{
     text, Color, Font, HorizontalAlignment, MouseCursor, Rectangle, Size,
     VerticalAlignment,
@@ -6,7 +6,10 @@ use iced_native::{
 
 use std::f32;
 
-impl text::Renderer for Renderer {
+impl<B> text::Renderer for Renderer<B>
+where
+    B: Backend,
+{
     const DEFAULT_SIZE: u16 = 20;
 
     fn measure(
@@ -16,8 +19,7 @@ impl text::Renderer for Renderer {
         font: Font,
         bounds: Size,
     ) -> (f32, f32) {
-        self.text_pipeline
-            .measure(content, f32::from(size), font, bounds)
+        self.backend.measure(content, f32::from(size), font, bounds)
     }
 
     fn draw(
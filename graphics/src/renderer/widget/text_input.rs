@@ -1,12 +1,15 @@
-use crate::{text_input::StyleSheet, Primitive, Renderer};
-
+use crate::{Backend, Primitive, Renderer};
 use iced_native::{
     text_input, Background, Color, Font, HorizontalAlignment, MouseCursor,
     Point, Rectangle, Size, Vector, VerticalAlignment,
 };
+use iced_style::text_input::StyleSheet;
 use std::f32;
 
-impl text_input::Renderer for Renderer {
+impl<B> text_input::Renderer for Renderer<B>
+where
+    B: Backend,
+{
     type Style = Box<dyn StyleSheet>;
 
     fn default_size(&self) -> u16 {
@@ -15,17 +18,14 @@ impl text_input::Renderer for Renderer {
     }
 
     fn measure_value(&self, value: &str, size: u16, font: Font) -> f32 {
-        let (mut width, _) = self.text_pipeline.measure(
-            value,
-            f32::from(size),
-            font,
-            Size::INFINITY,
-        );
+        let (mut width, _) =
+            self.backend
+                .measure(value, f32::from(size), font, Size::INFINITY);
 
         let spaces_at_the_end = value.len() - value.trim_end().len();
 
         if spaces_at_the_end > 0 {
-            let space_width = self.text_pipeline.space_width(size as f32);
+            let space_width = self.backend.space_width(size as f32);
             width += spaces_at_the_end as f32 * space_width;
         }
 
@@ -162,8 +162,8 @@ impl text_input::Renderer for Renderer {
     }
 }
 
-fn measure_cursor_and_scroll_offset(
-    renderer: &Renderer,
+fn measure_cursor_and_scroll_offset<B: Backend>(
+    renderer: &Renderer<B>,
     text_bounds: Rectangle,
     value: &text_input::Value,
     size: u16,
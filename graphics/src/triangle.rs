@@ -0,0 +1,174 @@
+//! Draw meshes of triangles.
+use std::{
+    hash::{Hash, Hasher},
+    io,
+    sync::Arc,
+};
+
+/// A two-dimensional vertex with some color in __linear__ RGBA.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Vertex2D {
+    /// The vertex position
+    pub position: [f32; 2],
+    /// The vertex color in __linear__ RGBA.
+    pub color: [f32; 4],
+}
+
+/// A set of [`Vertex2D`] and indices representing a list of triangles.
+///
+/// [`Vertex2D`]: struct.Vertex2D.html
+#[derive(Clone, Debug)]
+pub struct Mesh2D {
+    /// The vertices of the mesh
+    pub vertices: Vec<Vertex2D>,
+    /// The list of vertex indices that defines the triangles of the mesh.
+    ///
+    /// Therefore, this list should always have a length that is a multiple of 3.
+    pub indices: Vec<u32>,
+    attributes: Vec<[f32; 2]>,
+    shader: Option<Shader>,
+}
+
+impl Mesh2D {
+    /// Creates a new [`Mesh2D`] from its vertices and indices.
+    ///
+    /// [`Mesh2D`]: struct.Mesh2D.html
+    pub fn new(vertices: Vec<Vertex2D>, indices: Vec<u32>) -> Mesh2D {
+        Mesh2D {
+            vertices,
+            indices,
+            attributes: Vec::new(),
+            shader: None,
+        }
+    }
+
+    /// Creates a new [`Mesh2D`] drawn with a custom fragment [`Shader`].
+    ///
+    /// Every vertex receives the attribute at the same index. Vertices
+    /// without an attribute receive `[0.0, 0.0]`.
+    ///
+    /// [`Mesh2D`]: struct.Mesh2D.html
+    /// [`Shader`]: struct.Shader.html
+    pub fn with_shader(
+        vertices: Vec<Vertex2D>,
+        indices: Vec<u32>,
+        attributes: Vec<[f32; 2]>,
+        shader: Shader,
+    ) -> Mesh2D {
+        Mesh2D {
+            vertices,
+            indices,
+            attributes,
+            shader: Some(shader),
+        }
+    }
+
+    /// Returns the extra attribute of each vertex of the [`Mesh2D`].
+    ///
+    /// [`Mesh2D`]: struct.Mesh2D.html
+    pub fn attributes(&self) -> &[[f32; 2]] {
+        &self.attributes
+    }
+
+    /// Returns the custom fragment [`Shader`] of the [`Mesh2D`], if any.
+    ///
+    /// [`Mesh2D`]: struct.Mesh2D.html
+    /// [`Shader`]: struct.Shader.html
+    pub fn shader(&self) -> Option<&Shader> {
+        self.shader.as_ref()
+    }
+}
+
+/// A custom fragment shader used to draw a [`Mesh2D`].
+///
+/// The shader receives the color of the vertex at location 0 as a `vec4` and
+/// its extra attribute at location 1 as a `vec2`. It must write the final
+/// color at location 0:
+///
+/// ```glsl
+/// #version 450
+///
+/// layout(location = 0) in vec4 i_Color;
+/// layout(location = 1) in vec2 i_Attribute;
+///
+/// layout(location = 0) out vec4 o_Color;
+///
+/// void main() {
+///     o_Color = i_Color * i_Attribute.x;
+/// }
+/// ```
+///
+/// Custom shaders are optional. It is up to every backend to support them.
+///
+/// A [`Shader`] is identified by the SPIR-V it was created from. Clones of a
+/// [`Shader`] are equal to it, while shaders created separately are always
+/// different, even from the same bytes.
+///
+/// [`Mesh2D`]: struct.Mesh2D.html
+/// [`Shader`]: struct.Shader.html
+#[derive(Clone, Debug)]
+pub struct Shader {
+    spirv: Arc<Vec<u32>>,
+}
+
+impl Shader {
+    /// Creates a [`Shader`] from its compiled SPIR-V bytes.
+    ///
+    /// [`Shader`]: struct.Shader.html
+    pub fn from_spirv(bytes: &[u8]) -> Result<Shader, io::Error> {
+        const MAGIC_NUMBER: u32 = 0x0723_0203;
+
+        if bytes.len() % 4 != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "input length not divisible by 4",
+            ));
+        }
+
+        let mut spirv: Vec<u32> = bytes
+            .chunks_exact(4)
+            .map(|word| {
+                u32::from_le_bytes([word[0], word[1], word[2], word[3]])
+            })
+            .collect();
+
+        if spirv.first() == Some(&MAGIC_NUMBER.swap_bytes()) {
+            for word in &mut spirv {
+                *word = word.swap_bytes();
+            }
+        }
+
+        if spirv.first() != Some(&MAGIC_NUMBER) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "input missing SPIR-V magic number",
+            ));
+        }
+
+        Ok(Shader {
+            spirv: Arc::new(spirv),
+        })
+    }
+
+    /// Returns the SPIR-V words of the [`Shader`].
+    ///
+    /// [`Shader`]: struct.Shader.html
+    pub fn spirv(&self) -> &[u32] {
+        &self.spirv
+    }
+}
+
+impl PartialEq for Shader {
+    fn eq(&self, other: &Shader) -> bool {
+        Arc::ptr_eq(&self.spirv, &other.spirv)
+    }
+}
+
+impl Eq for Shader {}
+
+impl Hash for Shader {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.spirv).hash(state);
+    }
+}
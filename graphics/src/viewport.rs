@@ -23,7 +23,11 @@ impl Viewport {
         (self.width, self.height)
     }
 
-    pub(crate) fn transformation(&self) -> Transformation {
+    /// Returns the projection [`Transformation`] of the [`Viewport`].
+    ///
+    /// [`Transformation`]: struct.Transformation.html
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn transformation(&self) -> Transformation {
         self.transformation
     }
 }
@@ -213,10 +213,7 @@ impl Frame {
     ///
     /// [`Frame`]: struct.Frame.html
     pub fn into_mesh(self) -> triangle::Mesh2D {
-        triangle::Mesh2D {
-            vertices: self.buffers.vertices,
-            indices: self.buffers.indices,
-        }
+        triangle::Mesh2D::new(self.buffers.vertices, self.buffers.indices)
     }
 }
 
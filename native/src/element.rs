@@ -1,7 +1,10 @@
 use crate::{
-    layout, Clipboard, Color, Event, Hasher, Layout, Length, Point, Widget,
+    focus, layout, space, Clipboard, Color, Event, Hasher, Layout, Length,
+    Point, Widget,
 };
 
+use std::any::Any;
+
 /// A generic [`Widget`].
 ///
 /// It is useful to build composable user interfaces that do not leak
@@ -15,7 +18,7 @@ use crate::{
 /// [`Element`]: struct.Element.html
 #[allow(missing_debug_implementations)]
 pub struct Element<'a, Message, Renderer> {
-    pub(crate) widget: Box<dyn Widget<Message, Renderer> + 'a>,
+    pub(crate) widget: Content<'a, Message, Renderer>,
 }
 
 impl<'a, Message, Renderer> Element<'a, Message, Renderer>
@@ -30,7 +33,7 @@ where
         widget: impl Widget<Message, Renderer> + 'a,
     ) -> Element<'a, Message, Renderer> {
         Element {
-            widget: Box::new(widget),
+            widget: Content::Widget(Box::new(widget)),
         }
     }
 
@@ -169,6 +172,10 @@ where
     ///     }
     /// }
     /// ```
+    ///
+    /// Mapping an [`Element`] that is already mapped does not wrap it again.
+    /// Instead, the mappers are composed, so events go through a single layer
+    /// no matter how many times an [`Element`] is mapped.
     pub fn map<F, B>(self, f: F) -> Element<'a, B, Renderer>
     where
         Message: 'static,
@@ -176,8 +183,13 @@ where
         B: 'static,
         F: 'static + Fn(Message) -> B,
     {
+        let map = match self.widget {
+            Content::Widget(widget) => Map::new(widget, f),
+            Content::Map(map) => map.then(f),
+        };
+
         Element {
-            widget: Box::new(Map::new(self.widget, f)),
+            widget: Content::Map(map),
         }
     }
 
@@ -186,8 +198,14 @@ where
     /// The [`Renderer`] will explain the layout of the [`Element`] graphically.
     /// This can be very useful for debugging your layout!
     ///
+    /// Hovering a widget highlights it and describes its computed size and
+    /// [`Length`]. When the `debug` feature is enabled, clicking while holding
+    /// the `Alt` key logs the chain of ancestors of the hovered widget, instead
+    /// of interacting with it.
+    ///
     /// [`Element`]: struct.Element.html
     /// [`Renderer`]: trait.Renderer.html
+    /// [`Length`]: enum.Length.html
     pub fn explain<C: Into<Color>>(
         self,
         color: C,
@@ -197,7 +215,65 @@ where
         Renderer: 'a + layout::Debugger,
     {
         Element {
-            widget: Box::new(Explain::new(self, color.into())),
+            widget: Content::Widget(Box::new(Explain::new(self, color.into()))),
+        }
+    }
+
+    /// Marks the [`Element`] as _to-be-inspected_.
+    ///
+    /// The [`Renderer`] will overlay the widget tree of the [`Element`],
+    /// describing the type, bounds, padding, and [`Length`] of every widget,
+    /// and highlighting the widget under the cursor.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Renderer`]: trait.Renderer.html
+    /// [`Length`]: enum.Length.html
+    pub fn inspect(self) -> Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: 'a + layout::Debugger,
+    {
+        Element {
+            widget: Content::Widget(Box::new(Inspect::new(self))),
+        }
+    }
+
+    /// Hides the [`Element`] if `is_hidden` is true.
+    ///
+    /// A hidden [`Element`] keeps its place in the layout. However, it is not
+    /// drawn and it ignores any events. Therefore, toggling its visibility
+    /// does not move the surrounding content around.
+    ///
+    /// [`Element`]: struct.Element.html
+    pub fn hidden(self, is_hidden: bool) -> Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: 'a + space::Renderer,
+    {
+        if !is_hidden {
+            return self;
+        }
+
+        Element {
+            widget: Content::Widget(Box::new(Hidden::new(self))),
+        }
+    }
+
+    /// Identifies the [`Element`] with the given `id`.
+    ///
+    /// The `id` is reported by [`Widget::inspect`], which allows tools to find
+    /// the [`Element`] in a user interface. It does not change the behavior of
+    /// the [`Element`] in any way.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Widget::inspect`]: widget/trait.Widget.html#method.inspect
+    pub fn id(self, id: &'static str) -> Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: 'a,
+    {
+        Element {
+            widget: Content::Widget(Box::new(Id::new(self, id))),
         }
     }
 
@@ -243,35 +319,318 @@ where
     }
 
     /// Computes the _layout_ hash of the [`Element`].
-    /// 
+    ///
     /// [`Element`]: struct.Element.html
     pub fn hash_layout(&self, state: &mut Hasher) {
         self.widget.hash_layout(state);
     }
 }
 
-struct Map<'a, A, B, Renderer> {
-    widget: Box<dyn Widget<A, Renderer> + 'a>,
-    mapper: Box<dyn Fn(A) -> B>,
+/// The widget of an [`Element`].
+///
+/// A mapped [`Element`] keeps its [`Map`] unboxed, so it can be mapped again
+/// without adding another layer.
+///
+/// [`Element`]: struct.Element.html
+/// [`Map`]: struct.Map.html
+pub(crate) enum Content<'a, Message, Renderer> {
+    Widget(Box<dyn Widget<Message, Renderer> + 'a>),
+    Map(Map<'a, Message, Renderer>),
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Content<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        match self {
+            Content::Widget(widget) => widget.width(),
+            Content::Map(map) => map.width(),
+        }
+    }
+
+    fn height(&self) -> Length {
+        match self {
+            Content::Widget(widget) => widget.height(),
+            Content::Map(map) => map.height(),
+        }
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        match self {
+            Content::Widget(widget) => widget.layout(renderer, limits),
+            Content::Map(map) => map.layout(renderer, limits),
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        match self {
+            Content::Widget(widget) => widget.on_event(
+                event,
+                layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            ),
+            Content::Map(map) => map.on_event(
+                event,
+                layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            ),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        match self {
+            Content::Widget(widget) => {
+                widget.draw(renderer, defaults, layout, cursor_position)
+            }
+            Content::Map(map) => {
+                map.draw(renderer, defaults, layout, cursor_position)
+            }
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        match self {
+            Content::Widget(widget) => widget.hash_layout(state),
+            Content::Map(map) => map.hash_layout(state),
+        }
+    }
+
+    fn next_redraw(&self) -> Option<std::time::Instant> {
+        match self {
+            Content::Widget(widget) => widget.next_redraw(),
+            Content::Map(map) => map.next_redraw(),
+        }
+    }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        match self {
+            Content::Widget(widget) => widget.traverse_focus(traversal),
+            Content::Map(map) => map.traverse_focus(traversal),
+        }
+    }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        match self {
+            Content::Widget(widget) => widget.inspect(layout, depth, nodes),
+            Content::Map(map) => map.inspect(layout, depth, nodes),
+        }
+    }
+}
+
+pub(crate) struct Map<'a, B, Renderer> {
+    source: Box<dyn Source<Renderer> + 'a>,
+    // Every mapper applied to the element, composed into one. It takes the
+    // message produced by the source out of the given `Option`.
+    mapper: Mapper<B>,
 }
 
-impl<'a, A, B, Renderer> Map<'a, A, B, Renderer> {
-    pub fn new<F>(
-        widget: Box<dyn Widget<A, Renderer> + 'a>,
-        mapper: F,
-    ) -> Map<'a, A, B, Renderer>
+type Mapper<B> = Box<dyn Fn(&mut dyn Any) -> B>;
+
+impl<'a, B, Renderer> Map<'a, B, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+{
+    fn new<A, F>(widget: Box<dyn Widget<A, Renderer> + 'a>, f: F) -> Self
     where
+        A: 'static,
         F: 'static + Fn(A) -> B,
     {
         Map {
-            widget,
-            mapper: Box::new(mapper),
+            source: Box::new(Origin {
+                widget,
+                buffer: Vec::new(),
+            }),
+            mapper: Box::new(move |message: &mut dyn Any| f(take(message))),
+        }
+    }
+
+    fn then<C, F>(self, f: F) -> Map<'a, C, Renderer>
+    where
+        B: 'static,
+        F: 'static + Fn(B) -> C,
+    {
+        let mapper = self.mapper;
+
+        Map {
+            source: self.source,
+            mapper: Box::new(move |message: &mut dyn Any| f(mapper(message))),
         }
     }
 }
 
-impl<'a, A, B, Renderer> Widget<B, Renderer> for Map<'a, A, B, Renderer>
+fn take<A: 'static>(message: &mut dyn Any) -> A {
+    message
+        .downcast_mut::<Option<A>>()
+        .and_then(Option::take)
+        .expect("Produce a message of the type expected by the mapper")
+}
+
+impl<'a, B, Renderer> Widget<B, Renderer> for Map<'a, B, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.source.width()
+    }
+
+    fn height(&self) -> Length {
+        self.source.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.source.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<B>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let mapper = &self.mapper;
+
+        self.source.on_event(
+            event,
+            layout,
+            cursor_position,
+            &mut |message: &mut dyn Any| messages.push(mapper(message)),
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.source
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.source.hash_layout(state);
+    }
+
+    fn next_redraw(&self) -> Option<std::time::Instant> {
+        self.source.next_redraw()
+    }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        self.source.traverse_focus(traversal);
+    }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        self.source.inspect(layout, depth, nodes)
+    }
+}
+
+/// The widget wrapped by a [`Map`], with the type of its messages erased.
+///
+/// [`Map`]: struct.Map.html
+trait Source<Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length;
+
+    fn height(&self) -> Length;
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node;
+
+    /// Processes an event, giving every message produced to `emit` wrapped
+    /// in an `Option`.
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        emit: &mut dyn FnMut(&mut dyn Any),
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    );
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output;
+
+    fn hash_layout(&self, state: &mut Hasher);
+
+    fn next_redraw(&self) -> Option<std::time::Instant>;
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal);
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    );
+}
+
+struct Origin<'a, A, Renderer> {
+    widget: Box<dyn Widget<A, Renderer> + 'a>,
+    // Reused between events, so producing messages only allocates while the
+    // buffer grows.
+    buffer: Vec<A>,
+}
+
+impl<'a, A, Renderer> Source<Renderer> for Origin<'a, A, Renderer>
 where
+    A: 'static,
     Renderer: crate::Renderer,
 {
     fn width(&self) -> Length {
@@ -295,24 +654,22 @@ where
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
-        messages: &mut Vec<B>,
+        emit: &mut dyn FnMut(&mut dyn Any),
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
     ) {
-        let mut original_messages = Vec::new();
-
         self.widget.on_event(
             event,
             layout,
             cursor_position,
-            &mut original_messages,
+            &mut self.buffer,
             renderer,
             clipboard,
         );
 
-        original_messages
-            .drain(..)
-            .for_each(|message| messages.push((self.mapper)(message)));
+        for message in self.buffer.drain(..) {
+            emit(&mut Some(message));
+        }
     }
 
     fn draw(
@@ -329,6 +686,23 @@ where
     fn hash_layout(&self, state: &mut Hasher) {
         self.widget.hash_layout(state);
     }
+
+    fn next_redraw(&self) -> Option<std::time::Instant> {
+        self.widget.next_redraw()
+    }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        self.widget.traverse_focus(traversal);
+    }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        self.widget.inspect(layout, depth, nodes)
+    }
 }
 
 struct Explain<'a, Message, Renderer: crate::Renderer> {
@@ -375,6 +749,40 @@ where
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
     ) {
+        #[cfg(feature = "debug")]
+        {
+            use crate::input::{mouse, ButtonState};
+
+            if let Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+                modifiers,
+            }) = event
+            {
+                if modifiers.alt {
+                    let mut nodes = Vec::new();
+
+                    self.element.widget.inspect(layout, 0, &mut nodes);
+
+                    if let Some(index) =
+                        layout::WidgetNode::hovered(&nodes, cursor_position)
+                    {
+                        for node in layout::WidgetNode::ancestors(&nodes, index)
+                        {
+                            log::info!(
+                                "{:indent$}{}",
+                                "",
+                                node,
+                                indent = node.depth * 2
+                            );
+                        }
+                    }
+
+                    return;
+                }
+            }
+        }
+
         self.element.widget.on_event(
             event,
             layout,
@@ -392,16 +800,380 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        let mut nodes = Vec::new();
+
+        self.element.widget.inspect(layout, 0, &mut nodes);
+
         renderer.explain(
             defaults,
-            self.element.widget.as_ref(),
+            &self.element.widget,
             layout,
             cursor_position,
             self.color,
+            &nodes,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.element.widget.hash_layout(state);
+    }
+
+    fn next_redraw(&self) -> Option<std::time::Instant> {
+        self.element.widget.next_redraw()
+    }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        self.element.widget.traverse_focus(traversal);
+    }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        self.element.widget.inspect(layout, depth, nodes)
+    }
+}
+
+struct Inspect<'a, Message, Renderer: crate::Renderer> {
+    element: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Inspect<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn new(element: Element<'a, Message, Renderer>) -> Self {
+        Inspect { element }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Inspect<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer + layout::Debugger,
+{
+    fn width(&self) -> Length {
+        self.element.widget.width()
+    }
+
+    fn height(&self) -> Length {
+        self.element.widget.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.element.widget.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.element.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut nodes = Vec::new();
+
+        self.element.widget.inspect(layout, 0, &mut nodes);
+
+        renderer.inspect(
+            defaults,
+            &self.element.widget,
+            layout,
+            cursor_position,
+            &nodes,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.element.widget.hash_layout(state);
+    }
+
+    fn next_redraw(&self) -> Option<std::time::Instant> {
+        self.element.widget.next_redraw()
+    }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        self.element.widget.traverse_focus(traversal);
+    }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        self.element.widget.inspect(layout, depth, nodes)
+    }
+}
+
+struct Hidden<'a, Message, Renderer: crate::Renderer> {
+    element: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Hidden<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn new(element: Element<'a, Message, Renderer>) -> Self {
+        Hidden { element }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Hidden<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer + space::Renderer,
+{
+    fn width(&self) -> Length {
+        self.element.widget.width()
+    }
+
+    fn height(&self) -> Length {
+        self.element.widget.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.element.widget.layout(renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        space::Renderer::draw(renderer, layout.bounds())
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.element.widget.hash_layout(state);
+    }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        self.element.widget.inspect(layout, depth, nodes)
+    }
+}
+
+struct Id<'a, Message, Renderer: crate::Renderer> {
+    element: Element<'a, Message, Renderer>,
+    id: &'static str,
+}
+
+impl<'a, Message, Renderer> Id<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn new(element: Element<'a, Message, Renderer>, id: &'static str) -> Self {
+        Id { element, id }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Id<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.element.widget.width()
+    }
+
+    fn height(&self) -> Length {
+        self.element.widget.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.element.widget.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.element.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
         )
     }
 
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.element
+            .widget
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
     fn hash_layout(&self, state: &mut Hasher) {
         self.element.widget.hash_layout(state);
     }
+
+    fn next_redraw(&self) -> Option<std::time::Instant> {
+        self.element.widget.next_redraw()
+    }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        self.element.widget.traverse_focus(traversal);
+    }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        let first = nodes.len();
+
+        self.element.widget.inspect(layout, depth, nodes);
+
+        // The first node pushed describes the identified widget itself
+        if let Some(node) = nodes.get_mut(first) {
+            node.id = Some(self.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, window, Size};
+
+    struct Emitter;
+
+    impl Widget<u32, Null> for Emitter {
+        fn width(&self) -> Length {
+            Length::Shrink
+        }
+
+        fn height(&self) -> Length {
+            Length::Shrink
+        }
+
+        fn layout(
+            &self,
+            _renderer: &Null,
+            _limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(Size::ZERO)
+        }
+
+        fn on_event(
+            &mut self,
+            _event: Event,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            messages: &mut Vec<u32>,
+            _renderer: &Null,
+            _clipboard: Option<&dyn Clipboard>,
+        ) {
+            messages.push(1);
+        }
+
+        fn draw(
+            &self,
+            _renderer: &mut Null,
+            _defaults: &(),
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+        ) {
+        }
+
+        fn hash_layout(&self, _state: &mut Hasher) {}
+    }
+
+    fn emit<Message>(element: &mut Element<'_, Message, Null>) -> Vec<Message> {
+        let node = layout::Node::new(Size::ZERO);
+        let mut messages = Vec::new();
+
+        element.widget.on_event(
+            Event::Window(window::Event::Resized {
+                width: 0,
+                height: 0,
+            }),
+            Layout::new(&node),
+            Point::ORIGIN,
+            &mut messages,
+            &Null,
+            None,
+        );
+
+        messages
+    }
+
+    fn source<Message>(element: &Element<'_, Message, Null>) -> String {
+        match &element.widget {
+            Content::Map(map) => format!("{:p}", map.source),
+            Content::Widget(_) => panic!("The element is not mapped"),
+        }
+    }
+
+    #[test]
+    fn maps_messages() {
+        let mut element = Element::new(Emitter).map(|n| n * 2);
+
+        assert_eq!(emit(&mut element), vec![2]);
+        assert_eq!(emit(&mut element), vec![2]);
+    }
+
+    #[test]
+    fn fuses_nested_maps() {
+        let element = Element::new(Emitter).map(|n| n + 1);
+        let layer = source(&element);
+
+        let mut element = element.map(|n| n * 10).map(|n| n.to_string());
+
+        assert_eq!(source(&element), layer);
+        assert_eq!(emit(&mut element), vec![String::from("20")]);
+    }
 }
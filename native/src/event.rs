@@ -10,6 +10,7 @@ use crate::{
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
 #[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A keyboard event
     Keyboard(keyboard::Event),
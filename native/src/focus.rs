@@ -0,0 +1,160 @@
+//! Move the keyboard focus between widgets.
+//!
+//! A [`UserInterface`] moves the focus to the next focusable widget when `Tab`
+//! is pressed, and to the previous one when `Shift + Tab` is pressed. Widgets
+//! take part by implementing [`Widget::traverse_focus`].
+//!
+//! [`UserInterface`]: ../struct.UserInterface.html
+//! [`Widget::traverse_focus`]: ../widget/trait.Widget.html#method.traverse_focus
+
+/// The direction in which the focus moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Towards the next focusable widget, in depth-first order
+    Forward,
+
+    /// Towards the previous focusable widget, in depth-first order
+    Backward,
+}
+
+/// A walk over the focusable widgets of a user interface.
+///
+/// The walk happens in two passes. The first pass finds the focused widget,
+/// and the second one moves the focus to its neighbor in the [`Direction`]
+/// of the [`Traversal`]. The focus wraps around both ends.
+///
+/// [`Direction`]: enum.Direction.html
+/// [`Traversal`]: struct.Traversal.html
+#[derive(Debug, Clone, Copy)]
+pub struct Traversal {
+    direction: Direction,
+    pass: Pass,
+    index: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Pass {
+    Locate { focused: Option<usize> },
+    Apply { target: usize },
+}
+
+impl Traversal {
+    /// Creates a new [`Traversal`] in the given [`Direction`].
+    ///
+    /// [`Traversal`]: struct.Traversal.html
+    /// [`Direction`]: enum.Direction.html
+    pub fn new(direction: Direction) -> Traversal {
+        Traversal {
+            direction,
+            pass: Pass::Locate { focused: None },
+            index: 0,
+        }
+    }
+
+    /// Visits a focusable widget, given whether it is focused.
+    ///
+    /// Focusable widgets must call this method exactly once per pass, in the
+    /// same order on both passes.
+    pub fn visit(&mut self, is_focused: &mut bool) {
+        match &mut self.pass {
+            Pass::Locate { focused } => {
+                if *is_focused && focused.is_none() {
+                    *focused = Some(self.index);
+                }
+            }
+            Pass::Apply { target } => {
+                *is_focused = self.index == *target;
+            }
+        }
+
+        self.index += 1;
+    }
+
+    /// Finishes the current pass and returns whether another pass over the
+    /// widgets is needed.
+    pub(crate) fn finish_pass(&mut self) -> bool {
+        let count = self.index;
+        self.index = 0;
+
+        match self.pass {
+            Pass::Locate { .. } if count == 0 => false,
+            Pass::Locate { focused } => {
+                let target = match (self.direction, focused) {
+                    (Direction::Forward, Some(i)) => (i + 1) % count,
+                    (Direction::Forward, None) => 0,
+                    (Direction::Backward, Some(i)) => (i + count - 1) % count,
+                    (Direction::Backward, None) => count - 1,
+                };
+
+                self.pass = Pass::Apply { target };
+
+                true
+            }
+            Pass::Apply { .. } => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn traverse(widgets: &mut [bool], direction: Direction) {
+        let mut traversal = Traversal::new(direction);
+
+        loop {
+            for is_focused in widgets.iter_mut() {
+                traversal.visit(is_focused);
+            }
+
+            if !traversal.finish_pass() {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn moves_forward_and_wraps() {
+        let mut widgets = [false; 3];
+
+        traverse(&mut widgets, Direction::Forward);
+        assert_eq!(widgets, [true, false, false]);
+
+        traverse(&mut widgets, Direction::Forward);
+        assert_eq!(widgets, [false, true, false]);
+
+        traverse(&mut widgets, Direction::Forward);
+        traverse(&mut widgets, Direction::Forward);
+        assert_eq!(widgets, [true, false, false]);
+    }
+
+    #[test]
+    fn moves_backward_and_wraps() {
+        let mut widgets = [false; 3];
+
+        traverse(&mut widgets, Direction::Backward);
+        assert_eq!(widgets, [false, false, true]);
+
+        traverse(&mut widgets, Direction::Backward);
+        assert_eq!(widgets, [false, true, false]);
+
+        traverse(&mut widgets, Direction::Backward);
+        traverse(&mut widgets, Direction::Backward);
+        assert_eq!(widgets, [false, false, true]);
+    }
+
+    #[test]
+    fn keeps_a_single_focus() {
+        let mut widgets = [false, true, true, false];
+
+        traverse(&mut widgets, Direction::Forward);
+        assert_eq!(widgets, [false, false, true, false]);
+    }
+
+    #[test]
+    fn ignores_empty_interfaces() {
+        let mut widgets: [bool; 0] = [];
+
+        traverse(&mut widgets, Direction::Forward);
+    }
+}
@@ -0,0 +1,8 @@
+//! Build gamepad events.
+mod axis;
+mod button;
+mod event;
+
+pub use axis::Axis;
+pub use button::Button;
+pub use event::Event;
@@ -0,0 +1,34 @@
+/// An axis of a gamepad.
+///
+/// This is mostly the `Axis` type found in [`gilrs`].
+///
+/// [`gilrs`]: https://docs.rs/gilrs/0.7/gilrs/
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum Axis {
+    /// The horizontal axis of the left stick.
+    LeftStickX,
+
+    /// The vertical axis of the left stick.
+    LeftStickY,
+
+    /// The left analog trigger.
+    LeftZ,
+
+    /// The horizontal axis of the right stick.
+    RightStickX,
+
+    /// The vertical axis of the right stick.
+    RightStickY,
+
+    /// The right analog trigger.
+    RightZ,
+
+    /// The horizontal axis of the directional pad.
+    DPadX,
+
+    /// The vertical axis of the directional pad.
+    DPadY,
+
+    /// Some other axis.
+    Other,
+}
@@ -0,0 +1,67 @@
+/// The button of a gamepad.
+///
+/// This is mostly the `Button` type found in [`gilrs`].
+///
+/// [`gilrs`]: https://docs.rs/gilrs/0.7/gilrs/
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum Button {
+    /// The bottom action button (e.g. A on Xbox controllers).
+    South,
+
+    /// The right action button (e.g. B on Xbox controllers).
+    East,
+
+    /// The top action button (e.g. Y on Xbox controllers).
+    North,
+
+    /// The left action button (e.g. X on Xbox controllers).
+    West,
+
+    /// The C button, found in some older controllers.
+    C,
+
+    /// The Z button, found in some older controllers.
+    Z,
+
+    /// The left bumper.
+    LeftTrigger,
+
+    /// The left trigger.
+    LeftTrigger2,
+
+    /// The right bumper.
+    RightTrigger,
+
+    /// The right trigger.
+    RightTrigger2,
+
+    /// The select (or back) button.
+    Select,
+
+    /// The start button.
+    Start,
+
+    /// The mode (or guide) button.
+    Mode,
+
+    /// The left stick, when pressed.
+    LeftThumb,
+
+    /// The right stick, when pressed.
+    RightThumb,
+
+    /// The up button of the directional pad.
+    DPadUp,
+
+    /// The down button of the directional pad.
+    DPadDown,
+
+    /// The left button of the directional pad.
+    DPadLeft,
+
+    /// The right button of the directional pad.
+    DPadRight,
+
+    /// Some other button.
+    Other,
+}
@@ -0,0 +1,45 @@
+use super::{Axis, Button};
+use crate::input::ButtonState;
+
+/// A gamepad event.
+///
+/// Every event carries the `id` of the gamepad that produced it, which allows
+/// telling apart multiple connected controllers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// A gamepad was connected.
+    Connected {
+        /// The identifier of the gamepad
+        id: usize,
+    },
+
+    /// A gamepad was disconnected.
+    Disconnected {
+        /// The identifier of the gamepad
+        id: usize,
+    },
+
+    /// A button of a gamepad was pressed or released.
+    Input {
+        /// The identifier of the gamepad
+        id: usize,
+
+        /// The button identifier
+        button: Button,
+
+        /// The state of the button
+        state: ButtonState,
+    },
+
+    /// An axis of a gamepad changed its value.
+    AxisChanged {
+        /// The identifier of the gamepad
+        id: usize,
+
+        /// The axis identifier
+        axis: Axis,
+
+        /// The new value of the axis, from `-1.0` to `1.0`
+        value: f32,
+    },
+}
@@ -8,15 +8,29 @@ use crate::input::ButtonState;
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A keyboard key was pressed or released.
+    ///
+    /// The text produced by the key, if any, is notified right after with a
+    /// [`CharacterReceived`] event.
+    ///
+    /// [`CharacterReceived`]: #variant.CharacterReceived
     Input {
         /// The state of the key
         state: ButtonState,
 
-        /// The key identifier
+        /// The key identifier, which depends on the keyboard layout
         key_code: KeyCode,
 
+        /// The physical scan code of the key, which does not depend on the
+        /// keyboard layout
+        scan_code: u32,
+
+        /// Whether the key press was produced by auto-repeat while the key
+        /// was being held down
+        repeat: bool,
+
         /// The state of the modifier keys
         modifiers: ModifiersState,
     },
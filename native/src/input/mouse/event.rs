@@ -1,5 +1,5 @@
 use super::Button;
-use crate::input::ButtonState;
+use crate::input::{keyboard::ModifiersState, ButtonState};
 
 /// A mouse event.
 ///
@@ -8,6 +8,7 @@ use crate::input::ButtonState;
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// The mouse cursor entered the window.
     CursorEntered,
@@ -24,6 +25,22 @@ pub enum Event {
         y: f32,
     },
 
+    /// The mouse device was moved.
+    ///
+    /// Unlike [`CursorMoved`], this event carries the raw relative motion
+    /// reported by the device. It is not affected by cursor acceleration and
+    /// it keeps being produced while the cursor is grabbed, which makes it
+    /// useful to implement mouse-look in 3D viewports.
+    ///
+    /// [`CursorMoved`]: #variant.CursorMoved
+    Motion {
+        /// The horizontal motion of the device
+        delta_x: f32,
+
+        /// The vertical motion of the device
+        delta_y: f32,
+    },
+
     /// A mouse button was pressed or released.
     Input {
         /// The state of the button
@@ -31,6 +48,9 @@ pub enum Event {
 
         /// The button identifier
         button: Button,
+
+        /// The state of the keyboard modifiers when the button changed
+        modifiers: ModifiersState,
     },
 
     /// The mouse wheel was scrolled.
@@ -42,6 +62,7 @@ pub enum Event {
 
 /// A scroll movement.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScrollDelta {
     /// A line-based scroll movement
     Lines {
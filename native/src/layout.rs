@@ -2,12 +2,14 @@
 mod debugger;
 mod limits;
 mod node;
+mod widget_node;
 
 pub mod flex;
 
 pub use debugger::Debugger;
 pub use limits::Limits;
 pub use node::Node;
+pub use widget_node::WidgetNode;
 
 use crate::{Point, Rectangle, Vector};
 
@@ -1,4 +1,4 @@
-use crate::{Color, Layout, Point, Renderer, Widget};
+use crate::{layout::WidgetNode, Color, Layout, Point, Renderer, Widget};
 
 /// A renderer able to graphically explain a [`Layout`].
 ///
@@ -10,11 +10,13 @@ pub trait Debugger: Renderer {
     /// _explain_ the given [`Layout`] graphically.
     ///
     /// A common approach consists in recursively rendering the bounds of the
-    /// [`Layout`] and its children.
+    /// [`Layout`] and its children, and describing the deepest of the
+    /// provided [`WidgetNode`] under the cursor.
     ///
     /// [`Layout`]: struct.Layout.html
     /// [`Element`]: ../struct.Element.html
     /// [`Element::explain`]: ../struct.Element.html#method.explain
+    /// [`WidgetNode`]: struct.WidgetNode.html
     fn explain<Message>(
         &mut self,
         defaults: &Self::Defaults,
@@ -22,5 +24,28 @@ pub trait Debugger: Renderer {
         layout: Layout<'_>,
         cursor_position: Point,
         color: Color,
+        nodes: &[WidgetNode],
     ) -> Self::Output;
+
+    /// Inspects the widget tree of an [`Element`] for debugging purposes.
+    ///
+    /// This will be called when [`Element::inspect`] has been used. It should
+    /// draw the given widget and overlay the provided list of [`WidgetNode`]
+    /// on top of it, highlighting the deepest node under the cursor.
+    ///
+    /// By default, it draws the widget without any overlay.
+    ///
+    /// [`Element`]: ../struct.Element.html
+    /// [`Element::inspect`]: ../struct.Element.html#method.inspect
+    /// [`WidgetNode`]: struct.WidgetNode.html
+    fn inspect<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        widget: &dyn Widget<Message, Self>,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _nodes: &[WidgetNode],
+    ) -> Self::Output {
+        widget.draw(self, defaults, layout, cursor_position)
+    }
 }
@@ -0,0 +1,169 @@
+use crate::{Length, Point, Rectangle};
+
+use std::fmt;
+
+/// A snapshot of a widget in the widget tree, used to inspect a user
+/// interface for debugging purposes.
+///
+/// A list of [`WidgetNode`] can be obtained by calling [`Widget::inspect`].
+///
+/// [`WidgetNode`]: struct.WidgetNode.html
+/// [`Widget::inspect`]: ../trait.Widget.html#method.inspect
+#[derive(Debug, Clone, PartialEq)]
+pub struct WidgetNode {
+    /// The name of the type of the widget, without its module path and
+    /// generic parameters.
+    pub name: &'static str,
+
+    /// The depth of the widget in the widget tree.
+    ///
+    /// The root widget has a depth of `0`.
+    pub depth: usize,
+
+    /// The bounds of the widget, in absolute coordinates.
+    pub bounds: Rectangle,
+
+    /// The width of the widget.
+    pub width: Length,
+
+    /// The height of the widget.
+    pub height: Length,
+
+    /// The padding of the widget, in units.
+    pub padding: u16,
+
+    /// The identifier given to the widget with [`Element::id`], if any.
+    ///
+    /// [`Element::id`]: ../struct.Element.html#method.id
+    pub id: Option<&'static str>,
+
+    /// The text describing the widget to the user, if any.
+    ///
+    /// For instance, the content of a `Text` or the label of a `Checkbox`.
+    pub label: Option<String>,
+}
+
+impl WidgetNode {
+    /// Creates a new [`WidgetNode`] for a widget of type `T` with the given
+    /// depth, bounds, and dimensions.
+    ///
+    /// The padding of the [`WidgetNode`] is `0` by default, and it has no
+    /// id nor label.
+    ///
+    /// [`WidgetNode`]: struct.WidgetNode.html
+    pub fn new<T: ?Sized>(
+        depth: usize,
+        bounds: Rectangle,
+        width: Length,
+        height: Length,
+    ) -> Self {
+        WidgetNode {
+            name: short_type_name::<T>(),
+            depth,
+            bounds,
+            width,
+            height,
+            padding: 0,
+            id: None,
+            label: None,
+        }
+    }
+
+    /// Sets the padding of the [`WidgetNode`].
+    ///
+    /// [`WidgetNode`]: struct.WidgetNode.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the label of the [`WidgetNode`].
+    ///
+    /// [`WidgetNode`]: struct.WidgetNode.html
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Returns the index of the deepest [`WidgetNode`] under the given
+    /// cursor position, if any.
+    ///
+    /// The nodes must be sorted in depth-first order, as produced by
+    /// [`Widget::inspect`].
+    ///
+    /// [`WidgetNode`]: struct.WidgetNode.html
+    /// [`Widget::inspect`]: ../trait.Widget.html#method.inspect
+    pub fn hovered(
+        nodes: &[WidgetNode],
+        cursor_position: Point,
+    ) -> Option<usize> {
+        // The last node with the greatest depth is the innermost one
+        nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.bounds.contains(cursor_position))
+            .max_by_key(|(_, node)| node.depth)
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the chain of ancestors of the [`WidgetNode`] at the given
+    /// index, starting from the root and ending with the node itself.
+    ///
+    /// The nodes must be sorted in depth-first order, as produced by
+    /// [`Widget::inspect`].
+    ///
+    /// [`WidgetNode`]: struct.WidgetNode.html
+    /// [`Widget::inspect`]: ../trait.Widget.html#method.inspect
+    pub fn ancestors(nodes: &[WidgetNode], index: usize) -> Vec<&WidgetNode> {
+        let mut chain = vec![&nodes[index]];
+        let mut depth = nodes[index].depth;
+
+        for node in nodes[..index].iter().rev() {
+            if depth == 0 {
+                break;
+            }
+
+            if node.depth == depth - 1 {
+                chain.push(node);
+                depth = node.depth;
+            }
+        }
+
+        chain.reverse();
+        chain
+    }
+}
+
+impl fmt::Display for WidgetNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}x{} at ({}, {}) [width: {:?}, height: {:?}, padding: {}]",
+            self.name,
+            self.bounds.width,
+            self.bounds.height,
+            self.bounds.x,
+            self.bounds.y,
+            self.width,
+            self.height,
+            self.padding,
+        )?;
+
+        if let Some(id) = self.id {
+            write!(f, " #{}", id)?;
+        }
+
+        if let Some(label) = &self.label {
+            write!(f, " {:?}", label)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn short_type_name<T: ?Sized>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+
+    name.rsplit("::").next().unwrap_or(name)
+}
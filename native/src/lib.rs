@@ -39,10 +39,13 @@
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
+pub mod focus;
 pub mod input;
 pub mod layout;
+pub mod program;
 pub mod renderer;
 pub mod subscription;
+pub mod time;
 pub mod widget;
 pub mod window;
 
@@ -69,6 +72,7 @@ pub use event::Event;
 pub use hasher::Hasher;
 pub use layout::Layout;
 pub use mouse_cursor::MouseCursor;
+pub use program::Program;
 pub use renderer::Renderer;
 pub use runtime::Runtime;
 pub use subscription::Subscription;
@@ -0,0 +1,183 @@
+use crate::{
+    Cache, Clipboard, Command, Event, Program, Renderer, Size, UserInterface,
+};
+
+/// The execution state of a [`Program`]. It leverages caching, event
+/// processing, and rendering primitive storage.
+///
+/// [`Program`]: trait.Program.html
+#[allow(missing_debug_implementations)]
+pub struct State<P>
+where
+    P: Program,
+{
+    program: P,
+    cache: Option<Cache>,
+    primitive: <P::Renderer as Renderer>::Output,
+    queued_events: Vec<Event>,
+    queued_messages: Vec<P::Message>,
+}
+
+impl<P> State<P>
+where
+    P: Program,
+{
+    /// Creates a new [`State`] with the provided [`Program`], initializing its
+    /// primitive with the given logical bounds and renderer.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Program`]: trait.Program.html
+    pub fn new(
+        mut program: P,
+        bounds: Size,
+        renderer: &mut P::Renderer,
+    ) -> Self {
+        let user_interface = UserInterface::build(
+            program.view(),
+            bounds,
+            Cache::default(),
+            renderer,
+        );
+
+        let primitive = user_interface.draw(renderer);
+        let cache = Some(user_interface.into_cache());
+
+        State {
+            program,
+            cache,
+            primitive,
+            queued_events: Vec::new(),
+            queued_messages: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the [`Program`] of the [`State`].
+    ///
+    /// [`Program`]: trait.Program.html
+    /// [`State`]: struct.State.html
+    pub fn program(&self) -> &P {
+        &self.program
+    }
+
+    /// Returns a reference to the current rendering primitive of the
+    /// [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn primitive(&self) -> &<P::Renderer as Renderer>::Output {
+        &self.primitive
+    }
+
+    /// Queues an event in the [`State`] for processing during an [`update`].
+    ///
+    /// Embedders are expected to convert the events of the host window into
+    /// iced events before queuing them. An [`Event`] is plain data, so hosts
+    /// that do not use `winit` can build it directly. Positions are logical
+    /// and relative to the top-left corner of the surface:
+    ///
+    /// ```
+    /// use iced_native::{
+    ///     input::{keyboard, mouse, ButtonState},
+    ///     Event,
+    /// };
+    ///
+    /// let events = vec![
+    ///     Event::Mouse(mouse::Event::CursorMoved { x: 120.0, y: 40.0 }),
+    ///     Event::Mouse(mouse::Event::Input {
+    ///         state: ButtonState::Pressed,
+    ///         button: mouse::Button::Left,
+    ///         modifiers: keyboard::ModifiersState::default(),
+    ///     }),
+    ///     Event::Keyboard(keyboard::Event::CharacterReceived('a')),
+    /// ];
+    /// ```
+    ///
+    /// [`State`]: struct.State.html
+    /// [`update`]: #method.update
+    /// [`Event`]: ../enum.Event.html
+    pub fn queue_event(&mut self, event: Event) {
+        self.queued_events.push(event);
+    }
+
+    /// Queues a message in the [`State`] for processing during an [`update`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`update`]: #method.update
+    pub fn queue_message(&mut self, message: P::Message) {
+        self.queued_messages.push(message);
+    }
+
+    /// Returns whether the event queue and the message queue of the [`State`]
+    /// are empty.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn is_queue_empty(&self) -> bool {
+        self.queued_events.is_empty() && self.queued_messages.is_empty()
+    }
+
+    /// Processes all the queued events and messages, rebuilding and redrawing
+    /// the widgets of the linked [`Program`] if necessary.
+    ///
+    /// Returns the [`Command`] obtained from [`Program`] after updating it,
+    /// only if an update was necessary.
+    ///
+    /// [`Program`]: trait.Program.html
+    /// [`Command`]: struct.Command.html
+    pub fn update(
+        &mut self,
+        bounds: Size,
+        renderer: &mut P::Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> Option<Command<P::Message>> {
+        let mut user_interface = build_user_interface(
+            &mut self.program,
+            self.cache.take().unwrap(),
+            renderer,
+            bounds,
+        );
+
+        let mut messages = user_interface.update(
+            self.queued_events.drain(..),
+            clipboard,
+            renderer,
+        );
+        messages.append(&mut self.queued_messages);
+
+        if messages.is_empty() {
+            self.primitive = user_interface.draw(renderer);
+            self.cache = Some(user_interface.into_cache());
+
+            None
+        } else {
+            // When there are messages, we are forced to rebuild twice
+            // for now :^)
+            let temp_cache = user_interface.into_cache();
+
+            let commands = Command::batch(
+                messages
+                    .into_iter()
+                    .map(|message| self.program.update(message)),
+            );
+
+            let user_interface = build_user_interface(
+                &mut self.program,
+                temp_cache,
+                renderer,
+                bounds,
+            );
+
+            self.primitive = user_interface.draw(renderer);
+            self.cache = Some(user_interface.into_cache());
+
+            Some(commands)
+        }
+    }
+}
+
+fn build_user_interface<'a, P: Program>(
+    program: &'a mut P,
+    cache: Cache,
+    renderer: &mut P::Renderer,
+    size: Size,
+) -> UserInterface<'a, P::Message, P::Renderer> {
+    UserInterface::build(program.view(), size, cache, renderer)
+}
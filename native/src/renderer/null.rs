@@ -81,20 +81,18 @@ impl scrollable::Renderer for Null {
         _bounds: Rectangle,
         _content_bounds: Rectangle,
         _offset: u32,
+        _style: &Self::Style,
     ) -> Option<scrollable::Scrollbar> {
         None
     }
 
     fn draw(
         &mut self,
-        _scrollable: &scrollable::State,
         _bounds: Rectangle,
         _content_bounds: Rectangle,
         _is_mouse_over: bool,
-        _is_mouse_over_scrollbar: bool,
-        _scrollbar: Option<scrollable::Scrollbar>,
         _offset: u32,
-        _style: &Self::Style,
+        _scrollbar: scrollable::ScrollbarState<'_, Self::Style>,
         _content: Self::Output,
     ) {
     }
@@ -149,6 +147,7 @@ impl button::Renderer for Null {
         _cursor_position: Point,
         _is_disabled: bool,
         _is_pressed: bool,
+        _is_focused: bool,
         _style: &Self::Style,
         _content: &Element<'_, Message, Self>,
         _content_layout: Layout<'_>,
@@ -168,6 +167,7 @@ impl radio::Renderer for Null {
         _bounds: Rectangle,
         _is_selected: bool,
         _is_mouse_over: bool,
+        _is_focused: bool,
         _label: Self::Output,
         _style: &Self::Style,
     ) {
@@ -185,6 +185,7 @@ impl checkbox::Renderer for Null {
         _bounds: Rectangle,
         _is_checked: bool,
         _is_mouse_over: bool,
+        _is_focused: bool,
         _label: Self::Output,
         _style: &Self::Style,
     ) {
@@ -204,7 +205,7 @@ impl slider::Renderer for Null {
         _cursor_position: Point,
         _range: std::ops::RangeInclusive<f32>,
         _value: f32,
-        _is_dragging: bool,
+        _state: &slider::State,
         _style_sheet: &Self::Style,
     ) {
     }
@@ -32,8 +32,13 @@ pub type Tracker = iced_futures::subscription::Tracker<Hasher, Event>;
 pub use iced_futures::subscription::Recipe;
 
 mod events;
+mod file_changes;
+
+#[cfg(feature = "gamepad")]
+mod gamepad_events;
 
 use events::Events;
+use file_changes::FileChanges;
 
 /// Returns a [`Subscription`] to all the runtime events.
 ///
@@ -45,3 +50,30 @@ use events::Events;
 pub fn events() -> Subscription<Event> {
     Subscription::from_recipe(Events)
 }
+
+/// Returns a [`Subscription`] that produces the given path every time the
+/// file it points to is modified, created, or removed.
+///
+/// The file is polled in a background thread for as long as the
+/// [`Subscription`] is kept alive. It can be used to reload assets, like a
+/// [`Theme`], while the application is running.
+///
+/// [`Subscription`]: type.Subscription.html
+/// [`Theme`]: ../../iced_style/theme/struct.Theme.html
+pub fn file_changes(
+    path: impl Into<std::path::PathBuf>,
+) -> Subscription<std::path::PathBuf> {
+    Subscription::from_recipe(FileChanges(path.into()))
+}
+
+/// Returns a [`Subscription`] to the events of all the connected gamepads.
+///
+/// The gamepads are polled in a background thread, using [`gilrs`], for as
+/// long as the [`Subscription`] is kept alive.
+///
+/// [`Subscription`]: type.Subscription.html
+/// [`gilrs`]: https://gitlab.com/gilrs-project/gilrs
+#[cfg(feature = "gamepad")]
+pub fn gamepad() -> Subscription<crate::input::gamepad::Event> {
+    Subscription::from_recipe(gamepad_events::Gamepad)
+}
@@ -0,0 +1,63 @@
+use crate::{
+    subscription::{EventStream, Recipe},
+    Hasher,
+};
+use iced_futures::futures::{
+    channel::mpsc,
+    stream::{BoxStream, StreamExt},
+};
+
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+/// The interval between two consecutive checks of the watched file.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+pub struct FileChanges(pub PathBuf);
+
+impl Recipe<Hasher, crate::Event> for FileChanges {
+    type Output = PathBuf;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.0.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: EventStream,
+    ) -> BoxStream<'static, Self::Output> {
+        let (sender, receiver) = mpsc::unbounded();
+        let path = self.0;
+
+        // We poll the modification time of the file in a background thread
+        // and stop once the subscription is dropped.
+        let _ = std::thread::spawn(move || {
+            let modified = |path: &PathBuf| -> Option<SystemTime> {
+                std::fs::metadata(path)
+                    .and_then(|metadata| metadata.modified())
+                    .ok()
+            };
+
+            let mut last_modified = modified(&path);
+
+            while !sender.is_closed() {
+                std::thread::sleep(POLL_INTERVAL);
+
+                let new_modified = modified(&path);
+
+                if new_modified != last_modified {
+                    last_modified = new_modified;
+
+                    if sender.unbounded_send(path.clone()).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        receiver.boxed()
+    }
+}
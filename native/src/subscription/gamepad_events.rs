@@ -0,0 +1,126 @@
+use crate::{
+    input::{gamepad, ButtonState},
+    subscription::{EventStream, Recipe},
+    Hasher,
+};
+use iced_futures::futures::{
+    channel::mpsc,
+    stream::{BoxStream, StreamExt},
+};
+
+/// The interval between two consecutive polls of the connected gamepads.
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(8);
+
+pub struct Gamepad;
+
+impl Recipe<Hasher, crate::Event> for Gamepad {
+    type Output = gamepad::Event;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: EventStream,
+    ) -> BoxStream<'static, Self::Output> {
+        let (sender, receiver) = mpsc::unbounded();
+
+        // `Gilrs` is not `Send` on every platform, so we keep it in its own
+        // thread and stop polling once the subscription is dropped.
+        let _ = std::thread::spawn(move || {
+            let mut gilrs = match gilrs::Gilrs::new() {
+                Ok(gilrs) => gilrs,
+                Err(error) => {
+                    log::warn!("Could not initialize gamepads: {}", error);
+
+                    return;
+                }
+            };
+
+            while !sender.is_closed() {
+                while let Some(gilrs::Event { id, event, .. }) =
+                    gilrs.next_event()
+                {
+                    if let Some(event) = convert(usize::from(id), event) {
+                        if sender.unbounded_send(event).is_err() {
+                            return;
+                        }
+                    }
+                }
+
+                std::thread::sleep(POLL_INTERVAL);
+            }
+        });
+
+        receiver.boxed()
+    }
+}
+
+fn convert(id: usize, event: gilrs::EventType) -> Option<gamepad::Event> {
+    use gilrs::EventType;
+
+    match event {
+        EventType::Connected => Some(gamepad::Event::Connected { id }),
+        EventType::Disconnected => Some(gamepad::Event::Disconnected { id }),
+        EventType::ButtonPressed(button, _) => Some(gamepad::Event::Input {
+            id,
+            button: button_identifier(button),
+            state: ButtonState::Pressed,
+        }),
+        EventType::ButtonReleased(button, _) => Some(gamepad::Event::Input {
+            id,
+            button: button_identifier(button),
+            state: ButtonState::Released,
+        }),
+        EventType::AxisChanged(axis, value, _) => {
+            Some(gamepad::Event::AxisChanged {
+                id,
+                axis: axis_identifier(axis),
+                value,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn button_identifier(button: gilrs::Button) -> gamepad::Button {
+    match button {
+        gilrs::Button::South => gamepad::Button::South,
+        gilrs::Button::East => gamepad::Button::East,
+        gilrs::Button::North => gamepad::Button::North,
+        gilrs::Button::West => gamepad::Button::West,
+        gilrs::Button::C => gamepad::Button::C,
+        gilrs::Button::Z => gamepad::Button::Z,
+        gilrs::Button::LeftTrigger => gamepad::Button::LeftTrigger,
+        gilrs::Button::LeftTrigger2 => gamepad::Button::LeftTrigger2,
+        gilrs::Button::RightTrigger => gamepad::Button::RightTrigger,
+        gilrs::Button::RightTrigger2 => gamepad::Button::RightTrigger2,
+        gilrs::Button::Select => gamepad::Button::Select,
+        gilrs::Button::Start => gamepad::Button::Start,
+        gilrs::Button::Mode => gamepad::Button::Mode,
+        gilrs::Button::LeftThumb => gamepad::Button::LeftThumb,
+        gilrs::Button::RightThumb => gamepad::Button::RightThumb,
+        gilrs::Button::DPadUp => gamepad::Button::DPadUp,
+        gilrs::Button::DPadDown => gamepad::Button::DPadDown,
+        gilrs::Button::DPadLeft => gamepad::Button::DPadLeft,
+        gilrs::Button::DPadRight => gamepad::Button::DPadRight,
+        gilrs::Button::Unknown => gamepad::Button::Other,
+    }
+}
+
+fn axis_identifier(axis: gilrs::Axis) -> gamepad::Axis {
+    match axis {
+        gilrs::Axis::LeftStickX => gamepad::Axis::LeftStickX,
+        gilrs::Axis::LeftStickY => gamepad::Axis::LeftStickY,
+        gilrs::Axis::LeftZ => gamepad::Axis::LeftZ,
+        gilrs::Axis::RightStickX => gamepad::Axis::RightStickX,
+        gilrs::Axis::RightStickY => gamepad::Axis::RightStickY,
+        gilrs::Axis::RightZ => gamepad::Axis::RightZ,
+        gilrs::Axis::DPadX => gamepad::Axis::DPadX,
+        gilrs::Axis::DPadY => gamepad::Axis::DPadY,
+        gilrs::Axis::Unknown => gamepad::Axis::Other,
+    }
+}
@@ -0,0 +1,361 @@
+//! Keep track of time in a way that can be controlled.
+//!
+//! The runtime, animations, and time-based subscriptions should obtain the
+//! current time using [`now`] instead of calling `Instant::now` directly. By
+//! default, [`now`] follows the system clock. However, a manual [`Clock`] can
+//! be installed with [`set_clock`] to drive time deterministically in tests
+//! and replays.
+//!
+//! The current [`Clock`] is stored per thread. This way, tests running in
+//! parallel do not interfere with each other.
+//!
+//! [`now`]: fn.now.html
+//! [`Clock`]: struct.Clock.html
+//! [`set_clock`]: fn.set_clock.html
+use crate::Subscription;
+
+use iced_futures::futures::task::{Context, Poll, Waker};
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+mod every;
+
+thread_local! {
+    static CLOCK: RefCell<Clock> = RefCell::new(Clock::system());
+}
+
+/// A source of the current time.
+///
+/// A [`Clock`] is either the system clock or a manual clock that only moves
+/// forward when told to. Cloning a [`Clock`] produces a handle to the same
+/// clock.
+///
+/// [`Clock`]: struct.Clock.html
+#[derive(Debug, Clone)]
+pub struct Clock {
+    manual: Option<Arc<Manual>>,
+}
+
+#[derive(Debug)]
+struct Manual {
+    id: u64,
+    state: Mutex<ManualState>,
+}
+
+#[derive(Debug)]
+struct ManualState {
+    now: Instant,
+    wakers: Vec<Waker>,
+}
+
+impl Clock {
+    /// Creates a [`Clock`] that follows the system clock.
+    ///
+    /// [`Clock`]: struct.Clock.html
+    pub fn system() -> Clock {
+        Clock { manual: None }
+    }
+
+    /// Creates a manual [`Clock`] stopped at the given [`Instant`].
+    ///
+    /// [`Clock`]: struct.Clock.html
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    pub fn manual(start: Instant) -> Clock {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Clock {
+            manual: Some(Arc::new(Manual {
+                id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+                state: Mutex::new(ManualState {
+                    now: start,
+                    wakers: Vec::new(),
+                }),
+            })),
+        }
+    }
+
+    /// Returns true if the [`Clock`] is a manual clock.
+    ///
+    /// [`Clock`]: struct.Clock.html
+    pub fn is_manual(&self) -> bool {
+        self.manual.is_some()
+    }
+
+    /// Returns the current time of the [`Clock`].
+    ///
+    /// [`Clock`]: struct.Clock.html
+    pub fn now(&self) -> Instant {
+        match &self.manual {
+            Some(manual) => manual.state.lock().unwrap().now,
+            None => Instant::now(),
+        }
+    }
+
+    /// Moves a manual [`Clock`] forward by the given [`Duration`].
+    ///
+    /// It has no effect on the system clock.
+    ///
+    /// [`Clock`]: struct.Clock.html
+    /// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    pub fn advance(&self, duration: Duration) {
+        if let Some(manual) = &self.manual {
+            let mut state = manual.state.lock().unwrap();
+            state.now += duration;
+
+            state.wake();
+        }
+    }
+
+    /// Moves a manual [`Clock`] forward to the given [`Instant`].
+    ///
+    /// A [`Clock`] never goes backwards. Therefore, nothing happens if the
+    /// [`Instant`] is in the past. It has no effect on the system clock.
+    ///
+    /// [`Clock`]: struct.Clock.html
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    pub fn advance_to(&self, instant: Instant) {
+        if let Some(manual) = &self.manual {
+            let mut state = manual.state.lock().unwrap();
+
+            if instant > state.now {
+                state.now = instant;
+
+                state.wake();
+            }
+        }
+    }
+
+    /// Returns an identifier that is shared by every handle to the same
+    /// [`Clock`].
+    ///
+    /// [`Clock`]: struct.Clock.html
+    fn id(&self) -> Option<u64> {
+        self.manual.as_ref().map(|manual| manual.id)
+    }
+
+    /// Returns a future that completes once the [`Clock`] reaches the given
+    /// deadline.
+    ///
+    /// The system clock relies on a shared timer. A manual clock completes
+    /// the future when it is advanced past the deadline.
+    ///
+    /// [`Clock`]: struct.Clock.html
+    fn sleep_until(&self, deadline: Instant) -> Sleep {
+        match &self.manual {
+            Some(manual) => Sleep::Manual {
+                manual: manual.clone(),
+                deadline,
+            },
+            None => Sleep::System(futures_timer::Delay::new(
+                deadline.saturating_duration_since(Instant::now()),
+            )),
+        }
+    }
+}
+
+impl ManualState {
+    fn wake(&mut self) {
+        for waker in self.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+enum Sleep {
+    Manual {
+        manual: Arc<Manual>,
+        deadline: Instant,
+    },
+    System(futures_timer::Delay),
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+        match self.get_mut() {
+            Sleep::Manual { manual, deadline } => {
+                let mut state = manual.state.lock().unwrap();
+
+                if state.now >= *deadline {
+                    Poll::Ready(())
+                } else {
+                    let waker = context.waker();
+
+                    if !state.wakers.iter().any(|w| w.will_wake(waker)) {
+                        state.wakers.push(waker.clone());
+                    }
+
+                    Poll::Pending
+                }
+            }
+            Sleep::System(delay) => Pin::new(delay).poll(context),
+        }
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Clock::system()
+    }
+}
+
+/// Returns the current time, according to the [`Clock`] of the current
+/// thread.
+///
+/// [`Clock`]: struct.Clock.html
+pub fn now() -> Instant {
+    CLOCK.with(|clock| clock.borrow().now())
+}
+
+/// Returns a handle to the [`Clock`] of the current thread.
+///
+/// [`Clock`]: struct.Clock.html
+pub fn clock() -> Clock {
+    CLOCK.with(|clock| clock.borrow().clone())
+}
+
+/// Replaces the [`Clock`] of the current thread, returning the previous one.
+///
+/// [`Clock`]: struct.Clock.html
+pub fn set_clock(clock: Clock) -> Clock {
+    CLOCK.with(|current| current.replace(clock))
+}
+
+/// Returns a [`Subscription`] that produces an [`Instant`] every time the
+/// given [`Duration`] passes.
+///
+/// The ticks follow the [`Clock`] of the thread creating the
+/// [`Subscription`]. When it is a manual [`Clock`], ticks are only produced
+/// when it is advanced, and the produced instants are exact multiples of the
+/// [`Duration`] since the [`Subscription`] started.
+///
+/// Ticks are never produced back to back. If the [`Clock`] moves past many
+/// ticks at once (for instance, after the system is suspended), only the
+/// latest one is produced.
+///
+/// [`Subscription`]: ../subscription/type.Subscription.html
+/// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+/// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+/// [`Clock`]: struct.Clock.html
+pub fn every(duration: Duration) -> Subscription<Instant> {
+    Subscription::from_recipe(every::Every {
+        duration,
+        clock: clock(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::Recipe;
+    use iced_futures::futures::{
+        executor::block_on,
+        stream::{self, StreamExt},
+        FutureExt,
+    };
+    use std::hash::Hasher as _;
+
+    const TICK: Duration = Duration::from_millis(100);
+
+    fn ticks(clock: &Clock) -> stream::BoxStream<'static, Instant> {
+        let every = every::Every {
+            duration: TICK,
+            clock: clock.clone(),
+        };
+
+        Box::new(every).stream(stream::empty().boxed())
+    }
+
+    fn hash(clock: &Clock) -> u64 {
+        let every = every::Every {
+            duration: TICK,
+            clock: clock.clone(),
+        };
+        let mut hasher = crate::Hasher::default();
+
+        every.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn manual_clock_only_moves_forward() {
+        let start = Instant::now();
+        let clock = Clock::manual(start);
+
+        assert!(clock.is_manual());
+        assert_eq!(clock.now(), start);
+
+        clock.advance(TICK);
+        assert_eq!(clock.now(), start + TICK);
+
+        clock.advance_to(start);
+        assert_eq!(clock.now(), start + TICK);
+
+        clock.clone().advance_to(start + TICK * 3);
+        assert_eq!(clock.now(), start + TICK * 3);
+    }
+
+    #[test]
+    fn set_clock_replaces_the_clock_of_the_thread() {
+        let start = Instant::now();
+        let clock = Clock::manual(start);
+
+        let previous = set_clock(clock.clone());
+        assert!(!previous.is_manual());
+        assert_eq!(now(), start);
+
+        clock.advance(TICK);
+        assert_eq!(now(), start + TICK);
+        assert_eq!(super::clock().now(), start + TICK);
+
+        let _ = set_clock(previous);
+        assert!(!super::clock().is_manual());
+    }
+
+    #[test]
+    fn every_ticks_when_a_manual_clock_advances() {
+        let start = Instant::now();
+        let clock = Clock::manual(start);
+        let mut ticks = ticks(&clock);
+
+        assert_eq!(ticks.next().now_or_never(), None);
+
+        clock.advance(TICK / 2);
+        assert_eq!(ticks.next().now_or_never(), None);
+
+        clock.advance(TICK / 2);
+        assert_eq!(block_on(ticks.next()), Some(start + TICK));
+        assert_eq!(ticks.next().now_or_never(), None);
+    }
+
+    #[test]
+    fn every_skips_missed_ticks() {
+        let start = Instant::now();
+        let clock = Clock::manual(start);
+        let mut ticks = ticks(&clock);
+
+        clock.advance(TICK * 5 + TICK / 2);
+
+        assert_eq!(block_on(ticks.next()), Some(start + TICK * 5));
+        assert_eq!(ticks.next().now_or_never(), None);
+
+        clock.advance(TICK / 2);
+        assert_eq!(block_on(ticks.next()), Some(start + TICK * 6));
+    }
+
+    #[test]
+    fn every_is_identified_by_its_clock() {
+        let start = Instant::now();
+        let clock = Clock::manual(start);
+
+        assert_eq!(hash(&clock), hash(&clock.clone()));
+        assert_ne!(hash(&clock), hash(&Clock::manual(start)));
+        assert_ne!(hash(&clock), hash(&Clock::system()));
+        assert_eq!(hash(&Clock::system()), hash(&Clock::system()));
+    }
+}
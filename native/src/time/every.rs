@@ -0,0 +1,54 @@
+use crate::{
+    subscription::{EventStream, Recipe},
+    time::Clock,
+    Hasher,
+};
+use iced_futures::futures::stream::{self, BoxStream, StreamExt};
+
+use std::time::{Duration, Instant};
+
+pub struct Every {
+    pub duration: Duration,
+    pub clock: Clock,
+}
+
+impl Recipe<Hasher, crate::Event> for Every {
+    type Output = Instant;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.duration.hash(state);
+        self.clock.id().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: EventStream,
+    ) -> BoxStream<'static, Self::Output> {
+        let Every { duration, clock } = *self;
+        let start = clock.now() + duration;
+
+        stream::unfold(start, move |next| {
+            let clock = clock.clone();
+
+            async move {
+                clock.sleep_until(next).await;
+
+                // Skip the ticks missed while the application was stalled
+                let now = clock.now();
+                let mut tick = next;
+
+                if duration > Duration::default() {
+                    while tick + duration <= now {
+                        tick += duration;
+                    }
+                }
+
+                Some((tick, tick + duration))
+            }
+        })
+        .boxed()
+    }
+}
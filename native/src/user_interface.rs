@@ -1,5 +1,7 @@
 use crate::{
-    input::mouse, layout, Clipboard, Element, Event, Layout, Point, Size,
+    focus,
+    input::{keyboard, mouse, ButtonState},
+    layout, Clipboard, Element, Event, Layout, Point, Size, Widget,
 };
 
 use std::hash::Hasher;
@@ -100,6 +102,11 @@ where
         let layout = if hash == cache.hash && bounds == cache.bounds {
             cache.layout
         } else {
+            #[cfg(feature = "trace")]
+            let span = tracing::info_span!("layout");
+            #[cfg(feature = "trace")]
+            let _guard = span.enter();
+
             renderer.layout(&root, &layout::Limits::new(Size::ZERO, bounds))
         };
 
@@ -178,11 +185,31 @@ where
         clipboard: Option<&dyn Clipboard>,
         renderer: &Renderer,
     ) -> Vec<Message> {
+        #[cfg(feature = "trace")]
+        let span = tracing::info_span!("event processing");
+        #[cfg(feature = "trace")]
+        let _guard = span.enter();
+
         let mut messages = Vec::new();
 
         for event in events {
-            if let Event::Mouse(mouse::Event::CursorMoved { x, y }) = event {
-                self.cursor_position = Point::new(x, y);
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                    self.cursor_position = Point::new(x, y);
+                }
+                Event::Keyboard(keyboard::Event::Input {
+                    key_code: keyboard::KeyCode::Tab,
+                    state: ButtonState::Pressed,
+                    modifiers,
+                    ..
+                }) => {
+                    self.move_focus(if modifiers.shift {
+                        focus::Direction::Backward
+                    } else {
+                        focus::Direction::Forward
+                    });
+                }
+                _ => {}
             }
 
             self.root.widget.on_event(
@@ -198,6 +225,25 @@ where
         messages
     }
 
+    /// Moves the keyboard focus of the [`UserInterface`] to the next
+    /// focusable widget in the given [`focus::Direction`].
+    ///
+    /// This happens automatically when `Tab` or `Shift + Tab` are pressed.
+    ///
+    /// [`UserInterface`]: struct.UserInterface.html
+    /// [`focus::Direction`]: focus/enum.Direction.html
+    pub fn move_focus(&mut self, direction: focus::Direction) {
+        let mut traversal = focus::Traversal::new(direction);
+
+        loop {
+            self.root.widget.traverse_focus(&mut traversal);
+
+            if !traversal.finish_pass() {
+                break;
+            }
+        }
+    }
+
     /// Draws the [`UserInterface`] with the provided [`Renderer`].
     ///
     /// It returns the current state of the [`MouseCursor`]. You should update
@@ -262,6 +308,11 @@ where
     /// }
     /// ```
     pub fn draw(&self, renderer: &mut Renderer) -> Renderer::Output {
+        #[cfg(feature = "trace")]
+        let span = tracing::info_span!("draw");
+        #[cfg(feature = "trace")]
+        let _guard = span.enter();
+
         self.root.widget.draw(
             renderer,
             &Renderer::Defaults::default(),
@@ -270,6 +321,36 @@ where
         )
     }
 
+    /// Returns the next [`Instant`] at which the [`UserInterface`] needs to be
+    /// redrawn, if any.
+    ///
+    /// Shells should wake up at the given [`Instant`] and redraw the
+    /// [`UserInterface`], even if no events have happened.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    /// [`UserInterface`]: struct.UserInterface.html
+    pub fn next_redraw(&self) -> Option<std::time::Instant> {
+        self.root.widget.next_redraw()
+    }
+
+    /// Describes the widgets of the [`UserInterface`] as a flat list of
+    /// [`WidgetNode`], in depth-first order.
+    ///
+    /// This can be used to find a widget by its id or label, for instance to
+    /// interact with it in a test.
+    ///
+    /// [`UserInterface`]: struct.UserInterface.html
+    /// [`WidgetNode`]: layout/struct.WidgetNode.html
+    pub fn inspect(&self) -> Vec<layout::WidgetNode> {
+        let mut nodes = Vec::new();
+
+        self.root
+            .widget
+            .inspect(Layout::new(&self.layout), 0, &mut nodes);
+
+        nodes
+    }
+
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
     /// process.
     ///
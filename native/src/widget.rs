@@ -27,6 +27,7 @@ pub mod container;
 pub mod image;
 pub mod progress_bar;
 pub mod radio;
+pub mod radio_group;
 pub mod row;
 pub mod scrollable;
 pub mod slider;
@@ -35,6 +36,9 @@ pub mod svg;
 pub mod text;
 pub mod text_input;
 
+#[cfg(test)]
+mod testing;
+
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
@@ -50,6 +54,8 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use radio_group::RadioGroup;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
@@ -64,7 +70,9 @@ pub use text::Text;
 #[doc(no_inline)]
 pub use text_input::TextInput;
 
-use crate::{layout, Clipboard, Event, Hasher, Layout, Length, Point};
+use crate::{focus, layout, Clipboard, Event, Hasher, Layout, Length, Point};
+
+use std::time::Instant;
 
 /// A component that displays information and allows interaction.
 ///
@@ -152,4 +160,61 @@ where
         _clipboard: Option<&dyn Clipboard>,
     ) {
     }
+
+    /// Returns the next [`Instant`] at which the [`Widget`] needs to be
+    /// redrawn, if any.
+    ///
+    /// Widgets that animate can use this to ask the runtime to wake up and
+    /// redraw the user interface, even if no events happen in the meantime.
+    ///
+    /// Widgets containing other widgets should return the earliest redraw
+    /// requested by their children.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    /// [`Widget`]: trait.Widget.html
+    fn next_redraw(&self) -> Option<Instant> {
+        None
+    }
+
+    /// Takes part in a [`focus::Traversal`], which moves the keyboard focus
+    /// between widgets.
+    ///
+    /// Focusable widgets should visit the traversal with their focus state.
+    /// Widgets containing other widgets should let their children traverse
+    /// it, in the same order they are laid out.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`focus::Traversal`]: ../focus/struct.Traversal.html
+    fn traverse_focus(&mut self, _traversal: &mut focus::Traversal) {}
+
+    /// Describes the [`Widget`] and its children as a flat list of
+    /// [`WidgetNode`], in depth-first order.
+    ///
+    /// This is used by [`Element::inspect`] to overlay the widget tree on top
+    /// of a user interface.
+    ///
+    /// Widgets containing other widgets should push themselves first, and
+    /// then inspect their children with an increased `depth`.
+    ///
+    /// By default, it pushes a single [`WidgetNode`] for the [`Widget`].
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`WidgetNode`]: ../layout/struct.WidgetNode.html
+    /// [`Element::inspect`]: ../struct.Element.html#method.inspect
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        nodes.push(layout::WidgetNode::new::<Self>(
+            depth,
+            layout.bounds(),
+            self.width(),
+            self.height(),
+        ));
+    }
 }
@@ -5,7 +5,8 @@
 //! [`Button`]: struct.Button.html
 //! [`State`]: struct.State.html
 use crate::{
-    input::{mouse, ButtonState},
+    focus,
+    input::{keyboard, mouse, ButtonState},
     layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
     Rectangle, Widget,
 };
@@ -13,6 +14,11 @@ use std::hash::Hash;
 
 /// A generic widget that produces a message when pressed.
 ///
+/// Once clicked or reached with `Tab`, a [`Button`] is focused and can be
+/// pressed with the keyboard by using `Space` or `Enter`.
+///
+/// [`Button`]: struct.Button.html
+///
 /// ```
 /// # use iced_native::{button, Text};
 /// #
@@ -129,6 +135,8 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_pressed: bool,
+    is_key_pressed: bool,
+    is_focused: bool,
 }
 
 impl State {
@@ -138,6 +146,13 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Button`] is currently focused or not.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -188,6 +203,7 @@ where
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state,
+                ..
             }) => {
                 if let Some(on_press) = self.on_press.clone() {
                     let bounds = layout.bounds();
@@ -196,6 +212,7 @@ where
                         ButtonState::Pressed => {
                             self.state.is_pressed =
                                 bounds.contains(cursor_position);
+                            self.state.is_focused = self.state.is_pressed;
                         }
                         ButtonState::Released => {
                             let is_clicked = self.state.is_pressed
@@ -210,6 +227,34 @@ where
                     }
                 }
             }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::Space,
+                state,
+                ..
+            })
+            | Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::Enter,
+                state,
+                ..
+            }) if self.state.is_focused => {
+                if let Some(on_press) = self.on_press.clone() {
+                    // Only a key pressed on the button can activate it
+                    match state {
+                        ButtonState::Pressed => {
+                            self.state.is_key_pressed = true;
+                        }
+                        ButtonState::Released => {
+                            let is_key_pressed = self.state.is_key_pressed;
+
+                            self.state.is_key_pressed = false;
+
+                            if is_key_pressed {
+                                messages.push(on_press);
+                            }
+                        }
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -226,7 +271,8 @@ where
             layout.bounds(),
             cursor_position,
             self.on_press.is_none(),
-            self.state.is_pressed,
+            self.state.is_pressed || self.state.is_key_pressed,
+            self.state.is_focused,
             &self.style,
             &self.content,
             layout.children().next().unwrap(),
@@ -237,6 +283,47 @@ where
         self.width.hash(state);
         self.content.hash_layout(state);
     }
+
+    fn next_redraw(&self) -> Option<std::time::Instant> {
+        self.content.widget.next_redraw()
+    }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        // Disabled buttons cannot be focused
+        if self.on_press.is_some() {
+            traversal.visit(&mut self.state.is_focused);
+        } else {
+            self.state.is_focused = false;
+        }
+
+        if !self.state.is_focused {
+            self.state.is_pressed = false;
+            self.state.is_key_pressed = false;
+        }
+    }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        nodes.push(
+            layout::WidgetNode::new::<Self>(
+                depth,
+                layout.bounds(),
+                self.width,
+                self.height,
+            )
+            .padding(self.padding),
+        );
+
+        self.content.widget.inspect(
+            layout.children().next().unwrap(),
+            depth + 1,
+            nodes,
+        );
+    }
 }
 
 /// The renderer of a [`Button`].
@@ -265,6 +352,7 @@ pub trait Renderer: crate::Renderer + Sized {
         cursor_position: Point,
         is_disabled: bool,
         is_pressed: bool,
+        is_focused: bool,
         style: &Self::Style,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
@@ -283,3 +371,79 @@ where
         Element::new(button)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, widget::testing, Size, Text};
+
+    use keyboard::KeyCode;
+
+    /// Sends the given events to a [`Button`] with the given [`State`],
+    /// returning the messages it produces.
+    fn press(state: &mut State, events: Vec<Event>) -> Vec<()> {
+        let mut button =
+            Button::<(), Null>::new(state, Text::new("Press me!")).on_press(());
+
+        let node = layout::Node::with_children(
+            Size::new(100.0, 30.0),
+            vec![layout::Node::new(Size::new(100.0, 30.0))],
+        );
+
+        testing::send(&mut button, &node, events)
+    }
+
+    fn focused() -> State {
+        State {
+            is_focused: true,
+            ..State::default()
+        }
+    }
+
+    #[test]
+    fn space_and_enter_press_a_focused_button() {
+        let mut state = focused();
+
+        assert_eq!(press(&mut state, testing::tap(KeyCode::Space)), vec![()]);
+        assert_eq!(press(&mut state, testing::tap(KeyCode::Enter)), vec![()]);
+    }
+
+    #[test]
+    fn unfocused_button_ignores_the_keyboard() {
+        let mut state = State::new();
+
+        assert_eq!(press(&mut state, testing::tap(KeyCode::Space)), vec![]);
+    }
+
+    #[test]
+    fn releasing_a_key_after_a_mouse_press_does_nothing() {
+        let mut state = State {
+            is_pressed: true,
+            ..focused()
+        };
+
+        assert_eq!(
+            press(
+                &mut state,
+                vec![testing::key(KeyCode::Enter, ButtonState::Released)]
+            ),
+            vec![]
+        );
+        assert!(state.is_pressed);
+    }
+
+    #[test]
+    fn disabled_button_ignores_the_keyboard() {
+        let mut state = focused();
+        let mut button = Button::<(), Null>::new(&mut state, Text::new("No"));
+
+        assert_eq!(
+            testing::send(
+                &mut button,
+                &layout::Node::new(Size::new(100.0, 30.0)),
+                testing::tap(KeyCode::Space),
+            ),
+            vec![]
+        );
+    }
+}
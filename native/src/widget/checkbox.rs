@@ -2,7 +2,8 @@
 use std::hash::Hash;
 
 use crate::{
-    input::{mouse, ButtonState},
+    focus,
+    input::{keyboard, mouse, ButtonState},
     layout, row, text, Align, Clipboard, Element, Event, Font, Hasher,
     HorizontalAlignment, Layout, Length, Point, Rectangle, Row, Text,
     VerticalAlignment, Widget,
@@ -24,6 +25,12 @@ use crate::{
 /// Checkbox::new(is_checked, "Toggle me!", Message::CheckboxToggled);
 /// ```
 ///
+/// A [`Checkbox`] made [`focusable`] can be focused by clicking it or by
+/// pressing `Tab`. Then, it can be toggled by pressing `Space` or `Enter`.
+///
+/// [`Checkbox`]: struct.Checkbox.html
+/// [`focusable`]: struct.Checkbox.html#method.focusable
+///
 /// ![Checkbox drawn by `iced_wgpu`](https://github.com/hecrj/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/checkbox.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct Checkbox<Message, Renderer: self::Renderer + text::Renderer> {
@@ -105,69 +112,112 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
         self.style = style.into();
         self
     }
-}
 
-impl<Message, Renderer> Widget<Message, Renderer>
-    for Checkbox<Message, Renderer>
-where
-    Renderer: self::Renderer + text::Renderer + row::Renderer,
-{
-    fn width(&self) -> Length {
-        self.width
+    /// Makes the [`Checkbox`] focusable with some local [`State`], allowing
+    /// it to be toggled with the keyboard.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    /// [`State`]: struct.State.html
+    pub fn focusable(
+        self,
+        state: &mut State,
+    ) -> Focusable<'_, Message, Renderer> {
+        Focusable {
+            checkbox: self,
+            state,
+        }
     }
+}
 
-    fn height(&self) -> Length {
-        Length::Shrink
+/// The local state of a focusable [`Checkbox`].
+///
+/// [`Checkbox`]: struct.Checkbox.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_focused: bool,
+    is_pressed: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
     }
 
-    fn layout(
-        &self,
-        renderer: &Renderer,
-        limits: &layout::Limits,
-    ) -> layout::Node {
-        Row::<(), Renderer>::new()
-            .width(self.width)
-            .spacing(self.spacing)
-            .align_items(Align::Center)
-            .push(
-                Row::new()
-                    .width(Length::Units(self.size))
-                    .height(Length::Units(self.size)),
-            )
-            .push(
-                Text::new(&self.label)
-                    .width(self.width)
-                    .size(self.text_size),
-            )
-            .layout(renderer, limits)
+    /// Returns whether the [`Checkbox`] is currently focused or not.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
     }
+}
 
-    fn on_event(
-        &mut self,
+impl<Message, Renderer> Checkbox<Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer + row::Renderer,
+{
+    fn handle_event(
+        &self,
+        mut state: Option<&mut State>,
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-        _renderer: &Renderer,
-        _clipboard: Option<&dyn Clipboard>,
     ) {
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state: ButtonState::Pressed,
+                ..
             }) => {
                 let mouse_over = layout.bounds().contains(cursor_position);
 
                 if mouse_over {
                     messages.push((self.on_toggle)(!self.is_checked));
                 }
+
+                if let Some(state) = &mut state {
+                    state.is_focused = mouse_over;
+                    state.is_pressed = false;
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::Space,
+                state: key_state,
+                ..
+            })
+            | Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::Enter,
+                state: key_state,
+                ..
+            }) => {
+                let state = match state {
+                    Some(state) if state.is_focused => state,
+                    _ => return,
+                };
+
+                match key_state {
+                    ButtonState::Pressed => {
+                        state.is_pressed = true;
+                    }
+                    ButtonState::Released => {
+                        if state.is_pressed {
+                            messages.push((self.on_toggle)(!self.is_checked));
+                        }
+
+                        state.is_pressed = false;
+                    }
+                }
             }
             _ => {}
         }
     }
 
-    fn draw(
+    fn draw_with_state(
         &self,
+        state: Option<&State>,
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
@@ -192,21 +242,195 @@ where
             VerticalAlignment::Center,
         );
 
-        let is_mouse_over = bounds.contains(cursor_position);
+        // A checkbox pressed with the keyboard looks like a hovered one
+        let is_mouse_over = bounds.contains(cursor_position)
+            || state.is_some_and(|state| state.is_pressed);
+
+        let is_focused = state.is_some_and(|state| state.is_focused);
 
         self::Renderer::draw(
             renderer,
             checkbox_bounds,
             self.is_checked,
             is_mouse_over,
+            is_focused,
             label,
             &self.style,
         )
     }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer>
+    for Checkbox<Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer + row::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        Row::<(), Renderer>::new()
+            .width(self.width)
+            .spacing(self.spacing)
+            .align_items(Align::Center)
+            .push(
+                Row::new()
+                    .width(Length::Units(self.size))
+                    .height(Length::Units(self.size)),
+            )
+            .push(
+                Text::new(&self.label)
+                    .width(self.width)
+                    .size(self.text_size),
+            )
+            .layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.handle_event(None, event, layout, cursor_position, messages);
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.draw_with_state(None, renderer, defaults, layout, cursor_position)
+    }
 
     fn hash_layout(&self, state: &mut Hasher) {
         self.label.hash(state);
     }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        nodes.push(
+            layout::WidgetNode::new::<Self>(
+                depth,
+                layout.bounds(),
+                self.width,
+                Length::Shrink,
+            )
+            .label(self.label.clone()),
+        );
+    }
+}
+
+/// A [`Checkbox`] that can be focused and toggled with the keyboard.
+///
+/// It is created with [`Checkbox::focusable`].
+///
+/// [`Checkbox`]: struct.Checkbox.html
+/// [`Checkbox::focusable`]: struct.Checkbox.html#method.focusable
+#[allow(missing_debug_implementations)]
+pub struct Focusable<'a, Message, Renderer: self::Renderer + text::Renderer> {
+    checkbox: Checkbox<Message, Renderer>,
+    state: &'a mut State,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Focusable<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer + row::Renderer,
+{
+    fn width(&self) -> Length {
+        Widget::<Message, Renderer>::width(&self.checkbox)
+    }
+
+    fn height(&self) -> Length {
+        Widget::<Message, Renderer>::height(&self.checkbox)
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.checkbox.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.checkbox.handle_event(
+            Some(&mut *self.state),
+            event,
+            layout,
+            cursor_position,
+            messages,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.checkbox.draw_with_state(
+            Some(&*self.state),
+            renderer,
+            defaults,
+            layout,
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.checkbox.hash_layout(state);
+    }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        traversal.visit(&mut self.state.is_focused);
+
+        if !self.state.is_focused {
+            self.state.is_pressed = false;
+        }
+    }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        Widget::<Message, Renderer>::inspect(
+            &self.checkbox,
+            layout,
+            depth,
+            nodes,
+        );
+    }
 }
 
 /// The renderer of a [`Checkbox`].
@@ -236,6 +460,7 @@ pub trait Renderer: crate::Renderer {
     ///   * the bounds of the [`Checkbox`]
     ///   * whether the [`Checkbox`] is selected or not
     ///   * whether the mouse is over the [`Checkbox`] or not
+    ///   * whether the [`Checkbox`] is focused or not
     ///   * the drawn label of the [`Checkbox`]
     ///
     /// [`Checkbox`]: struct.Checkbox.html
@@ -244,6 +469,7 @@ pub trait Renderer: crate::Renderer {
         bounds: Rectangle,
         is_checked: bool,
         is_mouse_over: bool,
+        is_focused: bool,
         label: Self::Output,
         style: &Self::Style,
     ) -> Self::Output;
@@ -261,3 +487,93 @@ where
         Element::new(checkbox)
     }
 }
+
+impl<'a, Message, Renderer> From<Focusable<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer + text::Renderer + row::Renderer,
+    Message: 'static,
+{
+    fn from(
+        checkbox: Focusable<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(checkbox)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, widget::testing, Size};
+
+    use keyboard::KeyCode;
+
+    /// Sends the given events to a focusable [`Checkbox`] with the given
+    /// [`State`], returning the messages it produces.
+    fn toggle(
+        is_checked: bool,
+        state: &mut State,
+        events: Vec<Event>,
+    ) -> Vec<bool> {
+        let mut checkbox =
+            Checkbox::<bool, Null>::new(is_checked, "Toggle me!", |value| {
+                value
+            })
+            .focusable(state);
+
+        testing::send(
+            &mut checkbox,
+            &layout::Node::new(Size::new(100.0, 20.0)),
+            events,
+        )
+    }
+
+    #[test]
+    fn space_and_enter_toggle_a_focused_checkbox() {
+        let mut state = State {
+            is_focused: true,
+            is_pressed: false,
+        };
+
+        assert_eq!(
+            toggle(false, &mut state, testing::tap(KeyCode::Space)),
+            vec![true]
+        );
+        assert_eq!(
+            toggle(true, &mut state, testing::tap(KeyCode::Enter)),
+            vec![false]
+        );
+    }
+
+    #[test]
+    fn toggles_when_the_key_is_released() {
+        let mut state = State {
+            is_focused: true,
+            is_pressed: false,
+        };
+
+        let press = testing::key(KeyCode::Space, ButtonState::Pressed);
+        let release = testing::key(KeyCode::Space, ButtonState::Released);
+
+        assert_eq!(toggle(false, &mut state, vec![press]), vec![]);
+        assert!(state.is_pressed);
+
+        assert_eq!(toggle(false, &mut state, vec![release]), vec![true]);
+        assert!(!state.is_pressed);
+    }
+
+    #[test]
+    fn only_focusable_checkboxes_use_the_keyboard() {
+        let mut checkbox =
+            Checkbox::<bool, Null>::new(false, "Toggle me!", |value| value);
+
+        assert_eq!(
+            testing::send(
+                &mut checkbox,
+                &layout::Node::new(Size::new(100.0, 20.0)),
+                testing::tap(KeyCode::Space),
+            ),
+            vec![]
+        );
+    }
+}
@@ -2,8 +2,8 @@
 use std::hash::Hash;
 
 use crate::{
-    layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Rectangle, Widget,
+    focus, layout, Align, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Widget,
 };
 
 use std::u32;
@@ -197,6 +197,34 @@ where
 
         self.content.hash_layout(state);
     }
+
+    fn next_redraw(&self) -> Option<std::time::Instant> {
+        self.content.widget.next_redraw()
+    }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        self.content.widget.traverse_focus(traversal);
+    }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        nodes.push(layout::WidgetNode::new::<Self>(
+            depth,
+            layout.bounds(),
+            self.width,
+            self.height,
+        ));
+
+        self.content.widget.inspect(
+            layout.children().next().unwrap(),
+            depth + 1,
+            nodes,
+        );
+    }
 }
 
 /// The renderer of a [`Container`].
@@ -1,12 +1,31 @@
 //! Display images in your user interface.
-use crate::{layout, Element, Hasher, Layout, Length, Point, Size, Widget};
+mod blurhash;
+
+use crate::{
+    layout, time, Background, Color, Element, Hasher, Layout, Length, Point,
+    Size, Widget,
+};
 
 use std::{
+    cell::Cell,
     hash::{Hash, Hasher as _},
     path::PathBuf,
-    sync::Arc,
+    sync::{
+        atomic::{self, AtomicU64},
+        Arc,
+    },
+    time::{Duration, Instant},
 };
 
+/// The interval between two checks of an [`Image`] that is being loaded in
+/// the background.
+///
+/// [`Image`]: struct.Image.html
+const LOADING_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The amount of times some images have finished loading in the background.
+static LOADED: AtomicU64 = AtomicU64::new(0);
+
 /// A frame that displays an image while keeping aspect ratio.
 ///
 /// # Example
@@ -18,11 +37,27 @@ use std::{
 /// ```
 ///
 /// <img src="https://github.com/hecrj/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
+///
+/// Big images can be [loaded in the background] to keep the user interface
+/// responsive while they are decoded.
+///
+/// [loaded in the background]: #method.placeholder
 #[derive(Debug)]
 pub struct Image {
     handle: Handle,
     width: Length,
     height: Length,
+    placeholder: Option<Placeholder>,
+    is_loading: Cell<bool>,
+}
+
+/// What is displayed while an [`Image`] loads in the background.
+///
+/// [`Image`]: struct.Image.html
+#[derive(Debug, Clone)]
+enum Placeholder {
+    Background(Background),
+    Preview(Handle),
 }
 
 impl Image {
@@ -34,6 +69,8 @@ impl Image {
             handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
+            placeholder: None,
+            is_loading: Cell::new(false),
         }
     }
 
@@ -52,6 +89,38 @@ impl Image {
         self.height = height;
         self
     }
+
+    /// Loads the [`Image`] in the background, displaying the given
+    /// placeholder until it is ready.
+    ///
+    /// The dimensions of the [`Image`] are unknown while it loads. Therefore,
+    /// the placeholder fills the available space unless an explicit width and
+    /// height are set.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn placeholder(mut self, placeholder: impl Into<Background>) -> Self {
+        self.placeholder = Some(Placeholder::Background(placeholder.into()));
+        self
+    }
+
+    /// Loads the [`Image`] in the background, displaying a blurred preview
+    /// decoded from the given [BlurHash] until it is ready.
+    ///
+    /// The preview is stretched to the bounds of the [`Image`], which are
+    /// unknown while it loads. Therefore, an explicit width and height should
+    /// be set. An invalid hash displays nothing instead.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [BlurHash]: https://blurha.sh
+    pub fn blurhash(mut self, hash: &str) -> Self {
+        self.placeholder = Some(match blurhash::decode(hash) {
+            Some(preview) => Placeholder::Preview(preview),
+            None => {
+                Placeholder::Background(Background::Color(Color::TRANSPARENT))
+            }
+        });
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Image
@@ -71,7 +140,23 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let (width, height) = renderer.dimensions(&self.handle);
+        let dimensions = if self.placeholder.is_some() {
+            renderer.load(&self.handle)
+        } else {
+            Some(renderer.dimensions(&self.handle))
+        };
+
+        let (width, height) = match dimensions {
+            Some(dimensions) => dimensions,
+            None => {
+                let size = limits
+                    .width(self.width)
+                    .height(self.height)
+                    .resolve(Size::ZERO);
+
+                return layout::Node::new(size);
+            }
+        };
 
         let aspect_ratio = width as f32 / height as f32;
 
@@ -98,6 +183,23 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> Renderer::Output {
+        if let Some(placeholder) = &self.placeholder {
+            let is_loading = renderer.load(&self.handle).is_none();
+
+            self.is_loading.set(is_loading);
+
+            if is_loading {
+                return match placeholder {
+                    Placeholder::Background(background) => {
+                        renderer.draw_placeholder(*background, layout)
+                    }
+                    Placeholder::Preview(preview) => {
+                        renderer.draw(preview.clone(), layout)
+                    }
+                };
+            }
+        }
+
         renderer.draw(self.handle.clone(), layout)
     }
 
@@ -105,6 +207,20 @@ where
         self.handle.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+
+        // The layout of an image loaded in the background changes once the
+        // image is ready
+        if self.placeholder.is_some() {
+            LOADED.load(atomic::Ordering::SeqCst).hash(state);
+        }
+    }
+
+    fn next_redraw(&self) -> Option<Instant> {
+        if self.is_loading.get() {
+            Some(time::now() + LOADING_POLL_INTERVAL)
+        } else {
+            None
+        }
     }
 }
 
@@ -135,6 +251,34 @@ impl Handle {
         Self::from_data(Data::Bytes(bytes))
     }
 
+    /// Creates an image [`Handle`] pointing to the image at the given URL.
+    ///
+    /// Downloading the image blocks until it finishes, unless the [`Image`]
+    /// is [loaded in the background]. Renderers may need additional features
+    /// to support URLs, like the `image_url` feature of `iced_wgpu`.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    /// [`Image`]: struct.Image.html
+    /// [loaded in the background]: struct.Image.html#method.placeholder
+    pub fn from_url<T: Into<String>>(url: T) -> Handle {
+        Self::from_data(Data::Url(url.into()))
+    }
+
+    /// Creates an image [`Handle`] containing the image pixels directly.
+    ///
+    /// The pixels must be in RGBA format, with 8 bits per channel, row by row
+    /// from the top-left corner. This way, procedurally generated content can
+    /// be displayed without encoding it first.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn from_pixels(width: u32, height: u32, pixels: Vec<u8>) -> Handle {
+        Self::from_data(Data::Pixels {
+            width,
+            height,
+            pixels,
+        })
+    }
+
     fn from_data(data: Data) -> Handle {
         let mut hasher = Hasher::default();
         data.hash(&mut hasher);
@@ -188,6 +332,19 @@ pub enum Data {
 
     /// In-memory data
     Bytes(Vec<u8>),
+
+    /// Remote data
+    Url(String),
+
+    /// Decoded image pixels in RGBA format
+    Pixels {
+        /// The width of the image
+        width: u32,
+        /// The height of the image
+        height: u32,
+        /// The pixels
+        pixels: Vec<u8>,
+    },
 }
 
 impl std::fmt::Debug for Data {
@@ -195,6 +352,10 @@ impl std::fmt::Debug for Data {
         match self {
             Data::Path(path) => write!(f, "Path({:?})", path),
             Data::Bytes(_) => write!(f, "Bytes(...)"),
+            Data::Url(url) => write!(f, "Url({:?})", url),
+            Data::Pixels { width, height, .. } => {
+                write!(f, "Pixels({} * {})", width, height)
+            }
         }
     }
 }
@@ -216,6 +377,40 @@ pub trait Renderer: crate::Renderer {
     ///
     /// [`Image`]: struct.Image.html
     fn draw(&mut self, handle: Handle, layout: Layout<'_>) -> Self::Output;
+
+    /// Starts loading the [`Image`] of the given [`Handle`] in the background,
+    /// if it is not loaded yet, and returns its dimensions once it is ready.
+    ///
+    /// Renderers loading images in the background must call [`notify_loaded`]
+    /// whenever some of them become ready. By default, the image is loaded
+    /// right away.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`Handle`]: struct.Handle.html
+    /// [`notify_loaded`]: fn.notify_loaded.html
+    fn load(&self, handle: &Handle) -> Option<(u32, u32)> {
+        Some(self.dimensions(handle))
+    }
+
+    /// Draws the placeholder of an [`Image`] that is still loading.
+    ///
+    /// [`Image`]: struct.Image.html
+    fn draw_placeholder(
+        &mut self,
+        placeholder: Background,
+        layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+/// Notifies that some images loaded in the background have become ready.
+///
+/// The layout of every [`Image`] with a [placeholder] is recomputed
+/// afterwards.
+///
+/// [`Image`]: struct.Image.html
+/// [placeholder]: struct.Image.html#method.placeholder
+pub fn notify_loaded() {
+    let _ = LOADED.fetch_add(1, atomic::Ordering::SeqCst);
 }
 
 impl<'a, Message, Renderer> From<Image> for Element<'a, Message, Renderer>
@@ -0,0 +1,170 @@
+//! Decode [BlurHash] previews.
+//!
+//! [BlurHash]: https://github.com/woltapp/blurhash/blob/master/Algorithm.md
+use super::Handle;
+
+use std::f32::consts::PI;
+
+/// The width and height of a decoded preview, in pixels.
+///
+/// Previews are stretched when drawn, which blurs them even further. Small
+/// previews are cheap to decode and to upload.
+const SIZE: u32 = 32;
+
+const ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ\
+abcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+/// Decodes the given hash into a [`Handle`] with the pixels of its preview.
+///
+/// Returns `None` if the hash is invalid.
+///
+/// [`Handle`]: ../struct.Handle.html
+pub fn decode(hash: &str) -> Option<Handle> {
+    let pixels = decode_pixels(hash, SIZE, SIZE)?;
+
+    Some(Handle::from_pixels(SIZE, SIZE, pixels))
+}
+
+fn decode_pixels(hash: &str, width: u32, height: u32) -> Option<Vec<u8>> {
+    let hash = hash.as_bytes();
+
+    if hash.len() < 6 {
+        return None;
+    }
+
+    let size_flag = decode_83(&hash[0..1])?;
+    let components_x = (size_flag % 9 + 1) as usize;
+    let components_y = (size_flag / 9 + 1) as usize;
+
+    if hash.len() != 4 + 2 * components_x * components_y {
+        return None;
+    }
+
+    let maximum_value = (decode_83(&hash[1..2])? + 1) as f32 / 166.0;
+
+    let mut colors = Vec::with_capacity(components_x * components_y);
+    colors.push(decode_dc(decode_83(&hash[2..6])?));
+
+    for i in 1..components_x * components_y {
+        let value = decode_83(&hash[4 + i * 2..6 + i * 2])?;
+
+        colors.push(decode_ac(value, maximum_value));
+    }
+
+    let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+
+    for y in 0..height {
+        for x in 0..width {
+            let mut pixel = [0.0; 3];
+
+            for j in 0..components_y {
+                for i in 0..components_x {
+                    let basis = (PI * x as f32 * i as f32 / width as f32).cos()
+                        * (PI * y as f32 * j as f32 / height as f32).cos();
+
+                    let color = colors[i + j * components_x];
+
+                    for (channel, value) in pixel.iter_mut().zip(&color) {
+                        *channel += value * basis;
+                    }
+                }
+            }
+
+            pixels.extend(pixel.iter().map(|value| linear_to_srgb(*value)));
+            pixels.push(255);
+        }
+    }
+
+    Some(pixels)
+}
+
+fn decode_83(digits: &[u8]) -> Option<u32> {
+    digits.iter().try_fold(0, |value, digit| {
+        let index = ALPHABET.iter().position(|c| c == digit)?;
+
+        Some(value * 83 + index as u32)
+    })
+}
+
+fn decode_dc(value: u32) -> [f32; 3] {
+    [
+        srgb_to_linear((value >> 16) as u8),
+        srgb_to_linear((value >> 8) as u8),
+        srgb_to_linear(value as u8),
+    ]
+}
+
+fn decode_ac(value: u32, maximum_value: f32) -> [f32; 3] {
+    let quantized = [value / (19 * 19), (value / 19) % 19, value % 19];
+
+    let mut color = [0.0; 3];
+
+    for (channel, quantized) in color.iter_mut().zip(&quantized) {
+        let value = (*quantized as f32 - 9.0) / 9.0;
+
+        *channel = value.signum() * value.powi(2) * maximum_value;
+    }
+
+    color
+}
+
+fn srgb_to_linear(value: u8) -> f32 {
+    let value = value as f32 / 255.0;
+
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> u8 {
+    let value = value.clamp(0.0, 1.0);
+
+    let srgb = if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    };
+
+    (srgb * 255.0 + 0.5) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_a_uniform_color() {
+        // A single component of color #FF8000
+        let pixels = decode_pixels("00TNoS", 4, 4).unwrap();
+
+        assert_eq!(pixels.len(), 4 * 4 * 4);
+
+        for pixel in pixels.chunks(4) {
+            assert_eq!(pixel, &[255, 128, 0, 255]);
+        }
+    }
+
+    #[test]
+    fn decodes_varying_colors() {
+        let pixels =
+            decode_pixels("LEHV6nWB2yk8pyo0adR*.7kCMdnj", 8, 8).unwrap();
+
+        assert_eq!(pixels.len(), 8 * 8 * 4);
+        assert_ne!(&pixels[0..4], &pixels[pixels.len() - 4..]);
+        assert!(pixels.chunks(4).all(|pixel| pixel[3] == 255));
+    }
+
+    #[test]
+    fn rejects_invalid_hashes() {
+        assert!(decode_pixels("", 4, 4).is_none());
+        assert!(decode_pixels("00TNo", 4, 4).is_none());
+
+        // The size flag asks for more components than available
+        assert!(decode_pixels("L0TNoS", 4, 4).is_none());
+
+        // Contains a character outside of the alphabet
+        assert!(decode_pixels("00TNo!", 4, 4).is_none());
+    }
+}
@@ -1,10 +1,28 @@
 //! Provide progress feedback to your users.
 use crate::{
-    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+    layout, time, Element, Hasher, Layout, Length, Point, Rectangle, Size,
+    Widget,
 };
 
+use std::time::{Duration, Instant};
 use std::{hash::Hash, ops::RangeInclusive};
 
+/// The time an indeterminate [`ProgressBar`] takes to sweep once.
+///
+/// [`ProgressBar`]: struct.ProgressBar.html
+const SWEEP_DURATION: Duration = Duration::from_millis(1500);
+
+/// The interval between two frames of an indeterminate [`ProgressBar`].
+///
+/// [`ProgressBar`]: struct.ProgressBar.html
+const FRAME: Duration = Duration::from_millis(16);
+
+thread_local! {
+    // The instant all the indeterminate progress bars of a thread are
+    // synchronized with.
+    static EPOCH: Instant = time::now();
+}
+
 /// A bar that displays progress.
 ///
 /// # Example
@@ -17,11 +35,17 @@ use std::{hash::Hash, ops::RangeInclusive};
 /// ProgressBar::new(0.0..=100.0, value);
 /// ```
 ///
+/// When the total amount of work is unknown, an [indeterminate] progress bar
+/// can be used instead. It animates a segment sweeping across the bar.
+///
+/// [indeterminate]: #method.indeterminate
+///
 /// ![Progress bar drawn with `iced_wgpu`](https://user-images.githubusercontent.com/18618951/71662391-a316c200-2d51-11ea-9cef-52758cab85e3.png)
 #[allow(missing_debug_implementations)]
 pub struct ProgressBar<Renderer: self::Renderer> {
     range: RangeInclusive<f32>,
     value: f32,
+    is_indeterminate: bool,
     width: Length,
     height: Option<Length>,
     style: Renderer::Style,
@@ -39,12 +63,27 @@ impl<Renderer: self::Renderer> ProgressBar<Renderer> {
         ProgressBar {
             value: value.max(*range.start()).min(*range.end()),
             range,
+            is_indeterminate: false,
             width: Length::Fill,
             height: None,
             style: Renderer::Style::default(),
         }
     }
 
+    /// Creates a new indeterminate [`ProgressBar`].
+    ///
+    /// An indeterminate [`ProgressBar`] shows that some work is in progress,
+    /// without telling how much of it is done. It keeps requesting redraws to
+    /// animate itself while it is displayed.
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn indeterminate() -> Self {
+        ProgressBar {
+            is_indeterminate: true,
+            ..Self::new(0.0..=1.0, 0.0)
+        }
+    }
+
     /// Sets the width of the [`ProgressBar`].
     ///
     /// [`ProgressBar`]: struct.ProgressBar.html
@@ -105,18 +144,36 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> Renderer::Output {
-        renderer.draw(
-            layout.bounds(),
-            self.range.clone(),
-            self.value,
-            &self.style,
-        )
+        if self.is_indeterminate {
+            let elapsed = EPOCH
+                .with(|epoch| time::now().saturating_duration_since(*epoch));
+
+            let phase =
+                (elapsed.as_secs_f32() / SWEEP_DURATION.as_secs_f32()).fract();
+
+            renderer.draw_indeterminate(layout.bounds(), phase, &self.style)
+        } else {
+            renderer.draw(
+                layout.bounds(),
+                self.range.clone(),
+                self.value,
+                &self.style,
+            )
+        }
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
         self.width.hash(state);
         self.height.hash(state);
     }
+
+    fn next_redraw(&self) -> Option<Instant> {
+        if self.is_indeterminate {
+            Some(time::now() + FRAME)
+        } else {
+            None
+        }
+    }
 }
 
 /// The renderer of a [`ProgressBar`].
@@ -152,6 +209,24 @@ pub trait Renderer: crate::Renderer {
         value: f32,
         style: &Self::Style,
     ) -> Self::Output;
+
+    /// Draws an indeterminate [`ProgressBar`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`ProgressBar`]
+    ///   * the phase of the animation, from `0.0` to `1.0`
+    ///
+    /// By default, it draws an empty [`ProgressBar`].
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    fn draw_indeterminate(
+        &self,
+        bounds: Rectangle,
+        _phase: f32,
+        style: &Self::Style,
+    ) -> Self::Output {
+        self.draw(bounds, 0.0..=1.0, 0.0, style)
+    }
 }
 
 impl<'a, Message, Renderer> From<ProgressBar<Renderer>>
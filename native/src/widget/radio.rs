@@ -1,12 +1,14 @@
 //! Create choices using radio buttons.
 use crate::{
-    input::{mouse, ButtonState},
+    focus,
+    input::{keyboard, mouse, ButtonState},
     layout, row, text, Align, Clipboard, Element, Event, Font, Hasher,
     HorizontalAlignment, Layout, Length, Point, Rectangle, Row, Text,
     VerticalAlignment, Widget,
 };
 
 use std::hash::Hash;
+use std::rc::Rc;
 
 /// A circular button representing a choice.
 ///
@@ -33,13 +35,20 @@ use std::hash::Hash;
 /// Radio::new(Choice::B, "This is B", selected_choice, Message::RadioSelected);
 /// ```
 ///
+/// A [`Radio`] button made [`focusable`] can be focused by clicking it or by
+/// pressing `Tab`. Then, it can be selected by pressing `Space` or `Enter`.
+///
+/// [`Radio`]: struct.Radio.html
+/// [`focusable`]: struct.Radio.html#method.focusable
+///
 /// ![Radio buttons drawn by `iced_wgpu`](https://github.com/hecrj/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/radio.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct Radio<Message, Renderer: self::Renderer> {
+    is_focused: bool,
     is_selected: bool,
     on_click: Message,
     label: String,
-    style: Renderer::Style,
+    style: Rc<Renderer::Style>,
 }
 
 impl<Message, Renderer: self::Renderer> Radio<Message, Renderer> {
@@ -59,10 +68,11 @@ impl<Message, Renderer: self::Renderer> Radio<Message, Renderer> {
         F: 'static + Fn(V) -> Message,
     {
         Radio {
+            is_focused: false,
             is_selected: Some(value) == selected,
             on_click: f(value),
             label: String::from(label),
-            style: Renderer::Style::default(),
+            style: Rc::new(Renderer::Style::default()),
         }
     }
 
@@ -70,68 +80,133 @@ impl<Message, Renderer: self::Renderer> Radio<Message, Renderer> {
     ///
     /// [`Radio`]: struct.Radio.html
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
-        self.style = style.into();
+        self.style = Rc::new(style.into());
         self
     }
-}
 
-impl<Message, Renderer> Widget<Message, Renderer> for Radio<Message, Renderer>
-where
-    Renderer: self::Renderer + text::Renderer + row::Renderer,
-    Message: Clone,
-{
-    fn width(&self) -> Length {
-        Length::Fill
+    /// Sets a style of the [`Radio`] button that is shared with other radio
+    /// buttons.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub(crate) fn shared_style(mut self, style: Rc<Renderer::Style>) -> Self {
+        self.style = style;
+        self
     }
 
-    fn height(&self) -> Length {
-        Length::Shrink
+    /// Makes the [`Radio`] button focusable with some local [`State`],
+    /// allowing it to be selected with the keyboard.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    /// [`State`]: struct.State.html
+    pub fn focusable(
+        self,
+        state: &mut State,
+    ) -> Focusable<'_, Message, Renderer> {
+        Focusable { radio: self, state }
     }
 
-    fn layout(
-        &self,
-        renderer: &Renderer,
-        limits: &layout::Limits,
-    ) -> layout::Node {
-        let size = self::Renderer::default_size(renderer);
+    /// Draws the [`Radio`] button as focused, even if it is not focusable.
+    ///
+    /// This lets a group of radio buttons show which option the keyboard
+    /// controls.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub(crate) fn focused(mut self, is_focused: bool) -> Self {
+        self.is_focused = is_focused;
+        self
+    }
+}
 
-        Row::<(), Renderer>::new()
-            .width(Length::Fill)
-            .spacing(15)
-            .align_items(Align::Center)
-            .push(
-                Row::new()
-                    .width(Length::Units(size as u16))
-                    .height(Length::Units(size as u16)),
-            )
-            .push(Text::new(&self.label))
-            .layout(renderer, limits)
+/// The local state of a focusable [`Radio`] button.
+///
+/// [`Radio`]: struct.Radio.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_focused: bool,
+    is_pressed: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
     }
 
-    fn on_event(
-        &mut self,
+    /// Returns whether the [`Radio`] button is currently focused or not.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+impl<Message, Renderer> Radio<Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer + row::Renderer,
+    Message: Clone,
+{
+    fn handle_event(
+        &self,
+        mut state: Option<&mut State>,
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-        _renderer: &Renderer,
-        _clipboard: Option<&dyn Clipboard>,
     ) {
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state: ButtonState::Pressed,
+                ..
             }) => {
-                if layout.bounds().contains(cursor_position) {
+                let is_clicked = layout.bounds().contains(cursor_position);
+
+                if is_clicked {
                     messages.push(self.on_click.clone());
                 }
+
+                if let Some(state) = &mut state {
+                    state.is_focused = is_clicked;
+                    state.is_pressed = false;
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::Space,
+                state: key_state,
+                ..
+            })
+            | Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::Enter,
+                state: key_state,
+                ..
+            }) => {
+                let state = match state {
+                    Some(state) if state.is_focused => state,
+                    _ => return,
+                };
+
+                match key_state {
+                    ButtonState::Pressed => {
+                        state.is_pressed = true;
+                    }
+                    ButtonState::Released => {
+                        if state.is_pressed {
+                            messages.push(self.on_click.clone());
+                        }
+
+                        state.is_pressed = false;
+                    }
+                }
             }
             _ => {}
         }
     }
 
-    fn draw(
+    fn draw_with_state(
         &self,
+        state: Option<&State>,
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
@@ -156,21 +231,190 @@ where
             VerticalAlignment::Center,
         );
 
-        let is_mouse_over = bounds.contains(cursor_position);
+        // A radio button pressed with the keyboard looks like a hovered one
+        let is_mouse_over = bounds.contains(cursor_position)
+            || state.is_some_and(|state| state.is_pressed);
+
+        let is_focused =
+            self.is_focused || state.is_some_and(|state| state.is_focused);
 
         self::Renderer::draw(
             renderer,
             radio_bounds,
             self.is_selected,
             is_mouse_over,
+            is_focused,
             label,
             &self.style,
         )
     }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Radio<Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer + row::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self::Renderer::default_size(renderer);
+
+        Row::<(), Renderer>::new()
+            .width(Length::Fill)
+            .spacing(15)
+            .align_items(Align::Center)
+            .push(
+                Row::new()
+                    .width(Length::Units(size as u16))
+                    .height(Length::Units(size as u16)),
+            )
+            .push(Text::new(&self.label))
+            .layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.handle_event(None, event, layout, cursor_position, messages);
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.draw_with_state(None, renderer, defaults, layout, cursor_position)
+    }
 
     fn hash_layout(&self, state: &mut Hasher) {
         self.label.hash(state);
     }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        nodes.push(
+            layout::WidgetNode::new::<Self>(
+                depth,
+                layout.bounds(),
+                Length::Fill,
+                Length::Shrink,
+            )
+            .label(self.label.clone()),
+        );
+    }
+}
+
+/// A [`Radio`] button that can be focused and selected with the keyboard.
+///
+/// It is created with [`Radio::focusable`].
+///
+/// [`Radio`]: struct.Radio.html
+/// [`Radio::focusable`]: struct.Radio.html#method.focusable
+#[allow(missing_debug_implementations)]
+pub struct Focusable<'a, Message, Renderer: self::Renderer> {
+    radio: Radio<Message, Renderer>,
+    state: &'a mut State,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Focusable<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer + row::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        Widget::<Message, Renderer>::width(&self.radio)
+    }
+
+    fn height(&self) -> Length {
+        Widget::<Message, Renderer>::height(&self.radio)
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.radio.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.radio.handle_event(
+            Some(&mut *self.state),
+            event,
+            layout,
+            cursor_position,
+            messages,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.radio.draw_with_state(
+            Some(&*self.state),
+            renderer,
+            defaults,
+            layout,
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        Widget::<Message, Renderer>::hash_layout(&self.radio, state);
+    }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        traversal.visit(&mut self.state.is_focused);
+
+        if !self.state.is_focused {
+            self.state.is_pressed = false;
+        }
+    }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        Widget::<Message, Renderer>::inspect(&self.radio, layout, depth, nodes);
+    }
 }
 
 /// The renderer of a [`Radio`] button.
@@ -195,6 +439,7 @@ pub trait Renderer: crate::Renderer {
     ///   * the bounds of the [`Radio`]
     ///   * whether the [`Radio`] is selected or not
     ///   * whether the mouse is over the [`Radio`] or not
+    ///   * whether the [`Radio`] is focused or not
     ///   * the drawn label of the [`Radio`]
     ///
     /// [`Radio`]: struct.Radio.html
@@ -203,6 +448,7 @@ pub trait Renderer: crate::Renderer {
         bounds: Rectangle,
         is_selected: bool,
         is_mouse_over: bool,
+        is_focused: bool,
         label: Self::Output,
         style: &Self::Style,
     ) -> Self::Output;
@@ -218,3 +464,83 @@ where
         Element::new(radio)
     }
 }
+
+impl<'a, Message, Renderer> From<Focusable<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer + row::Renderer + text::Renderer,
+    Message: 'static + Clone,
+{
+    fn from(
+        radio: Focusable<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(radio)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, widget::testing, Size};
+
+    use keyboard::KeyCode;
+
+    const NODE_SIZE: Size = Size::new(100.0, 20.0);
+
+    #[test]
+    fn space_and_enter_select_a_focused_radio() {
+        let mut state = State {
+            is_focused: true,
+            is_pressed: false,
+        };
+
+        for &key_code in &[KeyCode::Space, KeyCode::Enter] {
+            // Selecting the selected option produces a message anyway
+            let mut radio =
+                Radio::<u8, Null>::new(1, "One", Some(1), |value| value)
+                    .focusable(&mut state);
+
+            assert_eq!(
+                testing::send(
+                    &mut radio,
+                    &layout::Node::new(NODE_SIZE),
+                    testing::tap(key_code),
+                ),
+                vec![1]
+            );
+        }
+    }
+
+    #[test]
+    fn unfocused_radio_ignores_the_keyboard() {
+        let mut state = State::new();
+        let mut radio = Radio::<u8, Null>::new(1, "One", None, |value| value)
+            .focusable(&mut state);
+
+        assert_eq!(
+            testing::send(
+                &mut radio,
+                &layout::Node::new(NODE_SIZE),
+                testing::tap(KeyCode::Enter),
+            ),
+            vec![]
+        );
+        assert!(!state.is_pressed);
+    }
+
+    #[test]
+    fn radios_drawn_as_focused_ignore_the_keyboard() {
+        // A group shows its focus on a radio, but handles the keys itself
+        let mut radio =
+            Radio::<u8, Null>::new(1, "One", None, |value| value).focused(true);
+
+        assert_eq!(
+            testing::send(
+                &mut radio,
+                &layout::Node::new(NODE_SIZE),
+                testing::tap(KeyCode::Space),
+            ),
+            vec![]
+        );
+    }
+}
@@ -0,0 +1,550 @@
+//! Let users choose a value out of a set of options.
+use crate::{
+    column, focus,
+    input::{keyboard, mouse, ButtonState},
+    layout, radio, row, text, Clipboard, Column, Element, Event, Hasher,
+    Layout, Length, Point, Radio, Row, Widget,
+};
+
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// A set of [`Radio`] buttons representing the options of a single choice.
+///
+/// # Example
+/// ```
+/// # use iced_native::radio_group;
+/// # type RadioGroup<'a, T, Message> =
+/// #     iced_native::RadioGroup<'a, T, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone, PartialEq, Eq)]
+/// pub enum Language {
+///     Rust,
+///     Elm,
+///     Haskell,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// pub enum Message {
+///     LanguageSelected(Language),
+/// }
+///
+/// let mut state = radio_group::State::new();
+/// let selected = Some(Language::Rust);
+///
+/// let group = RadioGroup::new(
+///     &mut state,
+///     vec![
+///         ("Rust", Language::Rust),
+///         ("Elm", Language::Elm),
+///         ("Haskell", Language::Haskell),
+///     ],
+///     selected,
+///     Message::LanguageSelected,
+/// );
+/// ```
+///
+/// A [`RadioGroup`] can be focused by clicking it or by pressing `Tab`. Then,
+/// the arrow keys move the selection between its options.
+///
+/// [`Radio`]: ../radio/struct.Radio.html
+/// [`RadioGroup`]: struct.RadioGroup.html
+#[allow(missing_debug_implementations)]
+pub struct RadioGroup<'a, T, Message, Renderer: radio::Renderer> {
+    state: &'a mut State,
+    labels: Vec<String>,
+    values: Vec<T>,
+    selected: Option<usize>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    direction: Direction,
+    spacing: u16,
+    width: Length,
+    style: Rc<Renderer::Style>,
+    content: Element<'a, usize, Renderer>,
+}
+
+impl<'a, T, Message, Renderer> RadioGroup<'a, T, Message, Renderer>
+where
+    T: Eq + Clone,
+    Renderer: 'static
+        + radio::Renderer
+        + row::Renderer
+        + column::Renderer
+        + text::Renderer,
+{
+    /// Creates a new [`RadioGroup`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`RadioGroup`]
+    ///   * the options of the [`RadioGroup`], as pairs of a label and a value
+    ///   * the current selected value
+    ///   * a function that will be called when an option is selected. It
+    ///     receives the value of the option and must produce a `Message`.
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    /// [`State`]: struct.State.html
+    pub fn new<I, L, F>(
+        state: &'a mut State,
+        options: I,
+        selected: Option<T>,
+        on_select: F,
+    ) -> Self
+    where
+        I: IntoIterator<Item = (L, T)>,
+        L: Into<String>,
+        F: 'a + Fn(T) -> Message,
+    {
+        let (labels, values): (Vec<String>, Vec<T>) = options
+            .into_iter()
+            .map(|(label, value)| (label.into(), value))
+            .unzip();
+
+        let selected = selected
+            .and_then(|selected| values.iter().position(|v| *v == selected));
+
+        let mut radio_group = RadioGroup {
+            state,
+            labels,
+            values,
+            selected,
+            on_select: Box::new(on_select),
+            direction: Direction::default(),
+            spacing: 0,
+            width: Length::Fill,
+            style: Rc::new(Default::default()),
+            content: Column::new().into(),
+        };
+
+        radio_group.content = radio_group.build();
+        radio_group
+    }
+
+    /// Sets the [`Direction`] in which the options of the [`RadioGroup`] are
+    /// laid out.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self.content = self.build();
+        self
+    }
+
+    /// Sets the spacing _between_ the options of the [`RadioGroup`].
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self.content = self.build();
+        self
+    }
+
+    /// Sets the width of the [`RadioGroup`].
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self.content = self.build();
+        self
+    }
+
+    /// Sets the style of every [`Radio`] button of the [`RadioGroup`].
+    ///
+    /// [`Radio`]: ../radio/struct.Radio.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as radio::Renderer>::Style>,
+    ) -> Self {
+        self.style = Rc::new(style.into());
+        self.content = self.build();
+        self
+    }
+
+    /// Builds the [`Radio`] buttons of the options. They produce the index of
+    /// the option when clicked.
+    ///
+    /// [`Radio`]: ../radio/struct.Radio.html
+    fn build(&self) -> Element<'a, usize, Renderer> {
+        let selected = self.selected;
+
+        // The keyboard controls the selected option, or the first one
+        let focused = if self.state.is_focused {
+            Some(selected.unwrap_or(0))
+        } else {
+            None
+        };
+
+        let radios = self.labels.iter().enumerate().map(|(i, label)| {
+            Element::from(
+                Radio::new(i, label, selected, |i| i)
+                    .focused(focused == Some(i))
+                    .shared_style(Rc::clone(&self.style)),
+            )
+        });
+
+        match self.direction {
+            Direction::Vertical => radios
+                .fold(Column::new(), Column::push)
+                .width(self.width)
+                .spacing(self.spacing)
+                .into(),
+            Direction::Horizontal => radios
+                .fold(Row::new(), Row::push)
+                .width(self.width)
+                .spacing(self.spacing)
+                .into(),
+        }
+    }
+}
+
+/// The direction in which the options of a [`RadioGroup`] are laid out.
+///
+/// [`RadioGroup`]: struct.RadioGroup.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// One option below the other
+    Vertical,
+
+    /// One option next to the other
+    Horizontal,
+}
+
+impl Default for Direction {
+    fn default() -> Self {
+        Direction::Vertical
+    }
+}
+
+/// The local state of a [`RadioGroup`].
+///
+/// [`RadioGroup`]: struct.RadioGroup.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the [`RadioGroup`] is currently focused or not.
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for RadioGroup<'a, T, Message, Renderer>
+where
+    T: Eq + Clone,
+    Renderer: 'static
+        + radio::Renderer
+        + row::Renderer
+        + column::Renderer
+        + text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        match &event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+                ..
+            }) => {
+                let is_focused = layout.bounds().contains(cursor_position);
+
+                if is_focused != self.state.is_focused {
+                    self.state.is_focused = is_focused;
+                    self.content = self.build();
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) if self.state.is_focused && !self.values.is_empty() => {
+                let last = self.values.len() - 1;
+                let selected = self.selected;
+
+                // The selection wraps around the ends of the group
+                let index = match key_code {
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Left => {
+                        match selected {
+                            Some(0) | None => last,
+                            Some(i) => i - 1,
+                        }
+                    }
+                    keyboard::KeyCode::Down | keyboard::KeyCode::Right => {
+                        match selected {
+                            Some(i) if i < last => i + 1,
+                            _ => 0,
+                        }
+                    }
+                    _ => return,
+                };
+
+                messages.push((self.on_select)(self.values[index].clone()));
+
+                return;
+            }
+            _ => {}
+        }
+
+        let mut clicked = Vec::new();
+
+        self.content.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            &mut clicked,
+            renderer,
+            clipboard,
+        );
+
+        messages.extend(
+            clicked
+                .into_iter()
+                .map(|i| (self.on_select)(self.values[i].clone())),
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.direction.hash(state);
+        self.content.hash_layout(state);
+    }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        let was_focused = self.state.is_focused;
+
+        if !self.values.is_empty() {
+            traversal.visit(&mut self.state.is_focused);
+        }
+
+        // The radio buttons show the focus, so they need to be rebuilt
+        if self.state.is_focused != was_focused {
+            self.content = self.build();
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<RadioGroup<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Eq + Clone,
+    Renderer: 'static
+        + radio::Renderer
+        + row::Renderer
+        + column::Renderer
+        + text::Renderer,
+    Message: 'a,
+{
+    fn from(
+        radio_group: RadioGroup<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(radio_group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, widget::testing, Size};
+
+    const OPTIONS: [(&str, u8); 3] = [("A", 0), ("B", 1), ("C", 2)];
+
+    fn press(key_code: keyboard::KeyCode) -> Event {
+        testing::key(key_code, ButtonState::Pressed)
+    }
+
+    fn click() -> Event {
+        Event::Mouse(mouse::Event::Input {
+            button: mouse::Button::Left,
+            state: ButtonState::Pressed,
+            modifiers: keyboard::ModifiersState::default(),
+        })
+    }
+
+    /// Sends an event to a [`RadioGroup`] with the given [`State`] and
+    /// selected value, returning the value it selects, if any.
+    fn send(
+        state: &mut State,
+        selected: Option<u8>,
+        event: Event,
+        cursor_position: Point,
+    ) -> Option<u8> {
+        let mut group = RadioGroup::<u8, u8, Null>::new(
+            state,
+            OPTIONS.to_vec(),
+            selected,
+            |value| value,
+        );
+
+        let node = group.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+        let mut messages = Vec::new();
+
+        group.on_event(
+            event,
+            Layout::new(&node),
+            cursor_position,
+            &mut messages,
+            &Null,
+            None,
+        );
+
+        messages.pop()
+    }
+
+    fn focused() -> State {
+        State { is_focused: true }
+    }
+
+    #[test]
+    fn arrows_move_the_selection_and_wrap_around() {
+        let outside = Point::new(200.0, 200.0);
+        let mut state = focused();
+
+        for &(key_code, selected, next) in &[
+            (keyboard::KeyCode::Down, Some(0), 1),
+            (keyboard::KeyCode::Right, Some(1), 2),
+            (keyboard::KeyCode::Down, Some(2), 0),
+            (keyboard::KeyCode::Up, Some(2), 1),
+            (keyboard::KeyCode::Left, Some(1), 0),
+            (keyboard::KeyCode::Up, Some(0), 2),
+        ] {
+            assert_eq!(
+                send(&mut state, selected, press(key_code), outside),
+                Some(next)
+            );
+        }
+    }
+
+    #[test]
+    fn arrows_start_at_the_ends_without_a_selection() {
+        let outside = Point::new(200.0, 200.0);
+        let mut state = focused();
+
+        assert_eq!(
+            send(&mut state, None, press(keyboard::KeyCode::Down), outside),
+            Some(0)
+        );
+        assert_eq!(
+            send(&mut state, None, press(keyboard::KeyCode::Up), outside),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn unfocused_group_ignores_the_arrows() {
+        let outside = Point::new(200.0, 200.0);
+        let mut state = State::new();
+
+        assert_eq!(
+            send(&mut state, Some(0), press(keyboard::KeyCode::Down), outside),
+            None
+        );
+    }
+
+    #[test]
+    fn clicking_focuses_the_group() {
+        let mut state = State::new();
+
+        assert_eq!(
+            send(&mut state, None, click(), Point::new(10.0, 10.0)),
+            Some(0)
+        );
+        assert!(state.is_focused());
+
+        assert_eq!(
+            send(&mut state, Some(0), click(), Point::new(10.0, 90.0)),
+            None
+        );
+        assert!(!state.is_focused());
+    }
+
+    #[test]
+    fn group_is_a_single_focus_stop() {
+        let mut state = State::new();
+
+        {
+            let mut group = RadioGroup::<u8, u8, Null>::new(
+                &mut state,
+                OPTIONS.to_vec(),
+                None,
+                |value| value,
+            );
+            let mut traversal =
+                focus::Traversal::new(focus::Direction::Forward);
+
+            loop {
+                group.traverse_focus(&mut traversal);
+
+                if !traversal.finish_pass() {
+                    break;
+                }
+            }
+        }
+
+        assert!(state.is_focused());
+    }
+
+    #[test]
+    fn empty_group_is_not_focusable() {
+        let mut state = State::new();
+        let mut traversal = focus::Traversal::new(focus::Direction::Forward);
+
+        RadioGroup::<u8, u8, Null>::new(
+            &mut state,
+            Vec::<(&str, u8)>::new(),
+            None,
+            |value| value,
+        )
+        .traverse_focus(&mut traversal);
+
+        assert!(!traversal.finish_pass());
+        assert!(!state.is_focused());
+    }
+}
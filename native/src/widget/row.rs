@@ -2,8 +2,8 @@
 use std::hash::Hash;
 
 use crate::{
-    layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Widget,
+    focus, layout, Align, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Widget,
 };
 
 use std::u32;
@@ -195,6 +195,40 @@ where
             child.widget.hash_layout(state);
         }
     }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        for child in &mut self.children {
+            child.widget.traverse_focus(traversal);
+        }
+    }
+
+    fn next_redraw(&self) -> Option<std::time::Instant> {
+        self.children
+            .iter()
+            .filter_map(|child| child.widget.next_redraw())
+            .min()
+    }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        nodes.push(
+            layout::WidgetNode::new::<Self>(
+                depth,
+                layout.bounds(),
+                self.width,
+                self.height,
+            )
+            .padding(self.padding),
+        );
+
+        for (child, layout) in self.children.iter().zip(layout.children()) {
+            child.widget.inspect(layout, depth + 1, nodes);
+        }
+    }
 }
 
 /// The renderer of a [`Row`].
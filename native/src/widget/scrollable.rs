@@ -1,13 +1,23 @@
 //! Navigate an endless amount of content with a scrollbar.
 use crate::{
-    column,
+    column, focus,
     input::{mouse, ButtonState},
-    layout, Align, Clipboard, Column, Element, Event, Hasher, Layout, Length,
-    Point, Rectangle, Size, Widget,
+    layout, time, Align, Clipboard, Column, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
 };
 
+use std::time::{Duration, Instant};
 use std::{f32, hash::Hash, u32};
 
+/// The duration of the fade-out animation of a [`Scrollbar`] that hides
+/// automatically.
+///
+/// [`Scrollbar`]: struct.Scrollbar.html
+const FADE_DURATION: Duration = Duration::from_millis(300);
+
+/// The interval between two frames of the fade-out animation.
+const FADE_FRAME: Duration = Duration::from_millis(16);
+
 /// A widget that can vertically display an infinite amount of content with a
 /// scrollbar.
 #[allow(missing_debug_implementations)]
@@ -16,6 +26,8 @@ pub struct Scrollable<'a, Message, Renderer: self::Renderer> {
     height: Length,
     max_height: u32,
     content: Column<'a, Message, Renderer>,
+    stick_to_end: bool,
+    visibility: Visibility,
     style: Renderer::Style,
 }
 
@@ -30,6 +42,8 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
             height: Length::Shrink,
             max_height: u32::MAX,
             content: Column::new(),
+            stick_to_end: false,
+            visibility: Visibility::default(),
             style: Renderer::Style::default(),
         }
     }
@@ -92,6 +106,31 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         self
     }
 
+    /// Sets whether the [`Scrollable`] should stick to the end of its
+    /// contents.
+    ///
+    /// When enabled, the [`Scrollable`] stays scrolled to the bottom as new
+    /// content is appended, like a chat or a log. It stops following the end
+    /// once the user scrolls up, and starts following it again once the user
+    /// scrolls back to the bottom.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn stick_to_end(mut self, stick_to_end: bool) -> Self {
+        self.stick_to_end = stick_to_end;
+        self
+    }
+
+    /// Sets the [`Visibility`] of the scrollbar of the [`Scrollable`].
+    ///
+    /// By default, the scrollbar is always visible.
+    ///
+    /// [`Visibility`]: enum.Visibility.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
     /// Sets the style of the [`Scrollable`] .
     ///
     /// [`Scrollable`]: struct.Scrollable.html
@@ -110,6 +149,14 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         self.content = self.content.push(child);
         self
     }
+
+    fn offset(&self, bounds: Rectangle, content_bounds: Rectangle) -> u32 {
+        if self.stick_to_end && !self.state.is_detached_from_end {
+            (content_bounds.height - bounds.height).max(0.0).round() as u32
+        } else {
+            self.state.offset(bounds, content_bounds)
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -161,6 +208,11 @@ where
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
+        if self.stick_to_end && !self.state.is_detached_from_end {
+            self.state.offset =
+                (content_bounds.height - bounds.height).max(0.0);
+        }
+
         // TODO: Event capture. Nested scrollables should capture scroll events.
         if is_mouse_over {
             match event {
@@ -174,23 +226,31 @@ where
                             self.state.scroll(y, bounds, content_bounds);
                         }
                     }
+
+                    self.state.last_activity = Some(time::now());
                 }
                 _ => {}
             }
         }
 
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
+        let offset = self.offset(bounds, content_bounds);
+        let scrollbar =
+            renderer.scrollbar(bounds, content_bounds, offset, &self.style);
         let is_mouse_over_scrollbar = scrollbar
             .as_ref()
             .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
             .unwrap_or(false);
 
+        if is_mouse_over_scrollbar || self.state.is_scroller_grabbed() {
+            self.state.last_activity = Some(time::now());
+        }
+
         if self.state.is_scroller_grabbed() {
             match event {
                 Event::Mouse(mouse::Event::Input {
                     button: mouse::Button::Left,
                     state: ButtonState::Released,
+                    ..
                 }) => {
                     self.state.scroller_grabbed_at = None;
                 }
@@ -215,6 +275,7 @@ where
                 Event::Mouse(mouse::Event::Input {
                     button: mouse::Button::Left,
                     state: ButtonState::Pressed,
+                    ..
                 }) => {
                     if let Some(scrollbar) = scrollbar {
                         if let Some(scroller_grabbed_at) =
@@ -241,8 +302,7 @@ where
         let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
             Point::new(
                 cursor_position.x,
-                cursor_position.y
-                    + self.state.offset(bounds, content_bounds) as f32,
+                cursor_position.y + self.offset(bounds, content_bounds) as f32,
             )
         } else {
             // TODO: Make `cursor_position` an `Option<Point>` so we can encode
@@ -272,8 +332,9 @@ where
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
+        let offset = self.offset(bounds, content_bounds);
+        let scrollbar =
+            renderer.scrollbar(bounds, content_bounds, offset, &self.style);
 
         let is_mouse_over = bounds.contains(cursor_position);
         let is_mouse_over_scrollbar = scrollbar
@@ -281,6 +342,12 @@ where
             .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
             .unwrap_or(false);
 
+        let opacity = self.state.scrollbar_opacity(
+            self.visibility,
+            is_mouse_over,
+            is_mouse_over_scrollbar,
+        );
+
         let content = {
             let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
                 Point::new(cursor_position.x, cursor_position.y + offset as f32)
@@ -298,14 +365,17 @@ where
 
         self::Renderer::draw(
             renderer,
-            &self.state,
             bounds,
             content_layout.bounds(),
             is_mouse_over,
-            is_mouse_over_scrollbar,
-            scrollbar,
             offset,
-            &self.style,
+            ScrollbarState {
+                scrollbar,
+                is_mouse_over: is_mouse_over_scrollbar,
+                is_grabbed: self.state.is_scroller_grabbed(),
+                opacity,
+                style: &self.style,
+            },
             content,
         )
     }
@@ -318,6 +388,40 @@ where
 
         self.content.hash_layout(state)
     }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        self.content.traverse_focus(traversal);
+    }
+
+    fn next_redraw(&self) -> Option<Instant> {
+        let content = self.content.next_redraw();
+        let fade = self.state.next_fade_frame(self.visibility);
+
+        match (content, fade) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        nodes.push(layout::WidgetNode::new::<Self>(
+            depth,
+            layout.bounds(),
+            Length::Fill,
+            self.height,
+        ));
+
+        self.content.inspect(
+            layout.children().next().unwrap(),
+            depth + 1,
+            nodes,
+        );
+    }
 }
 
 /// The local state of a [`Scrollable`].
@@ -327,6 +431,8 @@ where
 pub struct State {
     scroller_grabbed_at: Option<f32>,
     offset: f32,
+    is_detached_from_end: bool,
+    last_activity: Option<Instant>,
 }
 
 impl State {
@@ -355,6 +461,8 @@ impl State {
         self.offset = (self.offset - delta_y)
             .max(0.0)
             .min((content_bounds.height - bounds.height) as f32);
+
+        self.update_detachment(bounds, content_bounds);
     }
 
     /// Moves the scroll position to a relative amount, given the bounds of
@@ -373,6 +481,16 @@ impl State {
     ) {
         self.offset =
             ((content_bounds.height - bounds.height) * percentage).max(0.0);
+
+        self.update_detachment(bounds, content_bounds);
+    }
+
+    /// Makes a [`Scrollable`] that sticks to the end of its contents follow
+    /// the end again, even if the user has scrolled up.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn snap_to_end(&mut self) {
+        self.is_detached_from_end = false;
     }
 
     /// Returns the current scrolling offset of the [`State`], given the bounds
@@ -391,6 +509,94 @@ impl State {
     pub fn is_scroller_grabbed(&self) -> bool {
         self.scroller_grabbed_at.is_some()
     }
+
+    fn update_detachment(
+        &mut self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let hidden_content = (content_bounds.height - bounds.height).max(0.0);
+
+        // We allow some tolerance, as offsets are rounded when drawing
+        self.is_detached_from_end = hidden_content - self.offset >= 1.0;
+    }
+
+    fn scrollbar_opacity(
+        &self,
+        visibility: Visibility,
+        is_mouse_over: bool,
+        is_mouse_over_scrollbar: bool,
+    ) -> f32 {
+        match visibility {
+            Visibility::Always => 1.0,
+            Visibility::OnHover => {
+                if is_mouse_over || self.is_scroller_grabbed() {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            Visibility::AutoHide(delay) => {
+                if is_mouse_over_scrollbar || self.is_scroller_grabbed() {
+                    return 1.0;
+                }
+
+                let inactivity = match self.last_activity {
+                    Some(last_activity) => {
+                        time::now().saturating_duration_since(last_activity)
+                    }
+                    None => return 0.0,
+                };
+
+                if inactivity <= delay {
+                    1.0
+                } else {
+                    let fade = (inactivity - delay).as_secs_f32()
+                        / FADE_DURATION.as_secs_f32();
+
+                    (1.0 - fade).max(0.0)
+                }
+            }
+        }
+    }
+
+    fn next_fade_frame(&self, visibility: Visibility) -> Option<Instant> {
+        let delay = match visibility {
+            Visibility::AutoHide(delay) => delay,
+            _ => return None,
+        };
+
+        let last_activity = self.last_activity?;
+        let now = time::now();
+        let fade_start = last_activity + delay;
+
+        if now < fade_start {
+            Some(fade_start)
+        } else if now < fade_start + FADE_DURATION {
+            Some(now + FADE_FRAME)
+        } else {
+            None
+        }
+    }
+}
+
+/// The visibility of the scrollbar of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Visibility {
+    /// The scrollbar is always visible.
+    #[default]
+    Always,
+
+    /// The scrollbar is visible while the mouse is over the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    OnHover,
+
+    /// The scrollbar is visible while scrolling or hovering it, and fades out
+    /// after the given duration of inactivity.
+    AutoHide(Duration),
 }
 
 /// The scrollbar of a [`Scrollable`].
@@ -439,6 +645,38 @@ impl Scrollbar {
     }
 }
 
+/// The state of the [`Scrollbar`] of a [`Scrollable`] being drawn.
+///
+/// [`Scrollbar`]: struct.Scrollbar.html
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug)]
+pub struct ScrollbarState<'a, Style> {
+    /// The [`Scrollbar`] to draw, if the contents overflow.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub scrollbar: Option<Scrollbar>,
+
+    /// Whether the mouse is over the [`Scrollbar`] or not.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub is_mouse_over: bool,
+
+    /// Whether the [`Scroller`] is grabbed or not.
+    ///
+    /// [`Scroller`]: struct.Scroller.html
+    pub is_grabbed: bool,
+
+    /// The opacity of the [`Scrollbar`], from `0.0` (hidden) to `1.0`.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub opacity: f32,
+
+    /// The style of the [`Scrollbar`].
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub style: &'a Style,
+}
+
 /// The handle of a [`Scrollbar`].
 ///
 /// [`Scrollbar`]: struct.Scrollbar.html
@@ -461,8 +699,8 @@ pub trait Renderer: crate::Renderer + Sized {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Returns the [`Scrollbar`] given the bounds and content bounds of a
-    /// [`Scrollable`].
+    /// Returns the [`Scrollbar`] given the bounds, content bounds, and style
+    /// of a [`Scrollable`].
     ///
     /// [`Scrollbar`]: struct.Scrollbar.html
     /// [`Scrollable`]: struct.Scrollable.html
@@ -471,33 +709,28 @@ pub trait Renderer: crate::Renderer + Sized {
         bounds: Rectangle,
         content_bounds: Rectangle,
         offset: u32,
+        style: &Self::Style,
     ) -> Option<Scrollbar>;
 
     /// Draws the [`Scrollable`].
     ///
     /// It receives:
-    /// - the [`State`] of the [`Scrollable`]
     /// - the bounds of the [`Scrollable`] widget
     /// - the bounds of the [`Scrollable`] content
     /// - whether the mouse is over the [`Scrollable`] or not
-    /// - whether the mouse is over the [`Scrollbar`] or not
-    /// - a optional [`Scrollbar`] to be rendered
     /// - the scrolling offset
+    /// - the [`ScrollbarState`] to be rendered
     /// - the drawn content
     ///
-    /// [`Scrollbar`]: struct.Scrollbar.html
     /// [`Scrollable`]: struct.Scrollable.html
-    /// [`State`]: struct.State.html
+    /// [`ScrollbarState`]: struct.ScrollbarState.html
     fn draw(
         &mut self,
-        scrollable: &State,
         bounds: Rectangle,
         content_bounds: Rectangle,
         is_mouse_over: bool,
-        is_mouse_over_scrollbar: bool,
-        scrollbar: Option<Scrollbar>,
         offset: u32,
-        style: &Self::Style,
+        scrollbar: ScrollbarState<'_, Self::Style>,
         content: Self::Output,
     ) -> Self::Output;
 }
@@ -514,3 +747,113 @@ where
         Element::new(scrollable)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, time::Clock};
+
+    const DELAY: Duration = Duration::from_secs(1);
+
+    fn scroll(state: &mut State, content_height: f32, delta_y: f32) -> u32 {
+        let node = layout::Node::with_children(
+            Size::new(100.0, 100.0),
+            vec![layout::Node::new(Size::new(100.0, content_height))],
+        );
+        let layout = Layout::new(&node);
+        let bounds = layout.bounds();
+        let content_bounds = layout.children().next().unwrap().bounds();
+
+        let mut scrollable =
+            Scrollable::<(), Null>::new(state).stick_to_end(true);
+
+        scrollable.on_event(
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Pixels { x: 0.0, y: delta_y },
+            }),
+            layout,
+            Point::new(50.0, 50.0),
+            &mut Vec::new(),
+            &Null,
+            None,
+        );
+
+        scrollable.offset(bounds, content_bounds)
+    }
+
+    #[test]
+    fn sticks_to_end_until_scrolled_up() {
+        let mut state = State::new();
+
+        assert_eq!(scroll(&mut state, 300.0, 0.0), 200);
+        assert_eq!(scroll(&mut state, 500.0, 0.0), 400);
+
+        assert_eq!(scroll(&mut state, 500.0, 50.0), 350);
+        assert_eq!(scroll(&mut state, 600.0, 0.0), 350);
+
+        assert_eq!(scroll(&mut state, 600.0, -1000.0), 500);
+        assert_eq!(scroll(&mut state, 700.0, 0.0), 600);
+
+        assert_eq!(scroll(&mut state, 700.0, 1000.0), 0);
+        assert_eq!(scroll(&mut state, 800.0, 0.0), 0);
+
+        state.snap_to_end();
+
+        assert_eq!(scroll(&mut state, 800.0, 0.0), 700);
+    }
+
+    #[test]
+    fn auto_hide_fades_out_after_inactivity() {
+        let start = Instant::now();
+        let clock = Clock::manual(start);
+        let previous = time::set_clock(clock.clone());
+
+        let visibility = Visibility::AutoHide(DELAY);
+        let mut state = State::new();
+
+        assert_eq!(state.scrollbar_opacity(visibility, true, false), 0.0);
+        assert_eq!(state.next_fade_frame(visibility), None);
+
+        state.last_activity = Some(start);
+
+        assert_eq!(state.scrollbar_opacity(visibility, false, false), 1.0);
+        assert_eq!(state.next_fade_frame(visibility), Some(start + DELAY));
+
+        clock.advance(DELAY + FADE_DURATION / 2);
+
+        assert_eq!(state.scrollbar_opacity(visibility, false, false), 0.5);
+        assert_eq!(state.scrollbar_opacity(visibility, false, true), 1.0);
+        assert_eq!(
+            state.next_fade_frame(visibility),
+            Some(clock.now() + FADE_FRAME)
+        );
+
+        clock.advance(FADE_DURATION);
+
+        assert_eq!(state.scrollbar_opacity(visibility, false, false), 0.0);
+        assert_eq!(state.next_fade_frame(visibility), None);
+
+        let _ = time::set_clock(previous);
+    }
+
+    #[test]
+    fn other_visibilities_do_not_fade() {
+        let mut state = State::new();
+        state.last_activity = Some(Instant::now());
+
+        assert_eq!(
+            state.scrollbar_opacity(Visibility::Always, false, false),
+            1.0
+        );
+        assert_eq!(
+            state.scrollbar_opacity(Visibility::OnHover, false, false),
+            0.0
+        );
+        assert_eq!(
+            state.scrollbar_opacity(Visibility::OnHover, true, false),
+            1.0
+        );
+        assert_eq!(state.next_fade_frame(Visibility::Always), None);
+        assert_eq!(Visibility::default(), Visibility::Always);
+    }
+}
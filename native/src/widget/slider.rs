@@ -5,18 +5,30 @@
 //! [`Slider`]: struct.Slider.html
 //! [`State`]: struct.State.html
 use crate::{
-    input::{mouse, ButtonState},
+    focus,
+    input::{keyboard, mouse, ButtonState},
     layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
     Rectangle, Size, Widget,
 };
 
 use std::{hash::Hash, ops::RangeInclusive};
 
+/// The amount of steps the value of a [`Slider`] changes when pressing
+/// `PageUp` or `PageDown`.
+///
+/// [`Slider`]: struct.Slider.html
+const PAGE_STEPS: f32 = 10.0;
+
 /// An horizontal bar and a handle that selects a single value from a range of
 /// values.
 ///
 /// A [`Slider`] will try to fill the horizontal space of its container.
 ///
+/// Once clicked or reached with `Tab`, a [`Slider`] is focused and can be
+/// controlled with the keyboard: the arrow keys adjust its value by one step,
+/// `PageUp` and `PageDown` by ten steps, and `Home` and `End` jump to the
+/// bounds of its range.
+///
 /// [`Slider`]: struct.Slider.html
 ///
 /// # Example
@@ -40,6 +52,7 @@ pub struct Slider<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     range: RangeInclusive<f32>,
     value: f32,
+    step: Option<f32>,
     on_change: Box<dyn Fn(f32) -> Message>,
     width: Length,
     style: Renderer::Style,
@@ -71,6 +84,7 @@ impl<'a, Message, Renderer: self::Renderer> Slider<'a, Message, Renderer> {
             state,
             value: value.max(*range.start()).min(*range.end()),
             range,
+            step: None,
             on_change: Box::new(on_change),
             width: Length::Fill,
             style: Renderer::Style::default(),
@@ -85,6 +99,19 @@ impl<'a, Message, Renderer: self::Renderer> Slider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the step of the [`Slider`].
+    ///
+    /// When set, the values produced by the [`Slider`] are rounded to
+    /// multiples of the step, counting from the start of its range. By
+    /// default, there is no rounding and the keyboard adjusts the value in
+    /// steps of a hundredth of the range.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
     /// Sets the style of the [`Slider`].
     ///
     /// [`Slider`]: struct.Slider.html
@@ -100,6 +127,7 @@ impl<'a, Message, Renderer: self::Renderer> Slider<'a, Message, Renderer> {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_dragging: bool,
+    is_focused: bool,
 }
 
 impl State {
@@ -109,6 +137,20 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Slider`] is currently being dragged or not.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn is_dragging(&self) -> bool {
+        self.is_dragging
+    }
+
+    /// Returns whether the [`Slider`] is currently focused or not.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -147,19 +189,34 @@ where
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) {
-        let mut change = || {
+        let (start, end) = self.range.clone().into_inner();
+
+        let snap = |value: f32| {
+            let value = match self.step {
+                Some(step) if step > 0.0 => {
+                    start + ((value - start) / step).round() * step
+                }
+                _ => value,
+            };
+
+            value.max(start).min(end)
+        };
+
+        let mut change = |value: f32| {
+            messages.push((self.on_change)(snap(value)));
+        };
+
+        let drag_value = || {
             let bounds = layout.bounds();
 
             if cursor_position.x <= bounds.x {
-                messages.push((self.on_change)(*self.range.start()));
+                start
             } else if cursor_position.x >= bounds.x + bounds.width {
-                messages.push((self.on_change)(*self.range.end()));
+                end
             } else {
                 let percent = (cursor_position.x - bounds.x) / bounds.width;
-                let value = (self.range.end() - self.range.start()) * percent
-                    + self.range.start();
 
-                messages.push((self.on_change)(value));
+                (end - start) * percent + start
             }
         };
 
@@ -167,12 +224,17 @@ where
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state,
+                ..
             }) => match state {
                 ButtonState::Pressed => {
-                    if layout.bounds().contains(cursor_position) {
-                        change();
+                    let is_clicked = layout.bounds().contains(cursor_position);
+
+                    if is_clicked {
+                        change(drag_value());
                         self.state.is_dragging = true;
                     }
+
+                    self.state.is_focused = is_clicked;
                 }
                 ButtonState::Released => {
                     self.state.is_dragging = false;
@@ -180,7 +242,39 @@ where
             },
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                 if self.state.is_dragging {
-                    change();
+                    change(drag_value());
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) if self.state.is_focused => {
+                let step = self.step.unwrap_or((end - start) / 100.0);
+
+                let value = match key_code {
+                    keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                        Some(self.value - step)
+                    }
+                    keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                        Some(self.value + step)
+                    }
+                    keyboard::KeyCode::PageDown => {
+                        Some(self.value - step * PAGE_STEPS)
+                    }
+                    keyboard::KeyCode::PageUp => {
+                        Some(self.value + step * PAGE_STEPS)
+                    }
+                    keyboard::KeyCode::Home => Some(start),
+                    keyboard::KeyCode::End => Some(end),
+                    _ => None,
+                };
+
+                // Keys pressed at the bounds of the range change nothing
+                if let Some(value) = value.map(&snap) {
+                    if value != self.value {
+                        change(value);
+                    }
                 }
             }
             _ => {}
@@ -199,7 +293,7 @@ where
             cursor_position,
             self.range.clone(),
             self.value,
-            self.state.is_dragging,
+            self.state,
             &self.style,
         )
     }
@@ -207,6 +301,10 @@ where
     fn hash_layout(&self, state: &mut Hasher) {
         self.width.hash(state);
     }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        traversal.visit(&mut self.state.is_focused);
+    }
 }
 
 /// The renderer of a [`Slider`].
@@ -228,22 +326,22 @@ pub trait Renderer: crate::Renderer {
     /// Draws a [`Slider`].
     ///
     /// It receives:
-    ///   * the current cursor position
     ///   * the bounds of the [`Slider`]
-    ///   * the local state of the [`Slider`]
+    ///   * the current cursor position
     ///   * the range of values of the [`Slider`]
     ///   * the current value of the [`Slider`]
+    ///   * the local [`State`] of the [`Slider`], which tells whether it is
+    ///     being dragged or focused
     ///
     /// [`Slider`]: struct.Slider.html
     /// [`State`]: struct.State.html
-    /// [`Class`]: enum.Class.html
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
         range: RangeInclusive<f32>,
         value: f32,
-        is_dragging: bool,
+        state: &State,
         style: &Self::Style,
     ) -> Self::Output;
 }
@@ -260,3 +358,160 @@ where
         Element::new(slider)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, widget::testing};
+
+    /// Presses the given key on a focused [`Slider`] over `0.0..=50.0`,
+    /// returning the value it produces, if any.
+    fn press(
+        value: f32,
+        step: Option<f32>,
+        key_code: keyboard::KeyCode,
+    ) -> Option<f32> {
+        let mut state = State {
+            is_dragging: false,
+            is_focused: true,
+        };
+
+        let mut slider =
+            Slider::<f32, Null>::new(&mut state, 0.0..=50.0, value, |value| {
+                value
+            });
+
+        if let Some(step) = step {
+            slider = slider.step(step);
+        }
+
+        let node = layout::Node::new(Size::new(100.0, 30.0));
+        let mut messages = Vec::new();
+
+        slider.on_event(
+            testing::key(key_code, ButtonState::Pressed),
+            Layout::new(&node),
+            Point::ORIGIN,
+            &mut messages,
+            &Null,
+            None,
+        );
+
+        messages.pop()
+    }
+
+    #[test]
+    fn arrows_move_a_hundredth_of_the_range_by_default() {
+        assert_eq!(press(10.0, None, keyboard::KeyCode::Right), Some(10.5));
+        assert_eq!(press(10.0, None, keyboard::KeyCode::Up), Some(10.5));
+        assert_eq!(press(10.0, None, keyboard::KeyCode::Left), Some(9.5));
+        assert_eq!(press(10.0, None, keyboard::KeyCode::Down), Some(9.5));
+    }
+
+    #[test]
+    fn arrows_move_by_step() {
+        assert_eq!(
+            press(10.0, Some(5.0), keyboard::KeyCode::Right),
+            Some(15.0)
+        );
+        assert_eq!(press(10.0, Some(5.0), keyboard::KeyCode::Left), Some(5.0));
+
+        // Values out of step are snapped to the closest one
+        assert_eq!(
+            press(11.0, Some(5.0), keyboard::KeyCode::Right),
+            Some(15.0)
+        );
+    }
+
+    #[test]
+    fn page_keys_move_ten_steps() {
+        assert_eq!(press(10.0, None, keyboard::KeyCode::PageUp), Some(15.0));
+        assert_eq!(press(10.0, None, keyboard::KeyCode::PageDown), Some(5.0));
+        assert_eq!(
+            press(10.0, Some(2.0), keyboard::KeyCode::PageUp),
+            Some(30.0)
+        );
+
+        // The value never leaves the range
+        assert_eq!(
+            press(45.0, Some(2.0), keyboard::KeyCode::PageUp),
+            Some(50.0)
+        );
+        assert_eq!(
+            press(5.0, Some(2.0), keyboard::KeyCode::PageDown),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn home_and_end_jump_to_the_bounds() {
+        assert_eq!(press(10.0, None, keyboard::KeyCode::Home), Some(0.0));
+        assert_eq!(press(10.0, None, keyboard::KeyCode::End), Some(50.0));
+    }
+
+    #[test]
+    fn keys_at_the_bounds_change_nothing() {
+        assert_eq!(press(0.0, None, keyboard::KeyCode::Left), None);
+        assert_eq!(press(0.0, None, keyboard::KeyCode::Home), None);
+        assert_eq!(press(50.0, Some(5.0), keyboard::KeyCode::PageUp), None);
+        assert_eq!(press(50.0, None, keyboard::KeyCode::End), None);
+    }
+
+    #[test]
+    fn ignores_keys_while_unfocused() {
+        let mut state = State::new();
+        let mut slider =
+            Slider::<f32, Null>::new(&mut state, 0.0..=50.0, 10.0, |value| {
+                value
+            });
+
+        let node = layout::Node::new(Size::new(100.0, 30.0));
+        let mut messages = Vec::new();
+
+        slider.on_event(
+            testing::key(keyboard::KeyCode::Right, ButtonState::Pressed),
+            Layout::new(&node),
+            Point::ORIGIN,
+            &mut messages,
+            &Null,
+            None,
+        );
+
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn accepts_reversed_ranges() {
+        let mut state = State {
+            is_dragging: false,
+            is_focused: true,
+        };
+
+        let mut slider =
+            Slider::<f32, Null>::new(&mut state, 1.0..=0.0, 0.5, |value| value);
+
+        let node = layout::Node::new(Size::new(100.0, 30.0));
+        let mut messages = Vec::new();
+
+        for event in [
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+                modifiers: keyboard::ModifiersState::default(),
+            }),
+            testing::key(keyboard::KeyCode::Right, ButtonState::Pressed),
+        ] {
+            slider.on_event(
+                event,
+                Layout::new(&node),
+                Point::new(50.0, 15.0),
+                &mut messages,
+                &Null,
+                None,
+            );
+        }
+
+        // Like `Slider::new`, values end up at the end of the range
+        assert_eq!(messages, vec![0.0]);
+    }
+}
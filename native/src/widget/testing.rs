@@ -0,0 +1,49 @@
+//! Drive widgets with synthetic events in tests.
+use crate::{
+    input::{keyboard, ButtonState},
+    layout,
+    renderer::Null,
+    Event, Layout, Point, Widget,
+};
+
+/// Creates a keyboard event for the given key.
+pub fn key(key_code: keyboard::KeyCode, state: ButtonState) -> Event {
+    Event::Keyboard(keyboard::Event::Input {
+        state,
+        key_code,
+        scan_code: 0,
+        repeat: false,
+        modifiers: keyboard::ModifiersState::default(),
+    })
+}
+
+/// Presses and releases the given key.
+pub fn tap(key_code: keyboard::KeyCode) -> Vec<Event> {
+    vec![
+        key(key_code, ButtonState::Pressed),
+        key(key_code, ButtonState::Released),
+    ]
+}
+
+/// Sends the given events to a widget laid out as the given node, with the
+/// cursor away from it, and returns the messages it produces.
+pub fn send<Message>(
+    widget: &mut dyn Widget<Message, Null>,
+    node: &layout::Node,
+    events: Vec<Event>,
+) -> Vec<Message> {
+    let mut messages = Vec::new();
+
+    for event in events {
+        widget.on_event(
+            event,
+            Layout::new(node),
+            Point::new(-1.0, -1.0),
+            &mut messages,
+            &Null,
+            None,
+        );
+    }
+
+    messages
+}
@@ -168,6 +168,23 @@ where
         self.width.hash(state);
         self.height.hash(state);
     }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        nodes.push(
+            layout::WidgetNode::new::<Self>(
+                depth,
+                layout.bounds(),
+                self.width,
+                self.height,
+            )
+            .label(self.content.clone()),
+        );
+    }
 }
 
 /// The renderer of a [`Text`] fragment.
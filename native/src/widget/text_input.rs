@@ -4,12 +4,16 @@
 //!
 //! [`TextInput`]: struct.TextInput.html
 //! [`State`]: struct.State.html
+mod history;
+
 use crate::{
+    focus,
     input::{keyboard, mouse, ButtonState},
     layout, Clipboard, Element, Event, Font, Hasher, Layout, Length, Point,
     Rectangle, Size, Widget,
 };
 
+use history::{Edit, History, Snapshot};
 use std::u32;
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -91,6 +95,9 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
 
     /// Converts the [`TextInput`] into a secure password input.
     ///
+    /// A secure input keeps no undo history, so its previous contents are
+    /// never stored.
+    ///
     /// [`TextInput`]: struct.TextInput.html
     pub fn password(mut self) -> Self {
         self.is_secure = true;
@@ -153,6 +160,42 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
         self.style = style.into();
         self
     }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            value: self.value.to_string(),
+            cursor_position: self.state.cursor_position(&self.value),
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.value = Value::new(&snapshot.value);
+        self.state.cursor_position = snapshot.cursor_position;
+    }
+
+    fn edit(&mut self, edit: Edit, apply: impl FnOnce(&mut Value, &mut State)) {
+        // Secure inputs never keep their previous contents around
+        if self.is_secure {
+            apply(&mut self.value, self.state);
+
+            self.state.history = History::default();
+            return;
+        }
+
+        let cursor_position = self.state.cursor_position(&self.value);
+
+        let before = if self.state.history.starts_step(edit, cursor_position) {
+            Some(self.snapshot())
+        } else {
+            None
+        };
+
+        apply(&mut self.value, self.state);
+
+        let cursor_position = self.state.cursor_position(&self.value);
+
+        self.state.history.record(edit, before, cursor_position);
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -202,6 +245,7 @@ where
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state: ButtonState::Pressed,
+                ..
             }) => {
                 let is_clicked = layout.bounds().contains(cursor_position);
 
@@ -241,16 +285,23 @@ where
                 }
 
                 self.state.is_focused = is_clicked;
+                self.state.history.break_coalescing();
             }
             Event::Keyboard(keyboard::Event::CharacterReceived(c))
                 if self.state.is_focused
                     && self.state.is_pasting.is_none()
                     && !c.is_control() =>
             {
-                let cursor_position = self.state.cursor_position(&self.value);
+                let edit = Edit::Insert {
+                    is_whitespace: c.is_whitespace(),
+                };
+
+                self.edit(edit, |value, state| {
+                    let cursor_position = state.cursor_position(value);
 
-                self.value.insert(cursor_position, c);
-                self.state.move_cursor_right(&self.value);
+                    value.insert(cursor_position, c);
+                    state.move_cursor_right(value);
+                });
 
                 let message = (self.on_change)(self.value.to_string());
                 messages.push(message);
@@ -259,6 +310,7 @@ where
                 key_code,
                 state: ButtonState::Pressed,
                 modifiers,
+                ..
             }) if self.state.is_focused => match key_code {
                 keyboard::KeyCode::Enter => {
                     if let Some(on_submit) = self.on_submit.clone() {
@@ -270,9 +322,10 @@ where
                         self.state.cursor_position(&self.value);
 
                     if cursor_position > 0 {
-                        self.state.move_cursor_left(&self.value);
-
-                        let _ = self.value.remove(cursor_position - 1);
+                        self.edit(Edit::Delete, |value, state| {
+                            state.move_cursor_left(value);
+                            value.remove(cursor_position - 1);
+                        });
 
                         let message = (self.on_change)(self.value.to_string());
                         messages.push(message);
@@ -283,13 +336,17 @@ where
                         self.state.cursor_position(&self.value);
 
                     if cursor_position < self.value.len() {
-                        let _ = self.value.remove(cursor_position);
+                        self.edit(Edit::Delete, |value, _| {
+                            value.remove(cursor_position);
+                        });
 
                         let message = (self.on_change)(self.value.to_string());
                         messages.push(message);
                     }
                 }
                 keyboard::KeyCode::Left => {
+                    self.state.history.break_coalescing();
+
                     if platform::is_jump_modifier_pressed(modifiers)
                         && !self.is_secure
                     {
@@ -299,6 +356,8 @@ where
                     }
                 }
                 keyboard::KeyCode::Right => {
+                    self.state.history.break_coalescing();
+
                     if platform::is_jump_modifier_pressed(modifiers)
                         && !self.is_secure
                     {
@@ -309,9 +368,41 @@ where
                 }
                 keyboard::KeyCode::Home => {
                     self.state.cursor_position = 0;
+                    self.state.history.break_coalescing();
                 }
                 keyboard::KeyCode::End => {
                     self.state.move_cursor_to_end(&self.value);
+                    self.state.history.break_coalescing();
+                }
+                keyboard::KeyCode::Z
+                    if platform::is_copy_paste_modifier_pressed(modifiers) =>
+                {
+                    let current = self.snapshot();
+
+                    let snapshot = if modifiers.shift {
+                        self.state.history.redo(current)
+                    } else {
+                        self.state.history.undo(current)
+                    };
+
+                    if let Some(snapshot) = snapshot {
+                        self.restore(snapshot);
+
+                        let message = (self.on_change)(self.value.to_string());
+                        messages.push(message);
+                    }
+                }
+                keyboard::KeyCode::Y
+                    if platform::is_redo_modifier_pressed(modifiers) =>
+                {
+                    let current = self.snapshot();
+
+                    if let Some(snapshot) = self.state.history.redo(current) {
+                        self.restore(snapshot);
+
+                        let message = (self.on_change)(self.value.to_string());
+                        messages.push(message);
+                    }
                 }
                 keyboard::KeyCode::V => {
                     if platform::is_copy_paste_modifier_pressed(modifiers) {
@@ -330,16 +421,21 @@ where
                                 }
                             };
 
-                            let cursor_position =
-                                self.state.cursor_position(&self.value);
+                            self.edit(Edit::Paste, |value, state| {
+                                let cursor_position =
+                                    state.cursor_position(value);
+
+                                value.insert_many(
+                                    cursor_position,
+                                    content.clone(),
+                                );
 
-                            self.value
-                                .insert_many(cursor_position, content.clone());
+                                state.move_cursor_right_by_amount(
+                                    value,
+                                    content.len(),
+                                );
+                            });
 
-                            self.state.move_cursor_right_by_amount(
-                                &self.value,
-                                content.len(),
-                            );
                             self.state.is_pasting = Some(content);
 
                             let message =
@@ -353,15 +449,12 @@ where
                 _ => {}
             },
             Event::Keyboard(keyboard::Event::Input {
-                key_code,
+                key_code: keyboard::KeyCode::V,
                 state: ButtonState::Released,
                 ..
-            }) => match key_code {
-                keyboard::KeyCode::V => {
-                    self.state.is_pasting = None;
-                }
-                _ => {}
-            },
+            }) => {
+                self.state.is_pasting = None;
+            }
             _ => {}
         }
     }
@@ -413,6 +506,39 @@ where
         self.padding.hash(state);
         self.size.hash(state);
     }
+
+    fn traverse_focus(&mut self, traversal: &mut focus::Traversal) {
+        let was_focused = self.state.is_focused;
+
+        traversal.visit(&mut self.state.is_focused);
+
+        if self.state.is_focused && !was_focused {
+            self.state.move_cursor_to_end(&self.value);
+            self.state.history.break_coalescing();
+        }
+
+        if !self.state.is_focused {
+            self.state.is_pasting = None;
+        }
+    }
+
+    fn inspect(
+        &self,
+        layout: Layout<'_>,
+        depth: usize,
+        nodes: &mut Vec<layout::WidgetNode>,
+    ) {
+        nodes.push(
+            layout::WidgetNode::new::<Self>(
+                depth,
+                layout.bounds(),
+                self.width,
+                Length::Shrink,
+            )
+            .padding(self.padding)
+            .label(self.placeholder.clone()),
+        );
+    }
 }
 
 /// The renderer of a [`TextInput`].
@@ -501,6 +627,7 @@ pub struct State {
     is_focused: bool,
     is_pasting: Option<Value>,
     cursor_position: usize,
+    history: History,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -522,6 +649,7 @@ impl State {
             is_focused: true,
             is_pasting: None,
             cursor_position: usize::MAX,
+            history: History::default(),
         }
     }
 
@@ -802,4 +930,11 @@ mod platform {
             modifiers.control
         }
     }
+
+    pub fn is_redo_modifier_pressed(
+        modifiers: keyboard::ModifiersState,
+    ) -> bool {
+        // Ctrl+Y is only a redo shortcut outside of macOS
+        !cfg!(target_os = "macos") && modifiers.control
+    }
 }
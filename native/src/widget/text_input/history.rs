@@ -0,0 +1,358 @@
+use crate::time;
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// The maximum pause between two consecutive edits for them to be undone
+/// together.
+const COALESCE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The maximum amount of undo steps kept.
+const MAX_STEPS: usize = 100;
+
+/// The undo and redo history of a text field.
+///
+/// It stores snapshots of the contents of the field, taken right before they
+/// are edited.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: VecDeque<Snapshot>,
+    redo: Vec<Snapshot>,
+    last_edit: Option<LastEdit>,
+}
+
+/// The contents of a text field at a point in time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub value: String,
+    pub cursor_position: usize,
+}
+
+/// A kind of edit performed on a text field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    /// A character was typed.
+    Insert { is_whitespace: bool },
+
+    /// A grapheme was removed.
+    Delete,
+
+    /// Some content was pasted.
+    Paste,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LastEdit {
+    edit: Edit,
+    cursor_position: usize,
+    time: Instant,
+}
+
+impl History {
+    /// Returns whether an edit, applied at the given cursor position, starts
+    /// a new undo step.
+    ///
+    /// Consecutive edits of the same kind are coalesced into a single undo
+    /// step, unless the cursor jumps, a new word starts, or the user pauses.
+    /// Therefore, the contents of the field only need to be saved when a new
+    /// step starts.
+    pub fn starts_step(&self, edit: Edit, cursor_position: usize) -> bool {
+        let coalesces = match self.last_edit {
+            Some(last) => {
+                let is_contiguous = last.cursor_position == cursor_position;
+
+                let is_recent = time::now()
+                    .saturating_duration_since(last.time)
+                    < COALESCE_TIMEOUT;
+
+                let is_same_kind = match (last.edit, edit) {
+                    // Typing a word after some whitespace starts a new step
+                    (
+                        Edit::Insert {
+                            is_whitespace: false,
+                        },
+                        Edit::Insert { .. },
+                    ) => true,
+                    (
+                        Edit::Insert {
+                            is_whitespace: true,
+                        },
+                        Edit::Insert { is_whitespace },
+                    ) => is_whitespace,
+                    (Edit::Delete, Edit::Delete) => true,
+                    _ => false,
+                };
+
+                is_contiguous && is_recent && is_same_kind
+            }
+            None => false,
+        };
+
+        !coalesces
+    }
+
+    /// Records an edit, given the position of the cursor after it.
+    ///
+    /// The contents of the field before the edit must be provided when the
+    /// edit starts a new undo step, as reported by [`starts_step`].
+    ///
+    /// [`starts_step`]: #method.starts_step
+    pub fn record(
+        &mut self,
+        edit: Edit,
+        before: Option<Snapshot>,
+        cursor_position: usize,
+    ) {
+        if let Some(before) = before {
+            self.undo.push_back(before);
+
+            if self.undo.len() > MAX_STEPS {
+                let _ = self.undo.pop_front();
+            }
+        }
+
+        self.redo.clear();
+        self.last_edit = Some(LastEdit {
+            edit,
+            cursor_position,
+            time: time::now(),
+        });
+    }
+
+    /// Returns the contents to restore to undo the last step, given the
+    /// current contents of the field.
+    pub fn undo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        let snapshot = self.undo.pop_back()?;
+
+        self.redo.push(current);
+        self.last_edit = None;
+
+        Some(snapshot)
+    }
+
+    /// Returns the contents to restore to redo the last undone step, given
+    /// the current contents of the field.
+    pub fn redo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        let snapshot = self.redo.pop()?;
+
+        self.undo.push_back(current);
+        self.last_edit = None;
+
+        Some(snapshot)
+    }
+
+    /// Stops coalescing the next edit with the previous ones.
+    pub fn break_coalescing(&mut self) {
+        self.last_edit = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(value: &str) -> Snapshot {
+        Snapshot {
+            value: String::from(value),
+            cursor_position: value.len(),
+        }
+    }
+
+    /// Applies an edit at the end of the given value, recording it in the
+    /// history.
+    fn edit(
+        history: &mut History,
+        value: &mut String,
+        edit: Edit,
+        apply: impl FnOnce(&mut String),
+    ) {
+        let before = if history.starts_step(edit, value.len()) {
+            Some(snapshot(value))
+        } else {
+            None
+        };
+
+        apply(value);
+
+        history.record(edit, before, value.len());
+    }
+
+    /// Types the given text at the end of the current value, recording every
+    /// character in the history.
+    fn type_text(history: &mut History, value: &mut String, text: &str) {
+        for c in text.chars() {
+            let kind = Edit::Insert {
+                is_whitespace: c.is_whitespace(),
+            };
+
+            edit(history, value, kind, |value| value.push(c));
+        }
+    }
+
+    fn with_manual_clock(test: impl FnOnce(&time::Clock)) {
+        let clock = time::Clock::manual(Instant::now());
+        let previous = time::set_clock(clock.clone());
+
+        test(&clock);
+
+        let _ = time::set_clock(previous);
+    }
+
+    #[test]
+    fn coalesces_words() {
+        with_manual_clock(|_| {
+            let mut history = History::default();
+            let mut value = String::new();
+
+            type_text(&mut history, &mut value, "hello world");
+
+            // Trailing whitespace belongs to the word before it
+            assert_eq!(
+                history.undo(snapshot(&value)),
+                Some(snapshot("hello "))
+            );
+            assert_eq!(history.undo(snapshot("hello ")), Some(snapshot("")));
+            assert_eq!(history.undo(snapshot("")), None);
+        });
+    }
+
+    #[test]
+    fn breaks_after_timeout() {
+        with_manual_clock(|clock| {
+            let mut history = History::default();
+            let mut value = String::new();
+
+            type_text(&mut history, &mut value, "ab");
+            clock.advance(COALESCE_TIMEOUT);
+            type_text(&mut history, &mut value, "cd");
+
+            assert_eq!(history.undo(snapshot(&value)), Some(snapshot("ab")));
+            assert_eq!(history.undo(snapshot("ab")), Some(snapshot("")));
+        });
+    }
+
+    #[test]
+    fn coalesces_within_timeout() {
+        with_manual_clock(|clock| {
+            let mut history = History::default();
+            let mut value = String::new();
+
+            type_text(&mut history, &mut value, "ab");
+            clock.advance(COALESCE_TIMEOUT - Duration::from_millis(1));
+            type_text(&mut history, &mut value, "cd");
+
+            assert_eq!(history.undo(snapshot(&value)), Some(snapshot("")));
+        });
+    }
+
+    #[test]
+    fn breaks_when_cursor_jumps() {
+        with_manual_clock(|_| {
+            let mut history = History::default();
+            let insert = Edit::Insert {
+                is_whitespace: false,
+            };
+
+            assert!(history.starts_step(insert, 0));
+            history.record(insert, Some(snapshot("")), 1);
+            assert!(!history.starts_step(insert, 1));
+
+            // The cursor was moved back to the start before typing again
+            assert!(history.starts_step(insert, 0));
+            history.record(
+                insert,
+                Some(Snapshot {
+                    value: String::from("a"),
+                    cursor_position: 0,
+                }),
+                1,
+            );
+
+            assert_eq!(
+                history.undo(snapshot("ba")),
+                Some(Snapshot {
+                    value: String::from("a"),
+                    cursor_position: 0,
+                })
+            );
+            assert_eq!(history.undo(snapshot("a")), Some(snapshot("")));
+        });
+    }
+
+    #[test]
+    fn breaks_between_kinds() {
+        with_manual_clock(|_| {
+            let mut history = History::default();
+            let mut value = String::new();
+
+            type_text(&mut history, &mut value, "abc");
+
+            for _ in 0..2 {
+                edit(&mut history, &mut value, Edit::Delete, |value| {
+                    let _ = value.pop();
+                });
+            }
+
+            assert_eq!(history.undo(snapshot("a")), Some(snapshot("abc")));
+            assert_eq!(history.undo(snapshot("abc")), Some(snapshot("")));
+        });
+    }
+
+    #[test]
+    fn evicts_oldest_steps() {
+        with_manual_clock(|_| {
+            let mut history = History::default();
+
+            for i in 0..MAX_STEPS + 10 {
+                history.break_coalescing();
+                history.record(Edit::Paste, Some(snapshot(&i.to_string())), 0);
+            }
+
+            let steps: Vec<Snapshot> =
+                std::iter::from_fn(|| history.undo(snapshot(""))).collect();
+
+            assert_eq!(steps.len(), MAX_STEPS);
+            assert_eq!(steps.first(), Some(&snapshot("109")));
+            assert_eq!(steps.last(), Some(&snapshot("10")));
+        });
+    }
+
+    #[test]
+    fn redo_is_cleared_by_new_edits() {
+        with_manual_clock(|_| {
+            let mut history = History::default();
+            let mut value = String::new();
+
+            type_text(&mut history, &mut value, "a");
+
+            assert_eq!(history.undo(snapshot("a")), Some(snapshot("")));
+
+            type_text(&mut history, &mut String::new(), "b");
+
+            assert_eq!(history.redo(snapshot("b")), None);
+        });
+    }
+
+    #[test]
+    fn snapshots_only_new_steps() {
+        with_manual_clock(|clock| {
+            let mut history = History::default();
+            let mut value = String::new();
+            let insert = Edit::Insert {
+                is_whitespace: false,
+            };
+
+            assert!(history.starts_step(insert, 0));
+
+            type_text(&mut history, &mut value, "ab");
+            assert!(!history.starts_step(insert, value.len()));
+            assert!(history.starts_step(Edit::Delete, value.len()));
+
+            clock.advance(COALESCE_TIMEOUT);
+            assert!(history.starts_step(insert, value.len()));
+
+            history.break_coalescing();
+            assert!(history.starts_step(insert, value.len()));
+        });
+    }
+}
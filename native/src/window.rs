@@ -1,6 +1,13 @@
 //! Build window-based GUI applications.
 mod backend;
 mod event;
+mod monitor;
+mod overlay;
+mod statistics;
 
 pub use backend::Backend;
 pub use event::Event;
+pub use monitor::Monitor;
+pub use overlay::Overlay;
+pub use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
+pub use statistics::Statistics;
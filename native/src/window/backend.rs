@@ -1,4 +1,7 @@
-use crate::MouseCursor;
+use crate::{
+    window::{Overlay, Statistics},
+    Element, MouseCursor,
+};
 
 use raw_window_handle::HasRawWindowHandle;
 
@@ -42,14 +45,43 @@ pub trait Backend: Sized {
 
     /// Draws the output primitives to the next frame of the given [`SwapChain`].
     ///
+    /// The provided [`Overlay`] is drawn on top of the primitives.
+    ///
     /// [`SwapChain`]: #associatedtype.SwapChain
     /// [`Surface`]: #associatedtype.Surface
-    fn draw<T: AsRef<str>>(
+    /// [`Overlay`]: struct.Overlay.html
+    fn draw(
         &mut self,
         renderer: &mut Self::Renderer,
         swap_chain: &mut Self::SwapChain,
         output: &<Self::Renderer as crate::Renderer>::Output,
         scale_factor: f64,
-        overlay: &[T],
+        overlay: &Overlay,
     ) -> MouseCursor;
+
+    /// Returns the [`Statistics`] of the last frame drawn with the given
+    /// renderer.
+    ///
+    /// By default, it returns empty [`Statistics`].
+    ///
+    /// [`Statistics`]: struct.Statistics.html
+    fn statistics(&self, _renderer: &Self::Renderer) -> Statistics {
+        Statistics::default()
+    }
+
+    /// Marks the given [`Element`] as _to-be-inspected_, so the widget
+    /// inspector can be toggled at runtime.
+    ///
+    /// By default, it returns the [`Element`] unchanged. Backends whose
+    /// renderer implements [`layout::Debugger`] can call
+    /// [`Element::inspect`] here.
+    ///
+    /// [`Element`]: ../struct.Element.html
+    /// [`Element::inspect`]: ../struct.Element.html#method.inspect
+    /// [`layout::Debugger`]: ../layout/trait.Debugger.html
+    fn inspect<'a, Message: 'a>(
+        element: Element<'a, Message, Self::Renderer>,
+    ) -> Element<'a, Message, Self::Renderer> {
+        element
+    }
 }
@@ -1,7 +1,10 @@
+use crate::window::Monitor;
+
 use std::path::PathBuf;
 
 /// A window-related event.
 #[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A window was resized
     Resized {
@@ -12,6 +15,36 @@ pub enum Event {
         height: u32,
     },
 
+    /// The window gained focus.
+    Focused,
+
+    /// The window lost focus.
+    Unfocused,
+
+    /// The window was minimized.
+    ///
+    /// Some platforms do not report when the user minimizes a window. In
+    /// those cases, this event will only be produced when the application
+    /// requests it.
+    Minimized,
+
+    /// The window was maximized.
+    ///
+    /// Maximizing a window is not reported by every platform. In those cases,
+    /// this event will only be produced when the application requests it.
+    Maximized,
+
+    /// The window was restored from a minimized or maximized state.
+    Restored,
+
+    /// The monitors available in the system were detected.
+    ///
+    /// The position of each [`Monitor`] in the list is its index, which can
+    /// be used to choose the monitor targeted by the window.
+    ///
+    /// [`Monitor`]: struct.Monitor.html
+    MonitorsDetected(Vec<Monitor>),
+
     /// A file is being hovered over the window.
     ///
     /// When the user hovers multiple files at once, this event will be emitted
@@ -0,0 +1,20 @@
+/// A monitor connected to the system.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Monitor {
+    /// The human-readable name of the monitor, if available.
+    pub name: Option<String>,
+
+    /// The resolution of the monitor (in physical pixels).
+    pub size: (u32, u32),
+
+    /// The position of the top-left corner of the monitor in the desktop
+    /// (in physical pixels).
+    pub position: (i32, i32),
+
+    /// The scale factor of the monitor.
+    pub scale_factor: f64,
+
+    /// The refresh rate of the monitor (in hertz), if available.
+    pub refresh_rate: Option<u16>,
+}
@@ -0,0 +1,14 @@
+/// Debug information drawn on top of a frame.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Overlay {
+    /// The lines of text of the [`Overlay`], drawn from the top-left corner.
+    ///
+    /// [`Overlay`]: struct.Overlay.html
+    pub lines: Vec<String>,
+
+    /// The values of a rolling bar graph, drawn below the lines of text.
+    ///
+    /// Values are ordered from oldest to newest and are scaled relative to
+    /// the greatest one.
+    pub graph: Vec<f32>,
+}
@@ -0,0 +1,14 @@
+/// The rendering statistics of the last frame drawn by a window [`Backend`].
+///
+/// [`Backend`]: trait.Backend.html
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Statistics {
+    /// The amount of primitives drawn, grouped by type.
+    pub primitives: Vec<(&'static str, usize)>,
+
+    /// The amount of draw calls issued.
+    pub draw_calls: usize,
+
+    /// The size of the GPU buffers in use, in bytes, grouped by purpose.
+    pub buffers: Vec<(&'static str, u64)>,
+}
@@ -161,6 +161,201 @@ pub trait Application: Sized {
         window::Mode::Windowed
     }
 
+    /// Returns the current [`Redraw`] strategy of the [`Application`].
+    ///
+    /// The runtime will adapt its event loop when a new strategy is returned.
+    /// For instance, you can redraw continuously while a game is running and
+    /// go back to idling when it is paused.
+    ///
+    /// Currently, the redraw strategy only has an effect in native platforms.
+    ///
+    /// By default, an application redraws on demand.
+    ///
+    /// [`Redraw`]: window/enum.Redraw.html
+    /// [`Application`]: trait.Application.html
+    fn redraw(&self) -> window::Redraw {
+        window::Redraw::OnDemand
+    }
+
+    /// Returns whether the cursor should be grabbed by the window of the
+    /// [`Application`].
+    ///
+    /// A grabbed cursor is confined to the window and cannot leave it. Combine
+    /// it with [`cursor_visible`] and the `Motion` mouse events to implement
+    /// mouse-look in games and 3D viewports.
+    ///
+    /// Currently, the cursor grab only has an effect in native platforms.
+    ///
+    /// By default, the cursor is not grabbed.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`cursor_visible`]: #method.cursor_visible
+    fn cursor_grabbed(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the cursor should be visible when it is over the window
+    /// of the [`Application`].
+    ///
+    /// Currently, hiding the cursor only has an effect in native platforms.
+    ///
+    /// By default, the cursor is visible.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn cursor_visible(&self) -> bool {
+        true
+    }
+
+    /// Returns whether the window of the [`Application`] should be visible.
+    ///
+    /// The runtime checks this method right after the [`Application`] is
+    /// created and after every update, and shows or hides the window when the
+    /// returned value differs from the current visibility of the window. This
+    /// can be combined with a hidden window in the [`Settings`] to show the
+    /// window only once the application has finished loading.
+    ///
+    /// Currently, this method only has an effect in native platforms.
+    ///
+    /// By default, it returns `None` and the window keeps the visibility
+    /// given in the [`Settings`].
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`Settings`]: window/struct.Settings.html
+    fn visible(&self) -> Option<bool> {
+        None
+    }
+
+    /// Returns whether the window of the [`Application`] should stay on top of
+    /// other windows.
+    ///
+    /// The runtime will automatically pin or unpin the window when the
+    /// returned value changes.
+    ///
+    /// Currently, this method only has an effect in native platforms.
+    ///
+    /// By default, the window is not kept on top.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn always_on_top(&self) -> bool {
+        false
+    }
+
+    /// Returns the index of the monitor that the window of the [`Application`]
+    /// should target.
+    ///
+    /// The runtime will center the window on the chosen monitor, which will
+    /// also be used when the application enters fullscreen mode. The available
+    /// monitors are notified as a window event when the application starts.
+    ///
+    /// Currently, this method only has an effect in native platforms.
+    ///
+    /// By default, it returns `None` and the window will use the monitor
+    /// chosen by the system.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn monitor(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns whether the window of the [`Application`] should be minimized.
+    ///
+    /// The runtime will automatically minimize or restore the window when the
+    /// returned value changes. The user may still restore the window at any
+    /// time, so you may want to listen to window events to keep your state in
+    /// sync.
+    ///
+    /// Currently, this method only has an effect in native platforms.
+    ///
+    /// By default, the window is not minimized.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn minimized(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the window of the [`Application`] should be maximized.
+    ///
+    /// The runtime will automatically maximize or restore the window when the
+    /// returned value changes.
+    ///
+    /// Currently, this method only has an effect in native platforms.
+    ///
+    /// By default, the window is not maximized.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn maximized(&self) -> bool {
+        false
+    }
+
+    /// Returns the progress of the current long-running operation of the
+    /// [`Application`], if any.
+    ///
+    /// The progress will be shown in the taskbar icon of the application.
+    /// The runtime will automatically update it when the returned value
+    /// changes.
+    ///
+    /// Currently, the progress is only shown on Windows.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn taskbar_progress(&self) -> Option<window::TaskbarProgress> {
+        None
+    }
+
+    /// Returns whether the display should be prevented from going to sleep
+    /// while the [`Application`] is running.
+    ///
+    /// This is useful while playing a video or running a long job. The
+    /// runtime will automatically inhibit or allow sleep when the returned
+    /// value changes.
+    ///
+    /// Currently, this method only has an effect in native platforms.
+    ///
+    /// By default, sleep is not inhibited.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn inhibit_sleep(&self) -> bool {
+        false
+    }
+
+    /// Returns the exit code of the [`Application`], if it should be
+    /// terminated.
+    ///
+    /// Once an exit code is returned, the runtime will close every active
+    /// subscription, close the window, and terminate the process with the
+    /// given code.
+    ///
+    /// The event loop of `winit` 0.21 never returns, so the process is
+    /// terminated with [`std::process::exit`] right after the window closes.
+    /// Destructors of the [`Application`] and of any value owned by the event
+    /// loop do __not__ run. Persist anything important in `update` before
+    /// returning an exit code.
+    ///
+    /// Currently, this method only has an effect in native platforms.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`std::process::exit`]: https://doc.rust-lang.org/std/process/fn.exit.html
+    fn exit_code(&self) -> Option<i32> {
+        None
+    }
+
+    /// Handles the creation of the window of the [`Application`].
+    ///
+    /// The given [`RawWindowHandle`] targets the same native surface that
+    /// iced draws to. You can keep it around to let external renderers, video
+    /// decoders, or native dialogs interact with the window.
+    ///
+    /// Currently, this method is only called in native platforms.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`RawWindowHandle`]: window/enum.RawWindowHandle.html
+    fn window_created(&mut self, _handle: window::RawWindowHandle) {}
+
     /// Runs the [`Application`].
     ///
     /// This method will take control of the current thread and __will NOT
@@ -220,6 +415,70 @@ where
         }
     }
 
+    fn redraw(&self) -> iced_winit::Redraw {
+        match self.0.redraw() {
+            window::Redraw::OnDemand => iced_winit::Redraw::OnDemand,
+            window::Redraw::Continuous => iced_winit::Redraw::Continuous,
+        }
+    }
+
+    fn cursor_grabbed(&self) -> bool {
+        self.0.cursor_grabbed()
+    }
+
+    fn cursor_visible(&self) -> bool {
+        self.0.cursor_visible()
+    }
+
+    fn visible(&self) -> Option<bool> {
+        self.0.visible()
+    }
+
+    fn always_on_top(&self) -> bool {
+        self.0.always_on_top()
+    }
+
+    fn monitor(&self) -> Option<usize> {
+        self.0.monitor()
+    }
+
+    fn minimized(&self) -> bool {
+        self.0.minimized()
+    }
+
+    fn maximized(&self) -> bool {
+        self.0.maximized()
+    }
+
+    fn taskbar_progress(&self) -> Option<iced_winit::TaskbarProgress> {
+        self.0.taskbar_progress().map(|progress| match progress {
+            window::TaskbarProgress::Indeterminate => {
+                iced_winit::TaskbarProgress::Indeterminate
+            }
+            window::TaskbarProgress::Normal(value) => {
+                iced_winit::TaskbarProgress::Normal(value)
+            }
+            window::TaskbarProgress::Paused(value) => {
+                iced_winit::TaskbarProgress::Paused(value)
+            }
+            window::TaskbarProgress::Error(value) => {
+                iced_winit::TaskbarProgress::Error(value)
+            }
+        })
+    }
+
+    fn inhibit_sleep(&self) -> bool {
+        self.0.inhibit_sleep()
+    }
+
+    fn exit_code(&self) -> Option<i32> {
+        self.0.exit_code()
+    }
+
+    fn window_created(&mut self, handle: window::RawWindowHandle) {
+        self.0.window_created(handle)
+    }
+
     fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
         self.0.update(message)
     }
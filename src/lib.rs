@@ -196,6 +196,15 @@ pub use executor::Executor;
 pub use sandbox::Sandbox;
 pub use settings::Settings;
 
+#[cfg(feature = "theme")]
+pub use iced_style::theme;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use iced_winit::time;
+
+#[cfg(target_arch = "wasm32")]
+pub use iced_web::time;
+
 #[cfg(not(target_arch = "wasm32"))]
 use iced_winit as common;
 
@@ -35,7 +35,8 @@ impl From<Settings> for iced_winit::Settings {
                 size: settings.window.size,
                 resizable: settings.window.resizable,
                 decorations: settings.window.decorations,
-                platform_specific: Default::default(),
+                visible: settings.window.visible,
+                platform_specific: settings.window.platform_specific,
             },
         }
     }
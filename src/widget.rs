@@ -35,8 +35,9 @@ mod platform {
     #[doc(no_inline)]
     pub use {
         button::Button, checkbox::Checkbox, container::Container, image::Image,
-        progress_bar::ProgressBar, radio::Radio, scrollable::Scrollable,
-        slider::Slider, svg::Svg, text_input::TextInput,
+        progress_bar::ProgressBar, radio::Radio, radio_group::RadioGroup,
+        scrollable::Scrollable, slider::Slider, svg::Svg,
+        text_input::TextInput,
     };
 
     /// A container that distributes its contents vertically.
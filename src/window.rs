@@ -1,6 +1,11 @@
 //! Configure the window of your application in native platforms.
 mod mode;
+mod redraw;
 mod settings;
+mod taskbar_progress;
 
 pub use mode::Mode;
+pub use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
+pub use redraw::Redraw;
 pub use settings::Settings;
+pub use taskbar_progress::TaskbarProgress;
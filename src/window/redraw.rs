@@ -0,0 +1,13 @@
+/// The redraw strategy of a window-based application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Redraw {
+    /// The application idles until something happens: new events, messages,
+    /// or a redraw requested by a widget.
+    ///
+    /// This is the most power-efficient strategy.
+    OnDemand,
+
+    /// The application processes events and redraws continuously, as fast as
+    /// possible.
+    Continuous,
+}
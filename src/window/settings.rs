@@ -9,6 +9,19 @@ pub struct Settings {
 
     /// Whether the window should have a border, a title bar, etc. or not.
     pub decorations: bool,
+
+    /// Whether the window should be visible when created.
+    ///
+    /// A hidden window will be shown as soon as [`Application::visible`]
+    /// returns `Some(true)`. This allows an application to finish
+    /// loading before showing its window.
+    ///
+    /// [`Application::visible`]: ../trait.Application.html#method.visible
+    pub visible: bool,
+
+    /// Platform specific settings.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub platform_specific: iced_winit::settings::PlatformSpecific,
 }
 
 impl Default for Settings {
@@ -17,6 +30,9 @@ impl Default for Settings {
             size: (1024, 768),
             resizable: true,
             decorations: true,
+            visible: true,
+            #[cfg(not(target_arch = "wasm32"))]
+            platform_specific: Default::default(),
         }
     }
 }
@@ -2,7 +2,8 @@
 use iced_core::{Background, Color, Vector};
 
 /// The appearance of a button.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "theme", derive(serde::Deserialize))]
 pub struct Style {
     pub shadow_offset: Vector,
     pub background: Option<Background>,
@@ -45,6 +46,10 @@ pub trait StyleSheet {
         }
     }
 
+    fn focused(&self) -> Style {
+        highlight(self.hovered())
+    }
+
     fn disabled(&self) -> Style {
         let active = self.active();
 
@@ -65,6 +70,15 @@ pub trait StyleSheet {
     }
 }
 
+/// Highlights the border of the given style to show focus.
+pub(crate) fn highlight(style: Style) -> Style {
+    Style {
+        border_color: crate::FOCUS_COLOR,
+        border_width: style.border_width.max(2),
+        ..style
+    }
+}
+
 struct Default;
 
 impl StyleSheet for Default {
@@ -2,7 +2,8 @@
 use iced_core::{Background, Color};
 
 /// The appearance of a checkbox.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "theme", derive(serde::Deserialize))]
 pub struct Style {
     pub background: Background,
     pub checkmark_color: Color,
@@ -16,6 +17,22 @@ pub trait StyleSheet {
     fn active(&self, is_checked: bool) -> Style;
 
     fn hovered(&self, is_checked: bool) -> Style;
+
+    /// Produces the style of a focused checkbox.
+    ///
+    /// By default, it highlights the border of the hovered style.
+    fn focused(&self, is_checked: bool) -> Style {
+        highlight(self.hovered(is_checked))
+    }
+}
+
+/// Highlights the border of the given style to show focus.
+pub(crate) fn highlight(style: Style) -> Style {
+    Style {
+        border_color: crate::FOCUS_COLOR,
+        border_width: style.border_width.max(2),
+        ..style
+    }
 }
 
 struct Default;
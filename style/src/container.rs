@@ -3,6 +3,7 @@ use iced_core::{Background, Color};
 
 /// The appearance of a container.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "theme", derive(serde::Deserialize))]
 pub struct Style {
     pub text_color: Option<Color>,
     pub background: Option<Background>,
@@ -10,3 +10,10 @@ pub mod radio;
 pub mod scrollable;
 pub mod slider;
 pub mod text_input;
+
+#[cfg(feature = "theme")]
+pub mod theme;
+
+/// The color used by the default styles to highlight focused widgets.
+pub(crate) const FOCUS_COLOR: iced_core::Color =
+    iced_core::Color::from_rgb(0.5, 0.6, 0.9);
@@ -2,7 +2,8 @@
 use iced_core::{Background, Color};
 
 /// The appearance of a progress bar.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "theme", derive(serde::Deserialize))]
 pub struct Style {
     pub background: Background,
     pub bar: Background,
@@ -2,7 +2,8 @@
 use iced_core::{Background, Color};
 
 /// The appearance of a radio button.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "theme", derive(serde::Deserialize))]
 pub struct Style {
     pub background: Background,
     pub dot_color: Color,
@@ -15,6 +16,22 @@ pub trait StyleSheet {
     fn active(&self) -> Style;
 
     fn hovered(&self) -> Style;
+
+    /// Produces the style of a focused radio button.
+    ///
+    /// By default, it highlights the border of the hovered style.
+    fn focused(&self) -> Style {
+        highlight(self.hovered())
+    }
+}
+
+/// Highlights the border of the given style to show focus.
+pub(crate) fn highlight(style: Style) -> Style {
+    Style {
+        border_color: crate::FOCUS_COLOR,
+        border_width: style.border_width.max(2),
+        ..style
+    }
 }
 
 struct Default;
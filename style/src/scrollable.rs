@@ -1,9 +1,40 @@
 //! Navigate an endless amount of content with a scrollbar.
 use iced_core::{Background, Color};
 
+/// The default width of the scroller of a scrollable, in pixels.
+pub const DEFAULT_WIDTH: u16 = 10;
+
+/// The default space around the scroller of a scrollable, in pixels.
+pub const DEFAULT_MARGIN: u16 = 2;
+
 /// The appearance of a scrollable.
+///
+/// Custom styles can fill in the fields they do not care about with
+/// `Scrollbar::default`, which produces the active style of the default
+/// [`StyleSheet`]:
+///
+/// ```
+/// use iced_style::scrollable::Scrollbar;
+/// use iced_core::Background;
+///
+/// let scrollbar = Scrollbar {
+///     background: Some(Background::Color([0.9, 0.9, 0.9].into())),
+///     ..Scrollbar::default()
+/// };
+/// ```
+///
+/// [`StyleSheet`]: trait.StyleSheet.html
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "theme", derive(serde::Deserialize))]
 pub struct Scrollbar {
+    /// The width of the scroller, in pixels.
+    #[cfg_attr(feature = "theme", serde(default = "default_width"))]
+    pub width: u16,
+
+    /// The space around the scroller, in pixels.
+    #[cfg_attr(feature = "theme", serde(default = "default_margin"))]
+    pub margin: u16,
+
     pub background: Option<Background>,
     pub border_radius: u16,
     pub border_width: u16,
@@ -13,6 +44,7 @@ pub struct Scrollbar {
 
 /// The appearance of the scroller of a scrollable.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "theme", derive(serde::Deserialize))]
 pub struct Scroller {
     pub color: Color,
     pub border_radius: u16,
@@ -20,7 +52,22 @@ pub struct Scroller {
     pub border_color: Color,
 }
 
+#[cfg(feature = "theme")]
+fn default_width() -> u16 {
+    DEFAULT_WIDTH
+}
+
+#[cfg(feature = "theme")]
+fn default_margin() -> u16 {
+    DEFAULT_MARGIN
+}
+
 /// A set of rules that dictate the style of a scrollable.
+///
+/// The width and margin of the scrollbar are always taken from the
+/// [`active`] style, so the scrollbar does not move when it changes state.
+///
+/// [`active`]: #tymethod.active
 pub trait StyleSheet {
     /// Produces the style of an active scrollbar.
     fn active(&self) -> Scrollbar;
@@ -34,23 +81,37 @@ pub trait StyleSheet {
     }
 }
 
-struct Default;
-
-impl StyleSheet for Default {
-    fn active(&self) -> Scrollbar {
+impl std::default::Default for Scrollbar {
+    fn default() -> Self {
         Scrollbar {
+            width: DEFAULT_WIDTH,
+            margin: DEFAULT_MARGIN,
             background: None,
             border_radius: 5,
             border_width: 0,
             border_color: Color::TRANSPARENT,
-            scroller: Scroller {
-                color: [0.0, 0.0, 0.0, 0.7].into(),
-                border_radius: 5,
-                border_width: 0,
-                border_color: Color::TRANSPARENT,
-            },
+            scroller: Scroller::default(),
+        }
+    }
+}
+
+impl std::default::Default for Scroller {
+    fn default() -> Self {
+        Scroller {
+            color: [0.0, 0.0, 0.0, 0.7].into(),
+            border_radius: 5,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
         }
     }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Scrollbar {
+        Scrollbar::default()
+    }
 
     fn hovered(&self) -> Scrollbar {
         Scrollbar {
@@ -3,6 +3,7 @@ use iced_core::Color;
 
 /// The appearance of a slider.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "theme", derive(serde::Deserialize))]
 pub struct Style {
     pub rail_colors: (Color, Color),
     pub handle: Handle,
@@ -10,6 +11,7 @@ pub struct Style {
 
 /// The appearance of the handle of a slider.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "theme", derive(serde::Deserialize))]
 pub struct Handle {
     pub shape: HandleShape,
     pub color: Color,
@@ -19,6 +21,7 @@ pub struct Handle {
 
 /// The shape of the handle of a slider.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "theme", derive(serde::Deserialize))]
 pub enum HandleShape {
     Circle { radius: u16 },
     Rectangle { width: u16, border_radius: u16 },
@@ -34,6 +37,27 @@ pub trait StyleSheet {
 
     /// Produces the style of a slider that is being dragged.
     fn dragging(&self) -> Style;
+
+    /// Produces the style of a slider that is focused and can be controlled
+    /// with the keyboard.
+    ///
+    /// By default, it highlights the border of the handle of the hovered
+    /// style.
+    fn focused(&self) -> Style {
+        highlight(self.hovered())
+    }
+}
+
+/// Highlights the border of the handle of the given style to show focus.
+pub(crate) fn highlight(style: Style) -> Style {
+    Style {
+        handle: Handle {
+            border_color: crate::FOCUS_COLOR,
+            border_width: style.handle.border_width.max(2),
+            ..style.handle
+        },
+        ..style
+    }
 }
 
 struct Default;
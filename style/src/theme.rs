@@ -0,0 +1,459 @@
+//! Load the styles of your application from a file.
+//!
+//! A [`Theme`] describes the styles of the built-in widgets. It can be
+//! written in [TOML] or [RON], and loaded at runtime. Reloading a [`Theme`]
+//! whenever its file changes allows you to tweak the looks of your
+//! application without recompiling it.
+//!
+//! Every section of a [`Theme`] is optional. Widgets without a section use
+//! their default style. Within a section, only the `active` style is
+//! mandatory; the styles of other states are derived from it when missing.
+//!
+//! ```toml
+//! [button.active]
+//! shadow_offset = { x = 0.0, y = 1.0 }
+//! background = { Color = { r = 0.2, g = 0.5, b = 0.9, a = 1.0 } }
+//! border_radius = 4
+//! border_width = 0
+//! border_color = { r = 0.0, g = 0.0, b = 0.0, a = 0.0 }
+//! text_color = { r = 1.0, g = 1.0, b = 1.0, a = 1.0 }
+//! ```
+//!
+//! [`Theme`]: struct.Theme.html
+//! [TOML]: https://github.com/toml-lang/toml
+//! [RON]: https://github.com/ron-rs/ron
+use crate::{
+    button, checkbox, container, progress_bar, radio, scrollable, slider,
+    text_input,
+};
+use iced_core::Color;
+
+use serde::Deserialize;
+use std::{fmt, io, path::Path};
+
+/// The styles of the built-in widgets.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// The style of buttons.
+    pub button: Option<Button>,
+
+    /// The style of checkboxes.
+    pub checkbox: Option<Checkbox>,
+
+    /// The style of containers.
+    pub container: Option<container::Style>,
+
+    /// The style of progress bars.
+    pub progress_bar: Option<progress_bar::Style>,
+
+    /// The style of radio buttons.
+    pub radio: Option<Radio>,
+
+    /// The style of scrollables.
+    pub scrollable: Option<Scrollable>,
+
+    /// The style of sliders.
+    pub slider: Option<Slider>,
+
+    /// The style of text inputs.
+    pub text_input: Option<TextInput>,
+}
+
+impl Theme {
+    /// Loads a [`Theme`] from the file at the given path.
+    ///
+    /// The format of the file is chosen based on its extension, which must be
+    /// either `toml` or `ron`.
+    ///
+    /// [`Theme`]: struct.Theme.html
+    pub fn load(path: impl AsRef<Path>) -> Result<Theme, Error> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(Error::Io)?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::from_toml(&contents),
+            Some("ron") => Self::from_ron(&contents),
+            _ => Err(Error::UnknownFormat),
+        }
+    }
+
+    /// Parses a [`Theme`] written in TOML.
+    ///
+    /// [`Theme`]: struct.Theme.html
+    pub fn from_toml(contents: &str) -> Result<Theme, Error> {
+        toml::from_str(contents).map_err(Error::Toml)
+    }
+
+    /// Parses a [`Theme`] written in RON.
+    ///
+    /// [`Theme`]: struct.Theme.html
+    pub fn from_ron(contents: &str) -> Result<Theme, Error> {
+        ron::de::from_str(contents).map_err(Error::Ron)
+    }
+
+    /// Returns the style sheet of buttons.
+    pub fn button(&self) -> Box<dyn button::StyleSheet> {
+        match self.button {
+            Some(sheet) => Box::new(sheet),
+            None => Box::default(),
+        }
+    }
+
+    /// Returns the style sheet of checkboxes.
+    pub fn checkbox(&self) -> Box<dyn checkbox::StyleSheet> {
+        match self.checkbox {
+            Some(sheet) => Box::new(sheet),
+            None => Box::default(),
+        }
+    }
+
+    /// Returns the style sheet of containers.
+    pub fn container(&self) -> Box<dyn container::StyleSheet> {
+        match self.container {
+            Some(style) => Box::new(Container(style)),
+            None => Box::default(),
+        }
+    }
+
+    /// Returns the style sheet of progress bars.
+    pub fn progress_bar(&self) -> Box<dyn progress_bar::StyleSheet> {
+        match self.progress_bar {
+            Some(style) => Box::new(ProgressBar(style)),
+            None => Box::default(),
+        }
+    }
+
+    /// Returns the style sheet of radio buttons.
+    pub fn radio(&self) -> Box<dyn radio::StyleSheet> {
+        match self.radio {
+            Some(sheet) => Box::new(sheet),
+            None => Box::default(),
+        }
+    }
+
+    /// Returns the style sheet of scrollables.
+    pub fn scrollable(&self) -> Box<dyn scrollable::StyleSheet> {
+        match self.scrollable {
+            Some(sheet) => Box::new(sheet),
+            None => Box::default(),
+        }
+    }
+
+    /// Returns the style sheet of sliders.
+    pub fn slider(&self) -> Box<dyn slider::StyleSheet> {
+        match self.slider {
+            Some(sheet) => Box::new(sheet),
+            None => Box::default(),
+        }
+    }
+
+    /// Returns the style sheet of text inputs.
+    pub fn text_input(&self) -> Box<dyn text_input::StyleSheet> {
+        match self.text_input {
+            Some(sheet) => Box::new(sheet),
+            None => Box::default(),
+        }
+    }
+}
+
+/// An error that occurred while loading a [`Theme`].
+///
+/// [`Theme`]: struct.Theme.html
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be read.
+    Io(io::Error),
+
+    /// The TOML contents are invalid.
+    Toml(toml::de::Error),
+
+    /// The RON contents are invalid.
+    Ron(ron::de::Error),
+
+    /// The extension of the file is not `toml` nor `ron`.
+    UnknownFormat,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "could not read theme: {}", error),
+            Error::Toml(error) => write!(f, "invalid TOML theme: {}", error),
+            Error::Ron(error) => write!(f, "invalid RON theme: {}", error),
+            Error::UnknownFormat => {
+                write!(f, "unknown theme format, expected TOML or RON")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The styles of a button in every state.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Button {
+    /// The style of an active button.
+    pub active: button::Style,
+
+    /// The style of a hovered button.
+    pub hovered: Option<button::Style>,
+
+    /// The style of a pressed button.
+    pub pressed: Option<button::Style>,
+
+    /// The style of a disabled button.
+    pub disabled: Option<button::Style>,
+
+    /// The style of a focused button.
+    pub focused: Option<button::Style>,
+}
+
+impl button::StyleSheet for Button {
+    fn active(&self) -> button::Style {
+        self.active
+    }
+
+    fn hovered(&self) -> button::Style {
+        self.hovered.unwrap_or_else(|| {
+            button::StyleSheet::hovered(&Derived(self.active))
+        })
+    }
+
+    fn pressed(&self) -> button::Style {
+        self.pressed.unwrap_or_else(|| {
+            button::StyleSheet::pressed(&Derived(self.active))
+        })
+    }
+
+    fn disabled(&self) -> button::Style {
+        self.disabled.unwrap_or_else(|| {
+            button::StyleSheet::disabled(&Derived(self.active))
+        })
+    }
+
+    fn focused(&self) -> button::Style {
+        self.focused.unwrap_or_else(|| {
+            button::highlight(button::StyleSheet::hovered(self))
+        })
+    }
+}
+
+/// The styles of a checkbox in every state.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Checkbox {
+    /// The style of an active checkbox.
+    pub active: checkbox::Style,
+
+    /// The style of a hovered checkbox.
+    pub hovered: Option<checkbox::Style>,
+
+    /// The style of an active checkbox that is checked.
+    pub active_checked: Option<checkbox::Style>,
+
+    /// The style of a hovered checkbox that is checked.
+    pub hovered_checked: Option<checkbox::Style>,
+
+    /// The style of a focused checkbox.
+    pub focused: Option<checkbox::Style>,
+
+    /// The style of a focused checkbox that is checked.
+    pub focused_checked: Option<checkbox::Style>,
+}
+
+impl checkbox::StyleSheet for Checkbox {
+    fn active(&self, is_checked: bool) -> checkbox::Style {
+        if is_checked {
+            self.active_checked.unwrap_or(self.active)
+        } else {
+            self.active
+        }
+    }
+
+    fn hovered(&self, is_checked: bool) -> checkbox::Style {
+        let hovered = self.hovered.unwrap_or(self.active);
+
+        if is_checked {
+            self.hovered_checked
+                .or(self.active_checked)
+                .unwrap_or(hovered)
+        } else {
+            hovered
+        }
+    }
+
+    fn focused(&self, is_checked: bool) -> checkbox::Style {
+        let focused = if is_checked {
+            self.focused_checked
+        } else {
+            self.focused
+        };
+
+        focused.unwrap_or_else(|| {
+            checkbox::highlight(checkbox::StyleSheet::hovered(self, is_checked))
+        })
+    }
+}
+
+/// The styles of a radio button in every state.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Radio {
+    /// The style of an active radio button.
+    pub active: radio::Style,
+
+    /// The style of a hovered radio button.
+    pub hovered: Option<radio::Style>,
+
+    /// The style of a focused radio button.
+    pub focused: Option<radio::Style>,
+}
+
+impl radio::StyleSheet for Radio {
+    fn active(&self) -> radio::Style {
+        self.active
+    }
+
+    fn hovered(&self) -> radio::Style {
+        self.hovered.unwrap_or(self.active)
+    }
+
+    fn focused(&self) -> radio::Style {
+        self.focused.unwrap_or_else(|| {
+            radio::highlight(radio::StyleSheet::hovered(self))
+        })
+    }
+}
+
+/// The styles of a scrollable in every state.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Scrollable {
+    /// The style of the scrollbar of an active scrollable.
+    pub active: scrollable::Scrollbar,
+
+    /// The style of the scrollbar of a hovered scrollable.
+    pub hovered: Option<scrollable::Scrollbar>,
+
+    /// The style of the scrollbar while its scroller is being dragged.
+    pub dragging: Option<scrollable::Scrollbar>,
+}
+
+impl scrollable::StyleSheet for Scrollable {
+    fn active(&self) -> scrollable::Scrollbar {
+        self.active
+    }
+
+    fn hovered(&self) -> scrollable::Scrollbar {
+        self.hovered.unwrap_or(self.active)
+    }
+
+    fn dragging(&self) -> scrollable::Scrollbar {
+        self.dragging
+            .unwrap_or_else(|| scrollable::StyleSheet::hovered(self))
+    }
+}
+
+/// The styles of a slider in every state.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Slider {
+    /// The style of an active slider.
+    pub active: slider::Style,
+
+    /// The style of a hovered slider.
+    pub hovered: Option<slider::Style>,
+
+    /// The style of a slider while its handle is being dragged.
+    pub dragging: Option<slider::Style>,
+
+    /// The style of a focused slider.
+    pub focused: Option<slider::Style>,
+}
+
+impl slider::StyleSheet for Slider {
+    fn active(&self) -> slider::Style {
+        self.active
+    }
+
+    fn hovered(&self) -> slider::Style {
+        self.hovered.unwrap_or(self.active)
+    }
+
+    fn dragging(&self) -> slider::Style {
+        self.dragging
+            .unwrap_or_else(|| slider::StyleSheet::hovered(self))
+    }
+
+    fn focused(&self) -> slider::Style {
+        self.focused.unwrap_or_else(|| {
+            slider::highlight(slider::StyleSheet::hovered(self))
+        })
+    }
+}
+
+/// The styles of a text input in every state.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct TextInput {
+    /// The style of an active text input.
+    pub active: text_input::Style,
+
+    /// The style of a hovered text input.
+    pub hovered: Option<text_input::Style>,
+
+    /// The style of a focused text input.
+    pub focused: Option<text_input::Style>,
+
+    /// The color of the placeholder of a text input.
+    pub placeholder_color: Color,
+
+    /// The color of the value of a text input.
+    pub value_color: Color,
+}
+
+impl text_input::StyleSheet for TextInput {
+    fn active(&self) -> text_input::Style {
+        self.active
+    }
+
+    fn hovered(&self) -> text_input::Style {
+        self.hovered.unwrap_or(self.active)
+    }
+
+    fn focused(&self) -> text_input::Style {
+        self.focused.unwrap_or(self.active)
+    }
+
+    fn placeholder_color(&self) -> Color {
+        self.placeholder_color
+    }
+
+    fn value_color(&self) -> Color {
+        self.value_color
+    }
+}
+
+struct Container(container::Style);
+
+impl container::StyleSheet for Container {
+    fn style(&self) -> container::Style {
+        self.0
+    }
+}
+
+struct ProgressBar(progress_bar::Style);
+
+impl progress_bar::StyleSheet for ProgressBar {
+    fn style(&self) -> progress_bar::Style {
+        self.0
+    }
+}
+
+/// A button style sheet that derives every state from the active style,
+/// using the default rules of [`button::StyleSheet`].
+///
+/// [`button::StyleSheet`]: ../button/trait.StyleSheet.html
+struct Derived(button::Style);
+
+impl button::StyleSheet for Derived {
+    fn active(&self) -> button::Style {
+        self.0
+    }
+}
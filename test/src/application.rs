@@ -0,0 +1,103 @@
+use crate::Simulator;
+
+use iced_native::{Command, Element, Program, Size};
+use iced_wgpu::{wgpu, Pipeline, Renderer, Settings};
+
+use std::ops::{Deref, DerefMut};
+
+/// A [`Program`] running an [`iced::Application`], so it can be driven by a
+/// [`Simulator`].
+///
+/// It dereferences to the [`iced::Application`]. The subscriptions and window
+/// settings of the [`iced::Application`] are ignored.
+///
+/// [`Program`]: ../iced_native/trait.Program.html
+/// [`iced::Application`]: ../iced/trait.Application.html
+/// [`Simulator`]: struct.Simulator.html
+#[allow(missing_debug_implementations)]
+pub struct Application<A> {
+    application: A,
+
+    // Keeps the resources of the renderer alive
+    _device: wgpu::Device,
+}
+
+impl<A> Deref for Application<A> {
+    type Target = A;
+
+    fn deref(&self) -> &A {
+        &self.application
+    }
+}
+
+impl<A> DerefMut for Application<A> {
+    fn deref_mut(&mut self) -> &mut A {
+        &mut self.application
+    }
+}
+
+impl<A> Program for Application<A>
+where
+    A: iced::Application,
+{
+    type Renderer = Renderer;
+    type Message = A::Message;
+
+    fn update(&mut self, message: A::Message) -> Command<A::Message> {
+        self.application.update(message)
+    }
+
+    fn view(&mut self) -> Element<'_, A::Message, Renderer> {
+        self.application.view()
+    }
+}
+
+impl<A> Simulator<Application<A>>
+where
+    A: iced::Application,
+    A::Message: Clone,
+{
+    /// Creates a new [`Simulator`] for the [`iced::Application`] of type `A`
+    /// in a viewport of the given logical size.
+    ///
+    /// The [`iced::Application`] is initialized and its initial [`Command`]
+    /// is run to completion. Text is measured with the fonts of `iced_wgpu`,
+    /// so widgets are laid out like in a window.
+    ///
+    /// Returns `None` if no graphics adapter is available.
+    ///
+    /// [`Simulator`]: struct.Simulator.html
+    /// [`iced::Application`]: ../iced/trait.Application.html
+    /// [`Command`]: ../iced_native/struct.Command.html
+    pub fn application(size: Size) -> Option<Self> {
+        let adapter = wgpu::Adapter::request(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::Default,
+            backends: wgpu::BackendBit::all(),
+        })?;
+
+        let (mut device, _queue) =
+            adapter.request_device(&wgpu::DeviceDescriptor {
+                extensions: wgpu::Extensions {
+                    anisotropic_filtering: false,
+                },
+                limits: wgpu::Limits { max_bind_groups: 2 },
+            });
+
+        let renderer =
+            Renderer::new(Pipeline::new(&mut device, Settings::default()));
+        let (application, command) = A::new();
+
+        let mut simulator = Simulator::new(
+            Application {
+                application,
+                _device: device,
+            },
+            renderer,
+            size,
+        );
+
+        simulator.perform(command);
+
+        Some(simulator)
+    }
+}
@@ -0,0 +1,132 @@
+//! A headless testing harness for [Iced] programs.
+//!
+//! A [`Simulator`] runs an [`iced_native::Program`] without opening a window.
+//! Tests can inject synthetic events, like clicks or key presses, and then
+//! assert on the produced messages and on the state of the program.
+//!
+//! The [`Null`] renderer can be used to lay out widgets without a GPU. Keep in
+//! mind that it does not measure text, so widgets are positioned using a fixed
+//! line height and no width.
+//!
+//! ```
+//! use iced_native::{
+//!     button, renderer::Null, Button, Command, Element, Length, Point,
+//!     Program, Size, Text,
+//! };
+//! use iced_test::Simulator;
+//!
+//! #[derive(Default)]
+//! struct Counter {
+//!     value: i32,
+//!     increment: button::State,
+//! }
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum Message {
+//!     Increment,
+//! }
+//!
+//! impl Program for Counter {
+//!     type Renderer = Null;
+//!     type Message = Message;
+//!
+//!     fn update(&mut self, message: Message) -> Command<Message> {
+//!         match message {
+//!             Message::Increment => self.value += 1,
+//!         }
+//!
+//!         Command::none()
+//!     }
+//!
+//!     fn view(&mut self) -> Element<'_, Message, Null> {
+//!         Button::new(&mut self.increment, Text::new("+"))
+//!             .width(Length::Fill)
+//!             .on_press(Message::Increment)
+//!             .into()
+//!     }
+//! }
+//!
+//! let mut simulator =
+//!     Simulator::new(Counter::default(), Null::new(), Size::new(800.0, 600.0));
+//!
+//! simulator.click(Point::new(10.0, 10.0));
+//!
+//! assert_eq!(simulator.messages(), &[Message::Increment]);
+//! assert_eq!(simulator.program().value, 1);
+//! ```
+//!
+//! Widgets can also be found by their label or by the id given to them with
+//! `Element::id`, using a [`Selector`]:
+//!
+//! ```
+//! # use iced_native::{
+//! #     button, renderer::Null, Button, Command, Element, Program, Size,
+//! #     Text,
+//! # };
+//! # #[derive(Default)]
+//! # struct Counter {
+//! #     increment: button::State,
+//! # }
+//! #
+//! # impl Program for Counter {
+//! #     type Renderer = Null;
+//! #     type Message = ();
+//! #
+//! #     fn update(&mut self, _message: ()) -> Command<()> {
+//! #         Command::none()
+//! #     }
+//! #
+//! #     fn view(&mut self) -> Element<'_, (), Null> {
+//! #         Button::new(&mut self.increment, Text::new("+"))
+//! #             .on_press(())
+//! #             .into()
+//! #     }
+//! # }
+//! use iced_test::{Selector, Simulator};
+//!
+//! let mut simulator =
+//!     Simulator::new(Counter::default(), Null::new(), Size::new(800.0, 600.0));
+//!
+//! simulator.click_on(&Selector::label("+"));
+//!
+//! assert_eq!(simulator.messages(), &[()]);
+//! ```
+//!
+//! # Applications
+//! When the `application` feature is enabled, an [`iced::Application`] can be
+//! simulated with [`Simulator::application`]. Its widgets are laid out with
+//! `iced_wgpu`, which needs a graphics adapter.
+//!
+//! # Snapshots
+//! When the `snapshot` feature is enabled, views can be rendered offscreen
+//! with `iced_wgpu` and compared against reference images. Take a look at the
+//! [`snapshot`] module to learn more.
+//!
+//! [Iced]: https://github.com/hecrj/iced
+//! [`Simulator`]: struct.Simulator.html
+//! [`iced_native::Program`]: ../iced_native/trait.Program.html
+//! [`Null`]: ../iced_native/renderer/struct.Null.html
+//! [`snapshot`]: snapshot/index.html
+//! [`Selector`]: enum.Selector.html
+//! [`iced::Application`]: ../iced/trait.Application.html
+//! [`Simulator::application`]: struct.Simulator.html#method.application
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![deny(unused_results)]
+#![forbid(unsafe_code)]
+#![forbid(rust_2018_idioms)]
+mod simulator;
+
+#[cfg(feature = "application")]
+mod application;
+
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
+
+pub use simulator::{Selector, Simulator};
+
+#[cfg(feature = "application")]
+pub use application::Application;
+
+#[cfg(feature = "snapshot")]
+pub use snapshot::Snapshot;
@@ -0,0 +1,451 @@
+use iced_native::{
+    futures::executor,
+    input::{keyboard, mouse, ButtonState},
+    layout,
+    time::{self, Clock},
+    Cache, Command, Event, Point, Program, Size, UserInterface,
+};
+
+use std::time::{Duration, Instant};
+
+/// A headless runner of a [`Program`].
+///
+/// Every interaction is processed immediately: the produced messages are
+/// recorded, the [`Program`] is updated, and any resulting [`Command`] is run
+/// to completion in the current thread.
+///
+/// While a [`Simulator`] is alive, the current thread runs with a manual
+/// [`Clock`]. Time only passes when the [`Simulator`] is advanced, which makes
+/// animations and time-based subscriptions deterministic.
+///
+/// [`Simulator`]: struct.Simulator.html
+/// [`Clock`]: ../iced_native/time/struct.Clock.html
+/// [`Program`]: ../iced_native/trait.Program.html
+/// [`Command`]: ../iced_native/struct.Command.html
+#[allow(missing_debug_implementations)]
+pub struct Simulator<P: Program> {
+    // Dropped before the program, which may own the device of the renderer
+    renderer: P::Renderer,
+    program: P,
+    size: Size,
+    cache: Option<Cache>,
+    messages: Vec<P::Message>,
+    clock: Clock,
+    previous_clock: Option<Clock>,
+}
+
+impl<P> Simulator<P>
+where
+    P: Program,
+    P::Message: Clone,
+{
+    /// Creates a new [`Simulator`] for the given [`Program`], laid out with
+    /// the provided renderer in a viewport of the given logical size.
+    ///
+    /// [`Simulator`]: struct.Simulator.html
+    /// [`Program`]: ../iced_native/trait.Program.html
+    pub fn new(program: P, renderer: P::Renderer, size: Size) -> Self {
+        let clock = Clock::manual(Instant::now());
+        let previous_clock = time::set_clock(clock.clone());
+
+        Simulator {
+            renderer,
+            program,
+            size,
+            cache: Some(Cache::default()),
+            messages: Vec::new(),
+            clock,
+            previous_clock: Some(previous_clock),
+        }
+    }
+
+    /// Returns a reference to the [`Program`] being simulated.
+    ///
+    /// [`Program`]: ../iced_native/trait.Program.html
+    pub fn program(&self) -> &P {
+        &self.program
+    }
+
+    /// Returns a mutable reference to the [`Program`] being simulated.
+    ///
+    /// [`Program`]: ../iced_native/trait.Program.html
+    pub fn program_mut(&mut self) -> &mut P {
+        &mut self.program
+    }
+
+    /// Returns all the messages produced so far, in order.
+    pub fn messages(&self) -> &[P::Message] {
+        &self.messages
+    }
+
+    /// Takes all the messages produced so far, clearing the record.
+    pub fn take_messages(&mut self) -> Vec<P::Message> {
+        std::mem::replace(&mut self.messages, Vec::new())
+    }
+
+    /// Returns the manual [`Clock`] driving the [`Simulator`].
+    ///
+    /// [`Clock`]: ../iced_native/time/struct.Clock.html
+    /// [`Simulator`]: struct.Simulator.html
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Moves the [`Clock`] of the [`Simulator`] forward by the given
+    /// [`Duration`].
+    ///
+    /// [`Clock`]: ../iced_native/time/struct.Clock.html
+    /// [`Simulator`]: struct.Simulator.html
+    /// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    pub fn advance(&mut self, duration: Duration) {
+        self.clock.advance(duration);
+    }
+
+    /// Changes the logical size of the viewport of the [`Simulator`].
+    ///
+    /// [`Simulator`]: struct.Simulator.html
+    pub fn resize(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    /// Describes the widgets currently displayed by the [`Program`], in
+    /// depth-first order.
+    ///
+    /// [`Program`]: ../iced_native/trait.Program.html
+    pub fn inspect(&mut self) -> Vec<layout::WidgetNode> {
+        let user_interface = UserInterface::build(
+            self.program.view(),
+            self.size,
+            self.cache.take().unwrap(),
+            &mut self.renderer,
+        );
+
+        let nodes = user_interface.inspect();
+
+        self.cache = Some(user_interface.into_cache());
+
+        nodes
+    }
+
+    /// Finds the first widget matching the given [`Selector`], in depth-first
+    /// order.
+    ///
+    /// [`Selector`]: enum.Selector.html
+    pub fn find(&mut self, selector: &Selector) -> Option<layout::WidgetNode> {
+        self.inspect()
+            .into_iter()
+            .find(|node| selector.matches(node))
+    }
+
+    /// Clicks the center of the first widget matching the given [`Selector`].
+    ///
+    /// # Panics
+    /// Panics if no widget matches the [`Selector`].
+    ///
+    /// [`Selector`]: enum.Selector.html
+    pub fn click_on(&mut self, selector: &Selector) {
+        let node = self
+            .find(selector)
+            .unwrap_or_else(|| panic!("no widget matches {:?}", selector));
+
+        self.click(Point::new(
+            node.bounds.x + node.bounds.width / 2.0,
+            node.bounds.y + node.bounds.height / 2.0,
+        ));
+    }
+
+    /// Processes the given events, in order.
+    pub fn send(&mut self, events: impl IntoIterator<Item = Event>) {
+        let mut user_interface = UserInterface::build(
+            self.program.view(),
+            self.size,
+            self.cache.take().unwrap(),
+            &mut self.renderer,
+        );
+
+        let messages = user_interface.update(events, None, &self.renderer);
+
+        self.cache = Some(user_interface.into_cache());
+
+        for message in messages {
+            self.process(message);
+        }
+    }
+
+    /// Moves the mouse cursor to the given position.
+    pub fn move_cursor(&mut self, position: Point) {
+        self.send(vec![Event::Mouse(mouse::Event::CursorMoved {
+            x: position.x,
+            y: position.y,
+        })]);
+    }
+
+    /// Clicks the left mouse button at the given position.
+    pub fn click(&mut self, position: Point) {
+        self.send(vec![
+            Event::Mouse(mouse::Event::CursorMoved {
+                x: position.x,
+                y: position.y,
+            }),
+            Event::Mouse(mouse::Event::Input {
+                state: ButtonState::Pressed,
+                button: mouse::Button::Left,
+                modifiers: keyboard::ModifiersState::default(),
+            }),
+        ]);
+
+        self.send(vec![Event::Mouse(mouse::Event::Input {
+            state: ButtonState::Released,
+            button: mouse::Button::Left,
+            modifiers: keyboard::ModifiersState::default(),
+        })]);
+    }
+
+    /// Presses and releases the given key, without any modifiers.
+    pub fn press_key(&mut self, key_code: keyboard::KeyCode) {
+        let modifiers = keyboard::ModifiersState::default();
+
+        for state in &[ButtonState::Pressed, ButtonState::Released] {
+            self.send(vec![Event::Keyboard(keyboard::Event::Input {
+                state: *state,
+                key_code,
+                scan_code: 0,
+                repeat: false,
+                modifiers,
+            })]);
+        }
+    }
+
+    /// Types the given text, one character at a time.
+    ///
+    /// The text is received by the focused widget, if any. You can focus a
+    /// `TextInput` by clicking it first.
+    pub fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.send(vec![Event::Keyboard(
+                keyboard::Event::CharacterReceived(c),
+            )]);
+        }
+    }
+
+    /// Sends the given message directly to the [`Program`], as if it had been
+    /// produced by a widget.
+    ///
+    /// [`Program`]: ../iced_native/trait.Program.html
+    pub fn process(&mut self, message: P::Message) {
+        self.run(vec![message]);
+    }
+
+    /// Runs the given [`Command`] to completion, processing the messages it
+    /// produces.
+    ///
+    /// [`Command`]: ../iced_native/struct.Command.html
+    pub fn perform(&mut self, command: Command<P::Message>) {
+        let queue = command
+            .futures()
+            .into_iter()
+            .map(executor::block_on)
+            .collect();
+
+        self.run(queue);
+    }
+
+    fn run(&mut self, mut queue: Vec<P::Message>) {
+        while !queue.is_empty() {
+            for message in std::mem::replace(&mut queue, Vec::new()) {
+                self.messages.push(message.clone());
+
+                let command = self.program.update(message);
+
+                for future in command.futures() {
+                    queue.push(executor::block_on(future));
+                }
+            }
+        }
+    }
+}
+
+impl<P: Program> Drop for Simulator<P> {
+    fn drop(&mut self) {
+        if let Some(clock) = self.previous_clock.take() {
+            let _ = time::set_clock(clock);
+        }
+    }
+}
+
+/// A query that identifies a widget in a [`Simulator`].
+///
+/// [`Simulator`]: struct.Simulator.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selector {
+    /// Matches the widget given this id with `Element::id`.
+    Id(&'static str),
+
+    /// Matches a widget with this exact label, like the content of a `Text`
+    /// or the label of a `Checkbox`.
+    Label(String),
+}
+
+impl Selector {
+    /// Creates a [`Selector`] that matches the widget with the given id.
+    ///
+    /// [`Selector`]: enum.Selector.html
+    pub fn id(id: &'static str) -> Self {
+        Selector::Id(id)
+    }
+
+    /// Creates a [`Selector`] that matches a widget with the given label.
+    ///
+    /// [`Selector`]: enum.Selector.html
+    pub fn label(label: impl Into<String>) -> Self {
+        Selector::Label(label.into())
+    }
+
+    /// Returns true if the [`Selector`] matches the given [`WidgetNode`].
+    ///
+    /// [`Selector`]: enum.Selector.html
+    /// [`WidgetNode`]: ../iced_native/layout/struct.WidgetNode.html
+    pub fn matches(&self, node: &layout::WidgetNode) -> bool {
+        match self {
+            Selector::Id(id) => node.id == Some(*id),
+            Selector::Label(label) => node.label.as_ref() == Some(label),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::{
+        button, renderer::Null, text_input, Button, Checkbox, Column, Element,
+        Length, Text, TextInput,
+    };
+
+    #[derive(Default)]
+    struct Form {
+        name: String,
+        is_subscribed: bool,
+        submissions: usize,
+        name_input: text_input::State,
+        submit: button::State,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        NameChanged(String),
+        SubscriptionToggled(bool),
+        Submitted,
+    }
+
+    impl Program for Form {
+        type Renderer = Null;
+        type Message = Message;
+
+        fn update(&mut self, message: Message) -> Command<Message> {
+            match message {
+                Message::NameChanged(name) => self.name = name,
+                Message::SubscriptionToggled(is_subscribed) => {
+                    self.is_subscribed = is_subscribed
+                }
+                Message::Submitted => self.submissions += 1,
+            }
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, Message, Null> {
+            Column::new()
+                .width(Length::Fill)
+                .spacing(10)
+                .push(Text::new("Newsletter"))
+                .push(TextInput::new(
+                    &mut self.name_input,
+                    "Your name",
+                    &self.name,
+                    Message::NameChanged,
+                ))
+                .push(Checkbox::new(
+                    self.is_subscribed,
+                    "Subscribe",
+                    Message::SubscriptionToggled,
+                ))
+                .push(
+                    Element::from(
+                        Button::new(&mut self.submit, Text::new("Submit"))
+                            .width(Length::Fill)
+                            .on_press(Message::Submitted),
+                    )
+                    .id("submit"),
+                )
+                .into()
+        }
+    }
+
+    fn simulator() -> Simulator<Form> {
+        Simulator::new(Form::default(), Null::new(), Size::new(800.0, 600.0))
+    }
+
+    #[test]
+    fn finds_widgets_by_label() {
+        let mut simulator = simulator();
+
+        let title = simulator.find(&Selector::label("Newsletter")).unwrap();
+        let checkbox = simulator.find(&Selector::label("Subscribe")).unwrap();
+        let input = simulator.find(&Selector::label("Your name")).unwrap();
+
+        assert_eq!(title.name, "Text");
+        assert_eq!(checkbox.name, "Checkbox");
+        assert_eq!(input.name, "TextInput");
+        assert!(simulator.find(&Selector::label("Unsubscribe")).is_none());
+    }
+
+    #[test]
+    fn finds_widgets_by_id() {
+        let mut simulator = simulator();
+
+        let button = simulator.find(&Selector::id("submit")).unwrap();
+        let label = simulator.find(&Selector::label("Submit")).unwrap();
+
+        // The id belongs to the button, not to its content
+        assert_eq!(button.name, "Button");
+        assert_eq!(label.id, None);
+        assert_eq!(label.depth, button.depth + 1);
+        assert!(simulator.find(&Selector::id("cancel")).is_none());
+    }
+
+    #[test]
+    fn clicks_on_selected_widgets() {
+        let mut simulator = simulator();
+
+        simulator.click_on(&Selector::label("Subscribe"));
+        simulator.click_on(&Selector::id("submit"));
+
+        assert_eq!(
+            simulator.messages(),
+            &[Message::SubscriptionToggled(true), Message::Submitted]
+        );
+        assert!(simulator.program().is_subscribed);
+        assert_eq!(simulator.program().submissions, 1);
+    }
+
+    #[test]
+    fn types_in_selected_inputs() {
+        let mut simulator = simulator();
+
+        simulator.click_on(&Selector::label("Your name"));
+        simulator.type_text("Ada");
+
+        assert_eq!(simulator.program().name, "Ada");
+        assert_eq!(
+            simulator.take_messages().last(),
+            Some(&Message::NameChanged(String::from("Ada")))
+        );
+        assert!(simulator.messages().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "no widget matches")]
+    fn panics_when_clicking_a_missing_widget() {
+        simulator().click_on(&Selector::id("cancel"));
+    }
+}
@@ -0,0 +1,378 @@
+//! Compare the rendered output of a view against reference images.
+use iced_native::{Cache, Element, Size, UserInterface};
+use iced_wgpu::{
+    offscreen::{self, Offscreen},
+    Renderer,
+};
+
+use std::path::{Path, PathBuf};
+
+/// The environment variable that forces reference images to be overwritten
+/// by [`Snapshot::assert_matches`].
+///
+/// [`Snapshot::assert_matches`]: struct.Snapshot.html#method.assert_matches
+pub const UPDATE_VARIABLE: &str = "ICED_UPDATE_SNAPSHOTS";
+
+/// The rendered pixels of a view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+/// The differences between a [`Snapshot`] and its reference.
+///
+/// [`Snapshot`]: struct.Snapshot.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diff {
+    /// The amount of pixels that are perceptually different.
+    pub mismatched_pixels: usize,
+
+    /// An image highlighting the mismatched pixels in red, on top of a faded
+    /// version of the reference.
+    pub image: Snapshot,
+}
+
+impl Snapshot {
+    /// Renders the given element with the provided [`Offscreen`] renderer in
+    /// a viewport of the given logical size.
+    ///
+    /// [`Offscreen`]: ../../iced_wgpu/offscreen/struct.Offscreen.html
+    pub fn render<'a, Message>(
+        offscreen: &mut Offscreen,
+        element: impl Into<Element<'a, Message, Renderer>>,
+        size: Size,
+        scale_factor: f64,
+    ) -> Result<Snapshot, offscreen::Error> {
+        let output = {
+            let renderer = offscreen.renderer_mut();
+
+            let user_interface =
+                UserInterface::build(element, size, Cache::default(), renderer);
+
+            user_interface.draw(renderer)
+        };
+
+        let width = (f64::from(size.width) * scale_factor).round() as u32;
+        let height = (f64::from(size.height) * scale_factor).round() as u32;
+
+        let pixels =
+            offscreen.render(&output, (width, height), scale_factor)?;
+
+        assert_eq!(
+            pixels.len(),
+            (width * height * 4) as usize,
+            "Offscreen renderer produced an image of the wrong size"
+        );
+
+        Ok(Snapshot {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Creates a [`Snapshot`] from the given RGBA pixels.
+    ///
+    /// # Panics
+    /// Panics if the amount of pixels does not match the provided dimensions.
+    ///
+    /// [`Snapshot`]: struct.Snapshot.html
+    pub fn from_pixels(width: u32, height: u32, pixels: Vec<u8>) -> Snapshot {
+        assert_eq!(pixels.len(), (width * height * 4) as usize);
+
+        Snapshot {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Loads a [`Snapshot`] from the PNG image at the given path.
+    ///
+    /// [`Snapshot`]: struct.Snapshot.html
+    pub fn load(path: impl AsRef<Path>) -> Result<Snapshot, image::ImageError> {
+        let image = image::open(path)?.to_rgba();
+        let (width, height) = image.dimensions();
+
+        Ok(Snapshot {
+            width,
+            height,
+            pixels: image.into_raw(),
+        })
+    }
+
+    /// Saves the [`Snapshot`] as a PNG image at the given path.
+    ///
+    /// [`Snapshot`]: struct.Snapshot.html
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        image::save_buffer(
+            path,
+            &self.pixels,
+            self.width,
+            self.height,
+            image::ColorType::RGBA(8),
+        )
+    }
+
+    /// Returns the dimensions of the [`Snapshot`].
+    ///
+    /// [`Snapshot`]: struct.Snapshot.html
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Returns the pixels of the [`Snapshot`], row by row, in RGBA order.
+    ///
+    /// [`Snapshot`]: struct.Snapshot.html
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Compares the [`Snapshot`] with the given reference.
+    ///
+    /// Two pixels are considered different when their perceptual color
+    /// difference exceeds the given threshold, which ranges from `0.0` (exact
+    /// match) to `1.0` (anything goes). A threshold of `0.1` tolerates
+    /// antialiasing and rasterization differences between graphics drivers.
+    ///
+    /// Snapshots with different dimensions, or with a different amount of
+    /// pixels, never match.
+    ///
+    /// [`Snapshot`]: struct.Snapshot.html
+    pub fn compare(
+        &self,
+        reference: &Snapshot,
+        threshold: f32,
+    ) -> Result<(), Diff> {
+        if self.dimensions() != reference.dimensions()
+            || self.pixels.len() != reference.pixels.len()
+        {
+            return Err(Diff {
+                mismatched_pixels: (self.width * self.height)
+                    .max(reference.width * reference.height)
+                    as usize,
+                image: self.clone(),
+            });
+        }
+
+        let max_delta = 35215.0 * threshold * threshold;
+        let mut mismatched_pixels = 0;
+        let mut diff = Vec::with_capacity(self.pixels.len());
+
+        for (actual, expected) in self
+            .pixels
+            .chunks_exact(4)
+            .zip(reference.pixels.chunks_exact(4))
+        {
+            let delta = color_delta(actual, expected);
+
+            if delta > max_delta {
+                mismatched_pixels += 1;
+                diff.extend_from_slice(&[255, 0, 0, 255]);
+            } else {
+                let gray = faded_luminance(expected);
+                diff.extend_from_slice(&[gray, gray, gray, 255]);
+            }
+        }
+
+        if mismatched_pixels == 0 {
+            Ok(())
+        } else {
+            Err(Diff {
+                mismatched_pixels,
+                image: Snapshot {
+                    width: self.width,
+                    height: self.height,
+                    pixels: diff,
+                },
+            })
+        }
+    }
+
+    /// Asserts that the [`Snapshot`] matches the reference image stored at
+    /// the given path, using the provided perceptual threshold.
+    ///
+    /// If the reference image does not exist yet, or the
+    /// `ICED_UPDATE_SNAPSHOTS` environment variable is set, the [`Snapshot`]
+    /// is stored as the new reference instead.
+    ///
+    /// # Panics
+    /// Panics if the [`Snapshot`] does not match the reference. The actual
+    /// output and a diff image are saved next to the reference, with the
+    /// `.actual.png` and `.diff.png` extensions.
+    ///
+    /// [`Snapshot`]: struct.Snapshot.html
+    pub fn assert_matches(&self, path: impl AsRef<Path>, threshold: f32) {
+        let path = path.as_ref();
+
+        if !path.exists() || std::env::var_os(UPDATE_VARIABLE).is_some() {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .expect("Create snapshot directory");
+            }
+
+            self.save(path).expect("Save reference snapshot");
+            return;
+        }
+
+        let reference = Snapshot::load(path).expect("Load reference snapshot");
+
+        if let Err(diff) = self.compare(&reference, threshold) {
+            let actual_path = with_suffix(path, "actual");
+            let diff_path = with_suffix(path, "diff");
+
+            self.save(&actual_path).expect("Save actual snapshot");
+            diff.image.save(&diff_path).expect("Save diff snapshot");
+
+            panic!(
+                "Snapshot does not match {} ({} mismatched pixels).\n\
+                 Actual: {}\n\
+                 Diff: {}",
+                path.display(),
+                diff.mismatched_pixels,
+                actual_path.display(),
+                diff_path.display()
+            );
+        }
+    }
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    path.with_file_name(format!("{}.{}.png", stem, suffix))
+}
+
+/// Computes the perceptual difference between two RGBA pixels, as described
+/// in "Measuring perceived color difference using YIQ NTSC transmission color
+/// space in mobile applications" by Y. Kotsarenko and F. Ramos.
+fn color_delta(a: &[u8], b: &[u8]) -> f32 {
+    let (r1, g1, b1) = blend_with_white(a);
+    let (r2, g2, b2) = blend_with_white(b);
+
+    let y = luminance(r1, g1, b1) - luminance(r2, g2, b2);
+    let i = in_phase(r1, g1, b1) - in_phase(r2, g2, b2);
+    let q = quadrature(r1, g1, b1) - quadrature(r2, g2, b2);
+
+    0.5053 * y * y + 0.299 * i * i + 0.1957 * q * q
+}
+
+fn faded_luminance(pixel: &[u8]) -> u8 {
+    let (r, g, b) = blend_with_white(pixel);
+    let y = luminance(r, g, b);
+
+    (255.0 + 0.1 * (y - 255.0)) as u8
+}
+
+fn blend_with_white(pixel: &[u8]) -> (f32, f32, f32) {
+    let alpha = f32::from(pixel[3]) / 255.0;
+    let blend = |channel: u8| 255.0 + (f32::from(channel) - 255.0) * alpha;
+
+    (blend(pixel[0]), blend(pixel[1]), blend(pixel[2]))
+}
+
+fn luminance(r: f32, g: f32, b: f32) -> f32 {
+    r * 0.298_895_3 + g * 0.586_622_5 + b * 0.114_482_23
+}
+
+fn in_phase(r: f32, g: f32, b: f32) -> f32 {
+    r * 0.595_978 - g * 0.274_176_1 - b * 0.321_801_9
+}
+
+fn quadrature(r: f32, g: f32, b: f32) -> f32 {
+    r * 0.211_470_17 - g * 0.522_617_1 + b * 0.311_146_94
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHITE: [u8; 4] = [255, 255, 255, 255];
+    const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+    fn solid(width: u32, height: u32, pixel: [u8; 4]) -> Snapshot {
+        Snapshot::from_pixels(
+            width,
+            height,
+            pixel.repeat((width * height) as usize),
+        )
+    }
+
+    #[test]
+    fn equal_colors_have_no_delta() {
+        assert_eq!(color_delta(&WHITE, &WHITE), 0.0);
+        assert_eq!(color_delta(&BLACK, &BLACK), 0.0);
+    }
+
+    #[test]
+    fn black_and_white_are_almost_maximally_different() {
+        let delta = color_delta(&BLACK, &WHITE);
+
+        assert!(delta > 32_000.0);
+        assert!(delta <= 35215.0);
+        assert_eq!(delta, color_delta(&WHITE, &BLACK));
+    }
+
+    #[test]
+    fn transparent_pixels_blend_with_white() {
+        assert_eq!(color_delta(&[0, 0, 0, 0], &WHITE), 0.0);
+    }
+
+    #[test]
+    fn luminance_dominates_chrominance() {
+        // The same change in luminance is more noticeable than a change in
+        // the blue-yellow axis of the same magnitude
+        let gray = [128, 128, 128, 255];
+        let darker = [118, 118, 118, 255];
+        let bluer = [128, 128, 138, 255];
+
+        assert!(color_delta(&gray, &darker) > color_delta(&gray, &bluer));
+    }
+
+    #[test]
+    fn compare_tolerates_small_differences() {
+        let reference = solid(2, 2, [100, 150, 200, 255]);
+        let actual = solid(2, 2, [101, 151, 199, 255]);
+
+        assert_eq!(actual.compare(&reference, 0.1), Ok(()));
+        assert!(actual.compare(&reference, 0.0).is_err());
+    }
+
+    #[test]
+    fn compare_counts_mismatched_pixels() {
+        let reference = solid(2, 2, WHITE);
+        let mut pixels = WHITE.repeat(4);
+        pixels[..4].copy_from_slice(&BLACK);
+
+        let diff = Snapshot::from_pixels(2, 2, pixels)
+            .compare(&reference, 0.1)
+            .unwrap_err();
+
+        assert_eq!(diff.mismatched_pixels, 1);
+        assert_eq!(&diff.image.pixels()[..4], &[255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn compare_rejects_different_dimensions() {
+        let reference = solid(2, 2, WHITE);
+
+        assert!(solid(4, 1, WHITE).compare(&reference, 1.0).is_err());
+    }
+
+    #[test]
+    fn compare_rejects_truncated_pixels() {
+        let reference = solid(2, 2, WHITE);
+        let truncated = Snapshot {
+            width: 2,
+            height: 2,
+            pixels: WHITE.repeat(3),
+        };
+
+        assert!(truncated.compare(&reference, 1.0).is_err());
+    }
+}
@@ -17,6 +17,13 @@ pub enum Rule {
 
     /// Spacing between elements
     Spacing(u16),
+
+    /// A set of declarations generated from the style of a widget, together
+    /// with their pseudo-class variants
+    Styled(Vec<(&'static str, String)>),
+
+    /// Segment sweeping across an indeterminate progress bar
+    Sweep,
 }
 
 impl Rule {
@@ -29,6 +36,16 @@ impl Rule {
             Rule::Row => String::from("r"),
             Rule::Padding(padding) => format!("p-{}", padding),
             Rule::Spacing(spacing) => format!("s-{}", spacing),
+            Rule::Styled(states) => {
+                use std::collections::hash_map::DefaultHasher;
+                use std::hash::{Hash, Hasher};
+
+                let mut hasher = DefaultHasher::new();
+                states.hash(&mut hasher);
+
+                format!("st-{:x}", hasher.finish())
+            }
+            Rule::Sweep => String::from("sweep"),
         }
     }
 
@@ -70,6 +87,29 @@ impl Rule {
                 class
             )
             .into_bump_str(),
+            Rule::Styled(states) => {
+                let mut declaration =
+                    bumpalo::collections::String::new_in(bump);
+
+                for (pseudo_class, body) in states {
+                    declaration.push_str(&format!(
+                        ".{}{} {{ {} }} ",
+                        class, pseudo_class, body
+                    ));
+                }
+
+                declaration.into_bump_str()
+            }
+            Rule::Sweep => bumpalo::format!(
+                in bump,
+                "@keyframes {} {{ from {{ left: -30% }} to {{ left: 100% }} }} \
+                 .{} {{ position: absolute; top: 0; width: 30%; height: 100%; \
+                 animation: {} 1.5s linear infinite }}",
+                class,
+                class,
+                class
+            )
+            .into_bump_str(),
         }
     }
 }
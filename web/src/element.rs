@@ -52,6 +52,26 @@ impl<'a, Message> Element<'a, Message> {
         self
     }
 
+    /// Hides the [`Element`] if `is_hidden` is true.
+    ///
+    /// A hidden [`Element`] keeps its place in the layout. However, it is not
+    /// drawn and it ignores any events. Therefore, toggling its visibility
+    /// does not move the surrounding content around.
+    ///
+    /// [`Element`]: struct.Element.html
+    pub fn hidden(self, is_hidden: bool) -> Element<'a, Message>
+    where
+        Message: 'a,
+    {
+        if !is_hidden {
+            return self;
+        }
+
+        Element {
+            widget: Box::new(Hidden { content: self }),
+        }
+    }
+
     /// Produces a VDOM node for the [`Element`].
     pub fn node<'b>(
         &self,
@@ -95,3 +115,25 @@ where
             .node(bump, &bus.map(self.mapper.clone()), style_sheet)
     }
 }
+
+struct Hidden<'a, Message> {
+    content: Element<'a, Message>,
+}
+
+impl<'a, Message> Widget<Message> for Hidden<'a, Message> {
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        style_sheet: &mut Css<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        // The wrapper does not generate a box, so the content keeps its place
+        // in the layout while inheriting the hidden visibility
+        div(bump)
+            .attr("style", "display: contents; visibility: hidden")
+            .children(vec![self.content.node(bump, bus, style_sheet)])
+            .finish()
+    }
+}
@@ -65,6 +65,7 @@ mod hasher;
 
 pub mod css;
 pub mod subscription;
+pub mod time;
 pub mod widget;
 
 pub use bus::Bus;
@@ -189,6 +190,9 @@ pub trait Application {
         );
         runtime.spawn(command);
 
+        let subscription = app.subscription();
+        runtime.track(subscription);
+
         let application = Rc::new(RefCell::new(app));
 
         let instance = Instance {
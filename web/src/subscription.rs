@@ -17,3 +17,9 @@ use crate::Hasher;
 pub type Subscription<T> = iced_futures::Subscription<Hasher, (), T>;
 
 pub use iced_futures::subscription::Recipe;
+
+pub mod websocket;
+
+pub(crate) mod every;
+
+mod local;
@@ -0,0 +1,45 @@
+use crate::{subscription::local, time::Instant, Hasher};
+
+use iced_futures::{futures::stream::BoxStream, subscription::Recipe};
+use wasm_bindgen::{closure::Closure, JsCast};
+
+pub struct Every(pub std::time::Duration);
+
+impl Recipe<Hasher, ()> for Every {
+    type Output = Instant;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.0.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: BoxStream<'static, ()>,
+    ) -> BoxStream<'static, Self::Output> {
+        let duration = self.0;
+
+        local::run(move |sender, dropped| async move {
+            let window = web_sys::window().expect("Get window");
+
+            let callback = Closure::wrap(Box::new(move || {
+                let _ = sender.unbounded_send(Instant::now());
+            }) as Box<dyn FnMut()>);
+
+            let handle = window
+                .set_interval_with_callback_and_timeout_and_arguments_0(
+                    callback.as_ref().unchecked_ref(),
+                    duration.as_millis() as i32,
+                )
+                .expect("Set interval");
+
+            dropped.await;
+
+            // The callback is dropped right after this, so the interval must
+            // never fire again
+            window.clear_interval_with_handle(handle);
+        })
+    }
+}
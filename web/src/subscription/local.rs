@@ -0,0 +1,61 @@
+use iced_futures::futures::{
+    channel::{mpsc, oneshot},
+    stream::{BoxStream, Stream, StreamExt},
+    task::{Context, Poll},
+    Future,
+};
+use std::pin::Pin;
+
+/// Runs a local task that produces the values of a stream, until the stream is
+/// dropped.
+///
+/// Browser resources, like sockets and timers, cannot be sent between threads.
+/// Therefore, they cannot live in a stream that is handed over to the runtime.
+/// Instead, they live in the local task, which receives the values to produce
+/// and a future that resolves once the stream is dropped. The task must then
+/// release its resources and finish.
+pub fn run<T, F, Fut>(task: F) -> BoxStream<'static, T>
+where
+    T: 'static + Send,
+    F: FnOnce(mpsc::UnboundedSender<T>, Dropped) -> Fut,
+    Fut: 'static + Future<Output = ()>,
+{
+    let (sender, receiver) = mpsc::unbounded();
+    let (guard, dropped) = oneshot::channel();
+
+    wasm_bindgen_futures::spawn_local(task(sender, Dropped(dropped)));
+
+    Local {
+        receiver,
+        _guard: guard,
+    }
+    .boxed()
+}
+
+/// A future that resolves once the stream of a local task is dropped.
+pub struct Dropped(oneshot::Receiver<()>);
+
+impl Future for Dropped {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // The guard never sends anything, so any result means it was dropped
+        Pin::new(&mut self.0).poll(cx).map(|_| ())
+    }
+}
+
+struct Local<T> {
+    receiver: mpsc::UnboundedReceiver<T>,
+    _guard: oneshot::Sender<()>,
+}
+
+impl<T> Stream for Local<T> {
+    type Item = T;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<T>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
@@ -0,0 +1,201 @@
+//! Listen to messages from a WebSocket server.
+use crate::{subscription::local, Hasher, Subscription};
+
+use iced_futures::{
+    futures::{
+        channel::mpsc,
+        stream::{self, BoxStream, StreamExt},
+    },
+    subscription::Recipe,
+};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+
+use std::fmt;
+
+/// An event of a WebSocket connection.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// The connection with the server was established.
+    ///
+    /// The [`Sender`] can be kept to send messages to the server.
+    ///
+    /// [`Sender`]: struct.Sender.html
+    Connected(Sender),
+
+    /// A message was received from the server.
+    Message(Message),
+
+    /// The connection with the server was closed, or it could not be
+    /// established.
+    Disconnected,
+}
+
+/// A message of a WebSocket connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    /// A text message.
+    Text(String),
+
+    /// A binary message.
+    Binary(Vec<u8>),
+}
+
+/// A handle to send messages through a WebSocket connection.
+///
+/// It is produced by [`Event::Connected`], and it stops working once the
+/// connection is closed or the [`Subscription`] is dropped.
+///
+/// [`Event::Connected`]: enum.Event.html#variant.Connected
+/// [`Subscription`]: ../type.Subscription.html
+#[derive(Debug, Clone)]
+pub struct Sender(mpsc::UnboundedSender<Message>);
+
+impl Sender {
+    /// Sends a [`Message`] to the server.
+    ///
+    /// [`Message`]: enum.Message.html
+    pub fn send(&self, message: Message) -> Result<(), Closed> {
+        self.0.unbounded_send(message).map_err(|_| Closed)
+    }
+}
+
+impl PartialEq for Sender {
+    fn eq(&self, other: &Sender) -> bool {
+        self.0.same_receiver(&other.0)
+    }
+}
+
+/// An error produced when sending a [`Message`] through a connection that is
+/// already closed.
+///
+/// [`Message`]: enum.Message.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Closed;
+
+impl fmt::Display for Closed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the WebSocket connection is closed")
+    }
+}
+
+impl std::error::Error for Closed {}
+
+/// Returns a [`Subscription`] that connects to the WebSocket server at the
+/// given URL and produces the [`Event`]s of the connection.
+///
+/// The connection is closed once the [`Subscription`] is dropped.
+///
+/// [`Subscription`]: ../type.Subscription.html
+/// [`Event`]: enum.Event.html
+pub fn connect(url: impl Into<String>) -> Subscription<Event> {
+    Subscription::from_recipe(Connection { url: url.into() })
+}
+
+struct Connection {
+    url: String,
+}
+
+impl Recipe<Hasher, ()> for Connection {
+    type Output = Event;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.url.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: BoxStream<'static, ()>,
+    ) -> BoxStream<'static, Self::Output> {
+        let url = self.url;
+
+        local::run(move |events, dropped| async move {
+            let socket = match web_sys::WebSocket::new(&url) {
+                Ok(socket) => socket,
+                Err(_) => {
+                    let _ = events.unbounded_send(Event::Disconnected);
+                    return;
+                }
+            };
+
+            socket.set_binary_type(web_sys::BinaryType::Arraybuffer);
+
+            let (sender, outgoing) = mpsc::unbounded();
+
+            let on_open = {
+                let events = events.clone();
+
+                Closure::wrap(Box::new(move |_: JsValue| {
+                    let _ = events.unbounded_send(Event::Connected(Sender(
+                        sender.clone(),
+                    )));
+                }) as Box<dyn FnMut(JsValue)>)
+            };
+
+            let on_message = {
+                let events = events.clone();
+
+                Closure::wrap(Box::new(move |event: web_sys::MessageEvent| {
+                    let data = event.data();
+
+                    let message = if let Some(text) = data.as_string() {
+                        Message::Text(text)
+                    } else if data.is_instance_of::<js_sys::ArrayBuffer>() {
+                        let bytes = js_sys::Uint8Array::new(&data);
+
+                        Message::Binary(bytes.to_vec())
+                    } else {
+                        return;
+                    };
+
+                    let _ = events.unbounded_send(Event::Message(message));
+                })
+                    as Box<dyn FnMut(web_sys::MessageEvent)>)
+            };
+
+            let on_close = Closure::wrap(Box::new(move |_: JsValue| {
+                let _ = events.unbounded_send(Event::Disconnected);
+            })
+                as Box<dyn FnMut(JsValue)>);
+
+            socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+            socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+            socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+
+            enum Input {
+                Send(Message),
+                Drop,
+            }
+
+            let mut inputs = stream::select(
+                outgoing.map(Input::Send),
+                stream::once(dropped).map(|_| Input::Drop),
+            );
+
+            while let Some(input) = inputs.next().await {
+                // Failing to send means the connection is closing, which is
+                // reported by the close callback
+                let _ = match input {
+                    Input::Send(Message::Text(text)) => {
+                        socket.send_with_str(&text)
+                    }
+                    Input::Send(Message::Binary(bytes)) => socket
+                        .send_with_array_buffer_view(
+                            &js_sys::Uint8Array::from(&bytes[..]),
+                        ),
+                    Input::Drop => break,
+                };
+            }
+
+            // The callbacks are dropped right after this, so the socket must
+            // never call them again
+            socket.set_onopen(None);
+            socket.set_onmessage(None);
+            socket.set_onclose(None);
+
+            let _ = socket.close();
+        })
+    }
+}
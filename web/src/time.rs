@@ -0,0 +1,17 @@
+//! Listen and react to time.
+use crate::{subscription::every::Every, Subscription};
+
+/// A measurement of a monotonically nondecreasing clock, backed by
+/// `performance.now()`.
+pub use wasm_timer::Instant;
+
+/// Returns a [`Subscription`] that produces messages at a set interval.
+///
+/// The first message is produced after a `duration`, and then continues to
+/// produce more messages every `duration` after that. It uses `setInterval`
+/// under the hood, which is cleared once the [`Subscription`] is dropped.
+///
+/// [`Subscription`]: ../subscription/type.Subscription.html
+pub fn every(duration: std::time::Duration) -> Subscription<Instant> {
+    Subscription::from_recipe(Every(duration))
+}
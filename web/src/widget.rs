@@ -23,6 +23,7 @@ pub mod container;
 pub mod image;
 pub mod progress_bar;
 pub mod radio;
+pub mod radio_group;
 pub mod scrollable;
 pub mod slider;
 pub mod text_input;
@@ -49,6 +50,7 @@ pub use container::Container;
 pub use image::Image;
 pub use progress_bar::ProgressBar;
 pub use radio::Radio;
+pub use radio_group::RadioGroup;
 pub use row::Row;
 pub use space::Space;
 
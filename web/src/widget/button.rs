@@ -4,7 +4,7 @@
 //!
 //! [`Button`]: struct.Button.html
 //! [`State`]: struct.State.html
-use crate::{css, Background, Bus, Css, Element, Length, Widget};
+use crate::{css, Bus, Css, Element, Length, Widget};
 
 pub use iced_style::button::{Style, StyleSheet};
 
@@ -33,6 +33,8 @@ pub struct Button<'a, Message> {
     min_height: u32,
     padding: u16,
     style: Box<dyn StyleSheet>,
+    class: String,
+    inline_style: String,
 }
 
 impl<'a, Message> Button<'a, Message> {
@@ -54,6 +56,8 @@ impl<'a, Message> Button<'a, Message> {
             min_height: 0,
             padding: 5,
             style: Default::default(),
+            class: String::new(),
+            inline_style: String::new(),
         }
     }
 
@@ -105,6 +109,25 @@ impl<'a, Message> Button<'a, Message> {
         self
     }
 
+    /// Adds a custom CSS class to the [`Button`].
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets some inline CSS declarations for the [`Button`].
+    ///
+    /// They take precedence over the [`StyleSheet`] of the [`Button`].
+    ///
+    /// [`Button`]: struct.Button.html
+    /// [`StyleSheet`]: trait.StyleSheet.html
+    pub fn inline_style(mut self, inline_style: impl Into<String>) -> Self {
+        self.inline_style = inline_style.into();
+        self
+    }
+
     /// Sets the message that will be produced when the [`Button`] is pressed.
     ///
     /// [`Button`]: struct.Button.html
@@ -141,34 +164,39 @@ where
     ) -> dodrio::Node<'b> {
         use dodrio::builder::*;
 
-        // TODO: State-based styling
-        let style = self.style.active();
-
         let padding_class =
             style_sheet.insert(bump, css::Rule::Padding(self.padding));
 
-        let background = match style.background {
-            None => String::from("none"),
-            Some(background) => match background {
-                Background::Color(color) => css::color(color),
-            },
-        };
+        let style_class = style_sheet.insert(
+            bump,
+            css::Rule::Styled(vec![
+                ("", declarations(self.style.active())),
+                (":hover", declarations(self.style.hovered())),
+                (":active", declarations(self.style.pressed())),
+                (":disabled", declarations(self.style.disabled())),
+            ]),
+        );
 
         let mut node = button(bump)
             .attr(
                 "class",
-                bumpalo::format!(in bump, "{}", padding_class).into_bump_str(),
+                bumpalo::format!(
+                    in bump,
+                    "{} {} {}",
+                    padding_class,
+                    style_class,
+                    self.class
+                )
+                .into_bump_str(),
             )
             .attr(
                 "style",
                 bumpalo::format!(
                     in bump,
-                    "background: {}; border-radius: {}px; width:{}; min-width: {}; color: {}",
-                    background,
-                    style.border_radius,
+                    "width: {}; min-width: {}; {}",
                     css::length(self.width),
                     css::min_length(self.min_width),
-                    css::color(style.text_color)
+                    self.inline_style
                 )
                 .into_bump_str(),
             )
@@ -180,6 +208,8 @@ where
             node = node.on("click", move |_root, _vdom, _event| {
                 event_bus.publish(on_press.clone());
             });
+        } else {
+            node = node.attr("disabled", "");
         }
 
         node.finish()
@@ -194,3 +224,20 @@ where
         Element::new(button)
     }
 }
+
+fn declarations(style: Style) -> String {
+    format!(
+        "background: {}; border: {}px solid {}; border-radius: {}px; \
+         box-shadow: {}px {}px 0 rgba(0, 0, 0, 0.5); color: {}",
+        style
+            .background
+            .map(css::background)
+            .unwrap_or(String::from("none")),
+        style.border_width,
+        css::color(style.border_color),
+        style.border_radius,
+        style.shadow_offset.x,
+        style.shadow_offset.y,
+        css::color(style.text_color)
+    )
+}
@@ -22,6 +22,12 @@ use std::rc::Rc;
 /// Checkbox::new(is_checked, "Toggle me!", Message::CheckboxToggled);
 /// ```
 ///
+/// A [`Checkbox`] can be made focusable to keep the same API as native
+/// platforms. The browser already lets users focus any [`Checkbox`] with
+/// `Tab` and toggle it with `Space`.
+///
+/// [`Checkbox`]: struct.Checkbox.html
+///
 /// ![Checkbox drawn by Coffee's renderer](https://github.com/hecrj/coffee/blob/bda9818f823dfcb8a7ad0ff4940b4d4b387b5208/images/ui/checkbox.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct Checkbox<Message> {
@@ -71,6 +77,32 @@ impl<Message> Checkbox<Message> {
         self.style = style.into();
         self
     }
+
+    /// Makes the [`Checkbox`] focusable with some local [`State`].
+    ///
+    /// The browser already lets users focus any [`Checkbox`], so this keeps the
+    /// same API as native platforms.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    /// [`State`]: struct.State.html
+    pub fn focusable(self, _state: &mut State) -> Self {
+        self
+    }
+}
+
+/// The local state of a [`Checkbox`].
+///
+/// [`Checkbox`]: struct.Checkbox.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State;
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
 }
 
 impl<Message> Widget<Message> for Checkbox<Message>
@@ -16,6 +16,8 @@ pub struct Container<'a, Message> {
     vertical_alignment: Align,
     style_sheet: Box<dyn StyleSheet>,
     content: Element<'a, Message>,
+    class: String,
+    inline_style: String,
 }
 
 impl<'a, Message> Container<'a, Message> {
@@ -37,6 +39,8 @@ impl<'a, Message> Container<'a, Message> {
             vertical_alignment: Align::Start,
             style_sheet: Default::default(),
             content: content.into(),
+            class: String::new(),
+            inline_style: String::new(),
         }
     }
 
@@ -90,6 +94,22 @@ impl<'a, Message> Container<'a, Message> {
         self
     }
 
+    /// Adds a custom CSS class to the [`Container`].
+    ///
+    /// [`Container`]: struct.Container.html
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets some inline CSS declarations for the [`Container`].
+    ///
+    /// [`Container`]: struct.Container.html
+    pub fn inline_style(mut self, inline_style: impl Into<String>) -> Self {
+        self.inline_style = inline_style.into();
+        self
+    }
+
     /// Sets the style of the [`Container`].
     ///
     /// [`Container`]: struct.Container.html
@@ -118,13 +138,13 @@ where
         let node = div(bump)
             .attr(
                 "class",
-                bumpalo::format!(in bump, "{}", column_class).into_bump_str(),
+                bumpalo::format!(in bump, "{} {}", column_class, self.class).into_bump_str(),
             )
             .attr(
                 "style",
                 bumpalo::format!(
                     in bump,
-                    "width: {}; height: {}; max-width: {}; align-items: {}; justify-content: {}; background: {}; color: {}; border-width: {}px; border-color: {}; border-radius: {}px",
+                    "width: {}; height: {}; max-width: {}; align-items: {}; justify-content: {}; background: {}; color: {}; border-width: {}px; border-color: {}; border-radius: {}px; {}",
                     css::length(self.width),
                     css::length(self.height),
                     css::max_length(self.max_width),
@@ -134,7 +154,8 @@ where
                     style.text_color.map(css::color).unwrap_or(String::from("inherit")),
                     style.border_width,
                     css::color(style.border_color),
-                    style.border_radius
+                    style.border_radius,
+                    self.inline_style
                 )
                 .into_bump_str(),
             )
@@ -68,7 +68,8 @@ impl<Message> Widget<Message> for Image {
         use dodrio::builder::*;
 
         let src = bumpalo::format!(in bump, "{}", match self.handle.data.as_ref() {
-            Data::Path(path) => path.to_str().unwrap_or("")
+            Data::Path(path) => path.to_str().unwrap_or(""),
+            Data::Url(url) => url.as_str(),
         });
 
         let mut image = img(bump).attr("src", src.into_bump_str());
@@ -115,6 +116,13 @@ impl Handle {
         Self::from_data(Data::Path(path.into()))
     }
 
+    /// Creates an image [`Handle`] pointing to the image at the given URL.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn from_url<T: Into<String>>(url: T) -> Handle {
+        Self::from_data(Data::Url(url.into()))
+    }
+
     fn from_data(data: Data) -> Handle {
         let mut hasher = Hasher::default();
         data.hash(&mut hasher);
@@ -159,12 +167,16 @@ impl From<&str> for Handle {
 pub enum Data {
     /// A remote image
     Path(PathBuf),
+
+    /// An image at an absolute URL
+    Url(String),
 }
 
 impl std::fmt::Debug for Data {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Data::Path(path) => write!(f, "Path({:?})", path),
+            Data::Url(url) => write!(f, "Url({:?})", url),
         }
     }
 }
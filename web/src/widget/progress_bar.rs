@@ -21,6 +21,7 @@ use std::ops::RangeInclusive;
 pub struct ProgressBar {
     range: RangeInclusive<f32>,
     value: f32,
+    is_indeterminate: bool,
     width: Length,
     height: Option<Length>,
     style: Box<dyn StyleSheet>,
@@ -38,12 +39,26 @@ impl ProgressBar {
         ProgressBar {
             value: value.max(*range.start()).min(*range.end()),
             range,
+            is_indeterminate: false,
             width: Length::Fill,
             height: None,
             style: Default::default(),
         }
     }
 
+    /// Creates a new indeterminate [`ProgressBar`].
+    ///
+    /// An indeterminate [`ProgressBar`] shows that some work is in progress,
+    /// without telling how much of it is done. It is animated with CSS.
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn indeterminate() -> Self {
+        ProgressBar {
+            is_indeterminate: true,
+            ..Self::new(0.0..=1.0, 0.0)
+        }
+    }
+
     /// Sets the width of the [`ProgressBar`].
     ///
     /// [`ProgressBar`]: struct.ProgressBar.html
@@ -74,34 +89,55 @@ impl<Message> Widget<Message> for ProgressBar {
         &self,
         bump: &'b bumpalo::Bump,
         _bus: &Bus<Message>,
-        _style_sheet: &mut Css<'b>,
+        style_sheet: &mut Css<'b>,
     ) -> dodrio::Node<'b> {
         use dodrio::builder::*;
 
-        let (range_start, range_end) = self.range.clone().into_inner();
-        let amount_filled =
-            (self.value - range_start) / (range_end - range_start).max(1.0);
-
         let style = self.style.style();
 
-        let bar = div(bump)
-            .attr(
-                "style",
-                bumpalo::format!(
-                    in bump,
-                    "width: {}%; height: 100%; background: {}",
-                    amount_filled * 100.0,
-                    css::background(style.bar)
+        let bar = if self.is_indeterminate {
+            let sweep_class = style_sheet.insert(bump, css::Rule::Sweep);
+
+            div(bump)
+                .attr(
+                    "class",
+                    bumpalo::format!(in bump, "{}", sweep_class)
+                        .into_bump_str(),
                 )
-                .into_bump_str(),
-            )
-            .finish();
+                .attr(
+                    "style",
+                    bumpalo::format!(
+                        in bump,
+                        "background: {}",
+                        css::background(style.bar)
+                    )
+                    .into_bump_str(),
+                )
+                .finish()
+        } else {
+            let (range_start, range_end) = self.range.clone().into_inner();
+            let amount_filled =
+                (self.value - range_start) / (range_end - range_start).max(1.0);
+
+            div(bump)
+                .attr(
+                    "style",
+                    bumpalo::format!(
+                        in bump,
+                        "width: {}%; height: 100%; background: {}",
+                        amount_filled * 100.0,
+                        css::background(style.bar)
+                    )
+                    .into_bump_str(),
+                )
+                .finish()
+        };
 
         let node = div(bump).attr(
             "style",
             bumpalo::format!(
                 in bump,
-                "width: {}; height: {}; background: {}; border-radius: {}px; overflow: hidden;",
+                "width: {}; height: {}; background: {}; border-radius: {}px; overflow: hidden; position: relative;",
                 css::length(self.width),
                 css::length(self.height.unwrap_or(Length::Units(30))),
                 css::background(style.background),
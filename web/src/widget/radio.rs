@@ -4,6 +4,7 @@ use crate::{Bus, Css, Element, Widget};
 pub use iced_style::radio::{Style, StyleSheet};
 
 use dodrio::bumpalo;
+use std::rc::Rc;
 
 /// A circular button representing a choice.
 ///
@@ -29,13 +30,20 @@ use dodrio::bumpalo;
 /// Radio::new(Choice::B, "This is B", selected_choice, Message::RadioSelected);
 /// ```
 ///
+/// A [`Radio`] button can be made focusable to keep the same API as native
+/// platforms. The browser already lets users focus any [`Radio`] button with
+/// `Tab` and select it with `Space`.
+///
+/// [`Radio`]: struct.Radio.html
+///
 /// ![Radio buttons drawn by Coffee's renderer](https://github.com/hecrj/coffee/blob/bda9818f823dfcb8a7ad0ff4940b4d4b387b5208/images/ui/radio.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct Radio<Message> {
     is_selected: bool,
     on_click: Message,
     label: String,
-    style: Box<dyn StyleSheet>,
+    name: Option<String>,
+    style: Rc<Box<dyn StyleSheet>>,
 }
 
 impl<Message> Radio<Message> {
@@ -58,6 +66,7 @@ impl<Message> Radio<Message> {
             is_selected: Some(value) == selected,
             on_click: f(value),
             label: String::from(label),
+            name: None,
             style: Default::default(),
         }
     }
@@ -66,11 +75,59 @@ impl<Message> Radio<Message> {
     ///
     /// [`Radio`]: struct.Radio.html
     pub fn style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self {
-        self.style = style.into();
+        self.style = Rc::new(style.into());
+        self
+    }
+
+    /// Sets a style of the [`Radio`] button that is shared with other radio
+    /// buttons.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub(crate) fn shared_style(
+        mut self,
+        style: Rc<Box<dyn StyleSheet>>,
+    ) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Makes the [`Radio`] button focusable with some local [`State`].
+    ///
+    /// The browser already lets users focus any [`Radio`], so this keeps the
+    /// same API as native platforms.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    /// [`State`]: struct.State.html
+    pub fn focusable(self, _state: &mut State) -> Self {
+        self
+    }
+
+    /// Sets the name of the group of the [`Radio`] button, letting the
+    /// browser move the selection between the buttons of a group with the
+    /// arrow keys.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub(crate) fn name(mut self, name: String) -> Self {
+        self.name = Some(name);
         self
     }
 }
 
+/// The local state of a [`Radio`] button.
+///
+/// [`Radio`]: struct.Radio.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State;
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
 impl<Message> Widget<Message> for Radio<Message>
 where
     Message: 'static + Clone,
@@ -88,20 +145,26 @@ where
         let event_bus = bus.clone();
         let on_click = self.on_click.clone();
 
+        let radio = input(bump)
+            .attr("type", "radio")
+            .attr("style", "margin-right: 10px")
+            .bool_attr("checked", self.is_selected)
+            .on("click", move |_root, _vdom, _event| {
+                event_bus.publish(on_click.clone());
+            });
+
+        let radio = match &self.name {
+            Some(name) => radio.attr(
+                "name",
+                bumpalo::format!(in bump, "{}", name).into_bump_str(),
+            ),
+            None => radio,
+        };
+
         // TODO: Complete styling
         label(bump)
             .attr("style", "display: block; font-size: 20px")
-            .children(vec![
-                input(bump)
-                    .attr("type", "radio")
-                    .attr("style", "margin-right: 10px")
-                    .bool_attr("checked", self.is_selected)
-                    .on("click", move |_root, _vdom, _event| {
-                        event_bus.publish(on_click.clone());
-                    })
-                    .finish(),
-                text(radio_label.into_bump_str()),
-            ])
+            .children(vec![radio.finish(), text(radio_label.into_bump_str())])
             .finish()
     }
 }
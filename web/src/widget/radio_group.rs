@@ -0,0 +1,234 @@
+//! Let users choose a value out of a set of options.
+use crate::{Bus, Column, Css, Element, Length, Radio, Row, Widget};
+
+use dodrio::bumpalo;
+use iced_style::radio::StyleSheet;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+/// A set of [`Radio`] buttons representing the options of a single choice.
+///
+/// # Example
+/// ```
+/// # use iced_web::{radio_group, RadioGroup};
+/// #
+/// #[derive(Debug, Clone, PartialEq, Eq)]
+/// pub enum Language {
+///     Rust,
+///     Elm,
+///     Haskell,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// pub enum Message {
+///     LanguageSelected(Language),
+/// }
+///
+/// let mut state = radio_group::State::new();
+/// let selected = Some(Language::Rust);
+///
+/// let group = RadioGroup::new(
+///     &mut state,
+///     vec![
+///         ("Rust", Language::Rust),
+///         ("Elm", Language::Elm),
+///         ("Haskell", Language::Haskell),
+///     ],
+///     selected,
+///     Message::LanguageSelected,
+/// );
+/// ```
+///
+/// The [`Radio`] buttons of a [`RadioGroup`] share a name. Therefore, the
+/// browser lets users focus the [`RadioGroup`] with `Tab` and move the
+/// selection between its options with the arrow keys.
+///
+/// [`Radio`]: ../radio/struct.Radio.html
+/// [`RadioGroup`]: struct.RadioGroup.html
+#[allow(missing_debug_implementations)]
+pub struct RadioGroup<'a, T, Message> {
+    _state: &'a mut State,
+    labels: Vec<String>,
+    values: Vec<T>,
+    selected: Option<usize>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    direction: Direction,
+    spacing: u16,
+    width: Length,
+    style: Rc<Box<dyn StyleSheet>>,
+}
+
+impl<'a, T, Message> RadioGroup<'a, T, Message>
+where
+    T: Eq + Clone,
+{
+    /// Creates a new [`RadioGroup`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`RadioGroup`]
+    ///   * the options of the [`RadioGroup`], as pairs of a label and a value
+    ///   * the current selected value
+    ///   * a function that will be called when an option is selected. It
+    ///   receives the value of the option and must produce a `Message`.
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    /// [`State`]: struct.State.html
+    pub fn new<I, L, F>(
+        state: &'a mut State,
+        options: I,
+        selected: Option<T>,
+        on_select: F,
+    ) -> Self
+    where
+        I: IntoIterator<Item = (L, T)>,
+        L: Into<String>,
+        F: 'a + Fn(T) -> Message,
+    {
+        let (labels, values): (Vec<String>, Vec<T>) = options
+            .into_iter()
+            .map(|(label, value)| (label.into(), value))
+            .unzip();
+
+        let selected = selected
+            .and_then(|selected| values.iter().position(|v| *v == selected));
+
+        RadioGroup {
+            _state: state,
+            labels,
+            values,
+            selected,
+            on_select: Box::new(on_select),
+            direction: Direction::default(),
+            spacing: 0,
+            width: Length::Fill,
+            style: Rc::new(Default::default()),
+        }
+    }
+
+    /// Sets the [`Direction`] in which the options of the [`RadioGroup`] are
+    /// laid out.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the spacing _between_ the options of the [`RadioGroup`].
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the width of the [`RadioGroup`].
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the style of every [`Radio`] button of the [`RadioGroup`].
+    ///
+    /// [`Radio`]: ../radio/struct.Radio.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self {
+        self.style = Rc::new(style.into());
+        self
+    }
+}
+
+/// The direction in which the options of a [`RadioGroup`] are laid out.
+///
+/// [`RadioGroup`]: struct.RadioGroup.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// One option below the other
+    Vertical,
+
+    /// One option next to the other
+    Horizontal,
+}
+
+impl Default for Direction {
+    fn default() -> Self {
+        Direction::Vertical
+    }
+}
+
+/// The local state of a [`RadioGroup`].
+///
+/// [`RadioGroup`]: struct.RadioGroup.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State;
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+impl<'a, T, Message> Widget<Message> for RadioGroup<'a, T, Message>
+where
+    T: Clone,
+    Message: 'static + Clone,
+{
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        style_sheet: &mut Css<'b>,
+    ) -> dodrio::Node<'b> {
+        // The name of the group only needs to be stable and unique enough
+        // to tell different groups apart in the same page
+        let name = {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            self.labels.hash(&mut hasher);
+
+            format!("radio-group-{:x}", hasher.finish())
+        };
+
+        let radios = self.labels.iter().zip(&self.values).enumerate().map(
+            |(i, (label, value))| {
+                let message = (self.on_select)(value.clone());
+
+                Element::from(
+                    Radio::new(i, label, self.selected, move |_| {
+                        message.clone()
+                    })
+                    .name(name.clone())
+                    .shared_style(Rc::clone(&self.style)),
+                )
+            },
+        );
+
+        match self.direction {
+            Direction::Vertical => radios
+                .fold(Column::new(), Column::push)
+                .width(self.width)
+                .spacing(self.spacing)
+                .node(bump, bus, style_sheet),
+            Direction::Horizontal => radios
+                .fold(Row::new(), Row::push)
+                .width(self.width)
+                .spacing(self.spacing)
+                .node(bump, bus, style_sheet),
+        }
+    }
+}
+
+impl<'a, T, Message> From<RadioGroup<'a, T, Message>> for Element<'a, Message>
+where
+    T: 'a + Clone,
+    Message: 'static + Clone,
+{
+    fn from(radio_group: RadioGroup<'a, T, Message>) -> Element<'a, Message> {
+        Element::new(radio_group)
+    }
+}
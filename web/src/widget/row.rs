@@ -18,6 +18,8 @@ pub struct Row<'a, Message> {
     max_height: u32,
     align_items: Align,
     children: Vec<Element<'a, Message>>,
+    class: String,
+    inline_style: String,
 }
 
 impl<'a, Message> Row<'a, Message> {
@@ -34,6 +36,8 @@ impl<'a, Message> Row<'a, Message> {
             max_height: u32::MAX,
             align_items: Align::Start,
             children: Vec::new(),
+            class: String::new(),
+            inline_style: String::new(),
         }
     }
 
@@ -95,6 +99,22 @@ impl<'a, Message> Row<'a, Message> {
         self
     }
 
+    /// Adds a custom CSS class to the [`Row`].
+    ///
+    /// [`Row`]: struct.Row.html
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets some inline CSS declarations for the [`Row`].
+    ///
+    /// [`Row`]: struct.Row.html
+    pub fn inline_style(mut self, inline_style: impl Into<String>) -> Self {
+        self.inline_style = inline_style.into();
+        self
+    }
+
     /// Adds an [`Element`] to the [`Row`].
     ///
     /// [`Element`]: ../struct.Element.html
@@ -135,17 +155,18 @@ impl<'a, Message> Widget<Message> for Row<'a, Message> {
         div(bump)
             .attr(
                 "class",
-                bumpalo::format!(in bump, "{} {} {}", row_class, spacing_class, padding_class)
+                bumpalo::format!(in bump, "{} {} {} {}", row_class, spacing_class, padding_class, self.class)
                     .into_bump_str(),
             )
             .attr("style", bumpalo::format!(
                     in bump,
-                    "width: {}; height: {}; max-width: {}; max-height: {}; align-items: {}",
+                    "width: {}; height: {}; max-width: {}; max-height: {}; align-items: {}; {}",
                     css::length(self.width),
                     css::length(self.height),
                     css::max_length(self.max_width),
                     css::max_length(self.max_height),
-                    css::align(self.align_items)
+                    css::align(self.align_items),
+                    self.inline_style
                 ).into_bump_str()
             )
             .children(children)
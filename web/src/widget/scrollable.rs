@@ -11,6 +11,7 @@ pub struct Scrollable<'a, Message> {
     height: Length,
     max_height: u32,
     content: Column<'a, Message>,
+    stick_to_end: bool,
     style: Box<dyn StyleSheet>,
 }
 
@@ -27,6 +28,7 @@ impl<'a, Message> Scrollable<'a, Message> {
             height: Length::Shrink,
             max_height: u32::MAX,
             content: Column::new(),
+            stick_to_end: false,
             style: Default::default(),
         }
     }
@@ -89,6 +91,24 @@ impl<'a, Message> Scrollable<'a, Message> {
         self
     }
 
+    /// Sets whether the [`Scrollable`] should stick to the end of its
+    /// contents.
+    ///
+    /// When enabled, the [`Scrollable`] stays scrolled to the bottom as new
+    /// content is appended, like a chat or a log. It stops following the end
+    /// once the user scrolls up, and starts following it again once the user
+    /// scrolls back to the bottom.
+    ///
+    /// It relies on the scroll anchoring of the browser, which is not
+    /// available everywhere. Where it is missing, the [`Scrollable`] simply
+    /// keeps its position.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn stick_to_end(mut self, stick_to_end: bool) -> Self {
+        self.stick_to_end = stick_to_end;
+        self
+    }
+
     /// Sets the style of the [`Scrollable`] .
     ///
     /// [`Scrollable`]: struct.Scrollable.html
@@ -126,6 +146,27 @@ where
 
         // TODO: Scrollbar styling
 
+        let content = self.content.node(bump, bus, style_sheet);
+
+        let children = if self.stick_to_end {
+            // The browser keeps the anchor in view while it is visible, which
+            // only happens when the scrollable is scrolled to the bottom.
+            // Everything else is excluded from anchoring, so the position is
+            // kept otherwise.
+            let content = div(bump)
+                .attr("style", "overflow-anchor: none")
+                .children(vec![content])
+                .finish();
+
+            let anchor = div(bump)
+                .attr("style", "overflow-anchor: auto; height: 1px")
+                .finish();
+
+            vec![content, anchor]
+        } else {
+            vec![content]
+        };
+
         let node = div(bump)
             .attr(
                 "style",
@@ -138,7 +179,7 @@ where
                 )
                 .into_bump_str(),
             )
-            .children(vec![self.content.node(bump, bus, style_sheet)]);
+            .children(children);
 
         node.finish()
     }
@@ -24,6 +24,8 @@ pub struct Text {
     height: Length,
     horizontal_alignment: HorizontalAlignment,
     vertical_alignment: VerticalAlignment,
+    class: String,
+    inline_style: String,
 }
 
 impl Text {
@@ -40,6 +42,8 @@ impl Text {
             height: Length::Shrink,
             horizontal_alignment: HorizontalAlignment::Left,
             vertical_alignment: VerticalAlignment::Top,
+            class: String::new(),
+            inline_style: String::new(),
         }
     }
 
@@ -105,6 +109,22 @@ impl Text {
         self.vertical_alignment = alignment;
         self
     }
+
+    /// Adds a custom CSS class to the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets some inline CSS declarations for the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    pub fn inline_style(mut self, inline_style: impl Into<String>) -> Self {
+        self.inline_style = inline_style.into();
+        self
+    }
 }
 
 impl<'a, Message> Widget<Message> for Text {
@@ -133,7 +153,7 @@ impl<'a, Message> Widget<Message> for Text {
 
         let style = bumpalo::format!(
             in bump,
-            "width: {}; height: {}; font-size: {}px; color: {}; text-align: {}; font-family: {}",
+            "width: {}; height: {}; font-size: {}px; color: {}; text-align: {}; font-family: {}; {}",
             width,
             height,
             self.size.unwrap_or(20),
@@ -142,11 +162,16 @@ impl<'a, Message> Widget<Message> for Text {
             match self.font {
                 Font::Default => "inherit",
                 Font::External { name, .. } => name,
-            }
+            },
+            self.inline_style
         );
 
         // TODO: Complete styling
         p(bump)
+            .attr(
+                "class",
+                bumpalo::format!(in bump, "{}", self.class).into_bump_str(),
+            )
             .attr("style", style.into_bump_str())
             .children(vec![text(content.into_bump_str())])
             .finish()
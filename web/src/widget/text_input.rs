@@ -43,6 +43,8 @@ pub struct TextInput<'a, Message> {
     on_change: Rc<Box<dyn Fn(String) -> Message>>,
     on_submit: Option<Message>,
     style_sheet: Box<dyn StyleSheet>,
+    class: String,
+    inline_style: String,
 }
 
 impl<'a, Message> TextInput<'a, Message> {
@@ -77,6 +79,8 @@ impl<'a, Message> TextInput<'a, Message> {
             on_change: Rc::new(Box::new(on_change)),
             on_submit: None,
             style_sheet: Default::default(),
+            class: String::new(),
+            inline_style: String::new(),
         }
     }
 
@@ -136,6 +140,22 @@ impl<'a, Message> TextInput<'a, Message> {
         self.style_sheet = style.into();
         self
     }
+
+    /// Adds a custom CSS class to the [`TextInput`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets some inline CSS declarations for the [`TextInput`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn inline_style(mut self, inline_style: impl Into<String>) -> Self {
+        self.inline_style = inline_style.into();
+        self
+    }
 }
 
 impl<'a, Message> Widget<Message> for TextInput<'a, Message>
@@ -158,26 +178,44 @@ where
         let on_submit = self.on_submit.clone();
         let input_event_bus = bus.clone();
         let submit_event_bus = bus.clone();
-        let style = self.style_sheet.active();
+        let style_class = style_sheet.insert(
+            bump,
+            css::Rule::Styled(vec![
+                ("", declarations(self.style_sheet.active())),
+                (":hover", declarations(self.style_sheet.hovered())),
+                (":focus", declarations(self.style_sheet.focused())),
+                (
+                    "::placeholder",
+                    format!(
+                        "color: {}",
+                        css::color(self.style_sheet.placeholder_color())
+                    ),
+                ),
+            ]),
+        );
 
         input(bump)
             .attr(
                 "class",
-                bumpalo::format!(in bump, "{}", padding_class).into_bump_str(),
+                bumpalo::format!(
+                    in bump,
+                    "{} {} {}",
+                    padding_class,
+                    style_class,
+                    self.class
+                )
+                .into_bump_str(),
             )
             .attr(
                 "style",
                 bumpalo::format!(
                     in bump,
-                    "width: {}; max-width: {}; font-size: {}px; background: {}; border-width: {}px; border-color: {}; border-radius: {}px; color: {}",
+                    "width: {}; max-width: {}; font-size: {}px; color: {}; {}",
                     css::length(self.width),
                     css::max_length(self.max_width),
                     self.size.unwrap_or(20),
-                    css::background(style.background),
-                    style.border_width,
-                    css::color(style.border_color),
-                    style.border_radius,
-                    css::color(self.style_sheet.value_color())
+                    css::color(self.style_sheet.value_color()),
+                    self.inline_style
                 )
                 .into_bump_str(),
             )
@@ -227,6 +265,16 @@ where
     }
 }
 
+fn declarations(style: Style) -> String {
+    format!(
+        "background: {}; border: {}px solid {}; border-radius: {}px",
+        css::background(style.background),
+        style.border_width,
+        css::color(style.border_color),
+        style.border_radius
+    )
+}
+
 /// The state of a [`TextInput`].
 ///
 /// [`TextInput`]: struct.TextInput.html
@@ -3,11 +3,17 @@ mod raster;
 #[cfg(feature = "svg")]
 mod vector;
 
-use crate::Transformation;
-use iced_native::{image, svg, Rectangle};
+use crate::{layer, Transformation};
+use iced_native::Rectangle;
 
 use std::mem;
 
+#[cfg(feature = "image")]
+use iced_native::image;
+
+#[cfg(feature = "svg")]
+use iced_native::svg;
+
 #[cfg(any(feature = "image", feature = "svg"))]
 use std::cell::RefCell;
 
@@ -219,6 +225,14 @@ impl Pipeline {
         memory.dimensions()
     }
 
+    #[cfg(feature = "image")]
+    pub fn load(&self, handle: &image::Handle) -> Option<(u32, u32)> {
+        let mut cache = self.raster_cache.borrow_mut();
+        let memory = cache.load_async(&handle)?;
+
+        Some(memory.dimensions())
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(&self, handle: &svg::Handle) -> (u32, u32) {
         let mut cache = self.vector_cache.borrow_mut();
@@ -231,7 +245,7 @@ impl Pipeline {
         &mut self,
         device: &mut wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
-        instances: &[Image],
+        instances: &[layer::Image],
         transformation: Transformation,
         bounds: Rectangle<u32>,
         target: &wgpu::TextureView,
@@ -256,8 +270,10 @@ impl Pipeline {
         //
         // [1]: https://github.com/nical/guillotiere
         for image in instances {
-            let uploaded_texture = match &image.handle {
-                Handle::Raster(_handle) => {
+            let uploaded_texture = match image {
+                layer::Image::Raster {
+                    handle: _handle, ..
+                } => {
                     #[cfg(feature = "image")]
                     {
                         let mut cache = self.raster_cache.borrow_mut();
@@ -269,14 +285,17 @@ impl Pipeline {
                     #[cfg(not(feature = "image"))]
                     None
                 }
-                Handle::Vector(_handle) => {
+                layer::Image::Vector {
+                    handle: _handle,
+                    bounds: _bounds,
+                } => {
                     #[cfg(feature = "svg")]
                     {
                         let mut cache = self.vector_cache.borrow_mut();
 
                         cache.upload(
                             _handle,
-                            image.scale,
+                            [_bounds.width, _bounds.height],
                             _scale,
                             device,
                             encoder,
@@ -290,11 +309,13 @@ impl Pipeline {
             };
 
             if let Some(texture) = uploaded_texture {
+                let image_bounds = image.bounds();
+
                 let instance_buffer = device
                     .create_buffer_mapped(1, wgpu::BufferUsage::COPY_SRC)
                     .fill_from_slice(&[Instance {
-                        _position: image.position,
-                        _scale: image.scale,
+                        _position: [image_bounds.x, image_bounds.y],
+                        _scale: [image_bounds.width, image_bounds.height],
                     }]);
 
                 encoder.copy_buffer_to_buffer(
@@ -360,17 +381,6 @@ impl Pipeline {
     }
 }
 
-pub struct Image {
-    pub handle: Handle,
-    pub position: [f32; 2],
-    pub scale: [f32; 2],
-}
-
-pub enum Handle {
-    Raster(image::Handle),
-    Vector(svg::Handle),
-}
-
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Vertex {
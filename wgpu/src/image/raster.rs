@@ -1,31 +1,138 @@
+mod loader;
+
+use loader::Loader;
+
 use iced_native::image;
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{HashMap, HashSet, VecDeque},
     rc::Rc,
 };
 
+/// The maximum amount of bytes taken by the images loaded in the background
+/// that are kept around after they stop being displayed.
+const MAX_RETAINED_SIZE: usize = 64 * 1024 * 1024;
+
+pub type Pixels = ::image::ImageBuffer<::image::Bgra<u8>, Vec<u8>>;
+
 #[derive(Debug)]
 pub enum Memory {
-    Host(::image::ImageBuffer<::image::Bgra<u8>, Vec<u8>>),
+    Host(Pixels),
     Device {
         bind_group: Rc<wgpu::BindGroup>,
         width: u32,
         height: u32,
     },
+    Loading,
     NotFound,
     Invalid,
 }
 
+/// The result of decoding some image data.
+#[derive(Debug)]
+pub enum Decoded {
+    Pixels(Pixels),
+    NotFound,
+    Invalid,
+}
+
+impl Decoded {
+    pub fn new(data: &image::Data) -> Decoded {
+        match data {
+            image::Data::Path(path) => {
+                if let Ok(image) = ::image::open(path) {
+                    Decoded::Pixels(image.to_bgra())
+                } else {
+                    Decoded::NotFound
+                }
+            }
+            image::Data::Bytes(bytes) => Decoded::from_bytes(bytes),
+            image::Data::Url(url) => Decoded::fetch(url),
+            image::Data::Pixels {
+                width,
+                height,
+                pixels,
+            } => {
+                if let Some(image) = ::image::ImageBuffer::from_vec(
+                    *width,
+                    *height,
+                    pixels.to_vec(),
+                ) {
+                    Decoded::Pixels(
+                        ::image::DynamicImage::ImageRgba8(image).to_bgra(),
+                    )
+                } else {
+                    Decoded::Invalid
+                }
+            }
+        }
+    }
+}
+
+impl Decoded {
+    fn from_bytes(bytes: &[u8]) -> Decoded {
+        if let Ok(image) = ::image::load_from_memory(bytes) {
+            Decoded::Pixels(image.to_bgra())
+        } else {
+            Decoded::Invalid
+        }
+    }
+
+    #[cfg(feature = "image_url")]
+    fn fetch(url: &str) -> Decoded {
+        use std::io::Read;
+
+        let response = ureq::get(url).call();
+
+        if !response.ok() {
+            return Decoded::NotFound;
+        }
+
+        let mut bytes = Vec::new();
+
+        match response.into_reader().read_to_end(&mut bytes) {
+            Ok(_) => Decoded::from_bytes(&bytes),
+            Err(_) => Decoded::NotFound,
+        }
+    }
+
+    #[cfg(not(feature = "image_url"))]
+    fn fetch(url: &str) -> Decoded {
+        log::warn!(
+            "Loading images from URLs needs the `image_url` feature: {}",
+            url
+        );
+
+        Decoded::NotFound
+    }
+}
+
+impl From<Decoded> for Memory {
+    fn from(decoded: Decoded) -> Memory {
+        match decoded {
+            Decoded::Pixels(pixels) => Memory::Host(pixels),
+            Decoded::NotFound => Memory::NotFound,
+            Decoded::Invalid => Memory::Invalid,
+        }
+    }
+}
+
 impl Memory {
     pub fn dimensions(&self) -> (u32, u32) {
         match self {
             Memory::Host(image) => image.dimensions(),
             Memory::Device { width, height, .. } => (*width, *height),
+            Memory::Loading => (1, 1),
             Memory::NotFound => (1, 1),
             Memory::Invalid => (1, 1),
         }
     }
 
+    pub fn size(&self) -> usize {
+        let (width, height) = self.dimensions();
+
+        width as usize * height as usize * 4
+    }
+
     pub fn upload(
         &mut self,
         device: &wgpu::Device,
@@ -107,6 +214,7 @@ impl Memory {
                 Some(bind_group)
             }
             Memory::Device { bind_group, .. } => Some(bind_group.clone()),
+            Memory::Loading => None,
             Memory::NotFound => None,
             Memory::Invalid => None,
         }
@@ -117,6 +225,9 @@ impl Memory {
 pub struct Cache {
     map: HashMap<u64, Memory>,
     hits: HashSet<u64>,
+    loader: Option<Loader>,
+    asynchronous: HashSet<u64>,
+    retained: VecDeque<(u64, Memory)>,
 }
 
 impl Cache {
@@ -124,6 +235,9 @@ impl Cache {
         Self {
             map: HashMap::new(),
             hits: HashSet::new(),
+            loader: None,
+            asynchronous: HashSet::new(),
+            retained: VecDeque::new(),
         }
     }
 
@@ -132,34 +246,108 @@ impl Cache {
             return self.get(handle).unwrap();
         }
 
-        let memory = match handle.data() {
-            image::Data::Path(path) => {
-                if let Ok(image) = ::image::open(path) {
-                    Memory::Host(image.to_bgra())
-                } else {
-                    Memory::NotFound
-                }
-            }
-            image::Data::Bytes(bytes) => {
-                if let Ok(image) = ::image::load_from_memory(&bytes) {
-                    Memory::Host(image.to_bgra())
-                } else {
-                    Memory::Invalid
-                }
-            }
-        };
+        let memory = Decoded::new(handle.data()).into();
 
         self.insert(handle, memory);
         self.get(handle).unwrap()
     }
 
+    /// Loads the image of the given handle in the background.
+    ///
+    /// It returns `None` while the image is loading.
+    pub fn load_async(
+        &mut self,
+        handle: &image::Handle,
+    ) -> Option<&mut Memory> {
+        self.receive();
+
+        if !self.contains(handle) {
+            let retained = self
+                .retained
+                .iter()
+                .position(|(id, _)| *id == handle.id())
+                .and_then(|i| self.retained.remove(i));
+
+            match retained {
+                Some((_, memory)) => self.insert(handle, memory),
+                None => {
+                    self.loader
+                        .get_or_insert_with(Loader::new)
+                        .request(handle.clone());
+
+                    let _ = self.asynchronous.insert(handle.id());
+                    self.insert(handle, Memory::Loading);
+                }
+            }
+        }
+
+        match self.get(handle).unwrap() {
+            Memory::Loading => None,
+            memory => Some(memory),
+        }
+    }
+
     pub fn trim(&mut self) {
-        let hits = &self.hits;
+        self.receive();
+
+        let unused: Vec<u64> = self
+            .map
+            .keys()
+            .filter(|id| !self.hits.contains(id))
+            .cloned()
+            .collect();
+
+        for id in unused {
+            let memory = self.map.remove(&id).unwrap();
+
+            match memory {
+                // Images being decoded will be ready soon, keep them
+                Memory::Loading => {
+                    let _ = self.map.insert(id, memory);
+                }
+                _ if self.asynchronous.contains(&id) => {
+                    self.retained.push_back((id, memory));
+                }
+                _ => {}
+            }
+        }
+
+        let mut retained_size: usize =
+            self.retained.iter().map(|(_, memory)| memory.size()).sum();
+
+        while retained_size > MAX_RETAINED_SIZE {
+            match self.retained.pop_front() {
+                Some((id, memory)) => {
+                    let _ = self.asynchronous.remove(&id);
+
+                    retained_size -= memory.size();
+                }
+                None => break,
+            }
+        }
 
-        self.map.retain(|k, _| hits.contains(k));
         self.hits.clear();
     }
 
+    /// Stores the images that finished loading in the background.
+    fn receive(&mut self) {
+        let mut is_any_loaded = false;
+
+        if let Some(loader) = &self.loader {
+            for (id, decoded) in loader.finished() {
+                if let Some(memory) = self.map.get_mut(&id) {
+                    *memory = decoded.into();
+
+                    is_any_loaded = true;
+                }
+            }
+        }
+
+        if is_any_loaded {
+            image::notify_loaded();
+        }
+    }
+
     fn get(&mut self, handle: &image::Handle) -> Option<&mut Memory> {
         let _ = self.hits.insert(handle.id());
 
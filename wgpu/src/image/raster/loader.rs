@@ -0,0 +1,57 @@
+use super::Decoded;
+
+use iced_native::image;
+use std::sync::{mpsc, Arc, Mutex};
+
+/// The amount of threads decoding images in the background.
+const WORKERS: usize = 2;
+
+/// A pool of threads decoding images in the background.
+///
+/// The threads stop once the [`Loader`] is dropped.
+///
+/// [`Loader`]: struct.Loader.html
+#[derive(Debug)]
+pub struct Loader {
+    requests: mpsc::Sender<image::Handle>,
+    finished: mpsc::Receiver<(u64, Decoded)>,
+}
+
+impl Loader {
+    pub fn new() -> Self {
+        let (requests, pending) = mpsc::channel::<image::Handle>();
+        let (sender, finished) = mpsc::channel();
+
+        let pending = Arc::new(Mutex::new(pending));
+
+        for _ in 0..WORKERS {
+            let pending = pending.clone();
+            let sender = sender.clone();
+
+            let _ = std::thread::spawn(move || loop {
+                let handle = match pending.lock().unwrap().recv() {
+                    Ok(handle) => handle,
+                    Err(_) => return,
+                };
+
+                let decoded = Decoded::new(handle.data());
+
+                if sender.send((handle.id(), decoded)).is_err() {
+                    return;
+                }
+            });
+        }
+
+        Loader { requests, finished }
+    }
+
+    /// Queues the image of the given handle to be decoded.
+    pub fn request(&self, handle: image::Handle) {
+        let _ = self.requests.send(handle);
+    }
+
+    /// Returns the images decoded since the last call, without blocking.
+    pub fn finished(&self) -> impl Iterator<Item = (u64, Decoded)> + '_ {
+        self.finished.try_iter()
+    }
+}
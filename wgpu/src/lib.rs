@@ -24,33 +24,38 @@
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
-pub mod defaults;
+pub mod offscreen;
 pub mod settings;
 pub mod triangle;
 pub mod widget;
 pub mod window;
 
 mod image;
-mod primitive;
+mod pipeline;
 mod quad;
-mod renderer;
 mod target;
 mod text;
-mod transformation;
-mod viewport;
 
+pub use iced_graphics::{
+    backend, defaults, layer, Backend, Defaults, Layer, Primitive, Viewport,
+};
 pub use wgpu;
 
-pub use defaults::Defaults;
-pub use primitive::Primitive;
-pub use renderer::Renderer;
+pub use pipeline::Pipeline;
 pub use settings::Settings;
 pub use target::Target;
-pub use viewport::Viewport;
+
+/// A [`wgpu`] renderer for [`iced_native`].
+///
+/// It is an [`iced_graphics`] renderer drawn by a wgpu [`Pipeline`].
+///
+/// [`wgpu`]: https://github.com/gfx-rs/wgpu-rs
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+/// [`iced_graphics`]: https://github.com/hecrj/iced/tree/master/graphics
+/// [`Pipeline`]: struct.Pipeline.html
+pub type Renderer = iced_graphics::Renderer<Pipeline>;
 
 #[doc(no_inline)]
 pub use widget::*;
 
-pub(crate) use self::image::Image;
-pub(crate) use quad::Quad;
-pub(crate) use transformation::Transformation;
+pub(crate) use iced_graphics::Transformation;
@@ -0,0 +1,327 @@
+//! Render to images in memory.
+use crate::{Pipeline, Primitive, Renderer, Settings, Target, Viewport};
+
+use iced_native::{window::Overlay, MouseCursor};
+
+use std::fmt;
+
+/// The alignment of the rows of a texture when copying it to a buffer.
+const ROW_ALIGNMENT: u32 = 256;
+
+/// A [`Renderer`] that draws into an image in memory, instead of a window.
+///
+/// It is useful to take screenshots or to compare the output of a view against
+/// reference images in tests.
+///
+/// Rendering an image happens in three steps: [`draw`] records the commands
+/// that render a frame, [`submit`] runs them in the GPU, and [`read`] copies
+/// the resulting pixels back into memory. [`render`] performs the three of
+/// them at once.
+///
+/// The texture of the image is kept between frames, as long as its size does
+/// not change.
+///
+/// [`Renderer`]: ../type.Renderer.html
+/// [`draw`]: #method.draw
+/// [`submit`]: #method.submit
+/// [`read`]: #method.read
+/// [`render`]: #method.render
+#[derive(Debug)]
+pub struct Offscreen {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    renderer: Renderer,
+    image: Option<Image>,
+    encoder: Option<wgpu::CommandEncoder>,
+}
+
+#[derive(Debug)]
+struct Image {
+    width: u32,
+    height: u32,
+    row_pitch: u32,
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    buffer: wgpu::Buffer,
+}
+
+impl Offscreen {
+    /// Creates a new [`Offscreen`] renderer with the given [`Settings`].
+    ///
+    /// The output format of the [`Settings`] is ignored, as pixels are always
+    /// produced in the sRGB color space with RGBA order.
+    ///
+    /// Returns `None` if no graphics adapter is available.
+    ///
+    /// [`Offscreen`]: struct.Offscreen.html
+    /// [`Settings`]: ../settings/struct.Settings.html
+    pub fn new(settings: Settings) -> Option<Self> {
+        let adapter = wgpu::Adapter::request(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::Default,
+            backends: wgpu::BackendBit::all(),
+        })?;
+
+        let (mut device, queue) =
+            adapter.request_device(&wgpu::DeviceDescriptor {
+                extensions: wgpu::Extensions {
+                    anisotropic_filtering: false,
+                },
+                limits: wgpu::Limits { max_bind_groups: 2 },
+            });
+
+        let renderer = Renderer::new(Pipeline::new(
+            &mut device,
+            Settings {
+                format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                ..settings
+            },
+        ));
+
+        Some(Offscreen {
+            device,
+            queue,
+            renderer,
+            image: None,
+            encoder: None,
+        })
+    }
+
+    /// Returns a mutable reference to the [`Renderer`] of the [`Offscreen`]
+    /// renderer.
+    ///
+    /// It should be used to lay out and draw the widgets that will be rendered.
+    ///
+    /// [`Renderer`]: ../type.Renderer.html
+    /// [`Offscreen`]: struct.Offscreen.html
+    pub fn renderer_mut(&mut self) -> &mut Renderer {
+        &mut self.renderer
+    }
+
+    /// Renders the given output in an image of the given physical size, in
+    /// pixels, on top of a white background.
+    ///
+    /// It returns the pixels of the image, row by row, in RGBA order.
+    pub fn render(
+        &mut self,
+        output: &(Primitive, MouseCursor),
+        size: (u32, u32),
+        scale_factor: f64,
+    ) -> Result<Vec<u8>, Error> {
+        self.draw(output, size, scale_factor);
+        self.submit();
+        self.read()
+    }
+
+    /// Records the commands that render the given output in an image of the
+    /// given physical size, in pixels, on top of a white background.
+    ///
+    /// The commands do not run until [`submit`] is called.
+    ///
+    /// [`submit`]: #method.submit
+    pub fn draw(
+        &mut self,
+        output: &(Primitive, MouseCursor),
+        (width, height): (u32, u32),
+        scale_factor: f64,
+    ) {
+        let image = match self.image.take() {
+            Some(image) if image.width == width && image.height == height => {
+                image
+            }
+            _ => Image::new(&self.device, width, height),
+        };
+
+        let viewport = Viewport::new(width, height);
+
+        let mut encoder = self.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor { todo: 0 },
+        );
+
+        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
+                attachment: &image.view,
+                resolve_target: None,
+                load_op: wgpu::LoadOp::Clear,
+                store_op: wgpu::StoreOp::Store,
+                clear_color: wgpu::Color {
+                    r: 1.0,
+                    g: 1.0,
+                    b: 1.0,
+                    a: 1.0,
+                },
+            }],
+            depth_stencil_attachment: None,
+        });
+
+        let device = &mut self.device;
+
+        let _ = self.renderer.draw(
+            output,
+            viewport.dimensions(),
+            &Overlay::default(),
+            |pipeline, layers| {
+                pipeline.draw(
+                    device,
+                    &mut encoder,
+                    Target {
+                        texture: &image.view,
+                        viewport: &viewport,
+                    },
+                    layers,
+                    scale_factor,
+                )
+            },
+        );
+
+        encoder.copy_texture_to_buffer(
+            wgpu::TextureCopyView {
+                texture: &image.texture,
+                array_layer: 0,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                },
+            },
+            wgpu::BufferCopyView {
+                buffer: &image.buffer,
+                offset: 0,
+                row_pitch: image.row_pitch,
+                image_height: height,
+            },
+            image.extent(),
+        );
+
+        self.image = Some(image);
+        self.encoder = Some(encoder);
+    }
+
+    /// Runs the commands recorded by the last call to [`draw`] and waits
+    /// until the GPU finishes them.
+    ///
+    /// [`draw`]: #method.draw
+    pub fn submit(&mut self) {
+        if let Some(encoder) = self.encoder.take() {
+            self.queue.submit(&[encoder.finish()]);
+            self.device.poll(true);
+        }
+    }
+
+    /// Reads the pixels of the last submitted image, row by row, in RGBA
+    /// order.
+    ///
+    /// The amount of bytes returned is always `4 * width * height`.
+    pub fn read(&mut self) -> Result<Vec<u8>, Error> {
+        let image = self.image.as_ref().ok_or(Error::NothingDrawn)?;
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let row_pitch = image.row_pitch as usize;
+        let row_length = (4 * image.width) as usize;
+        let height = image.height as usize;
+
+        image.buffer.map_read_async(
+            0,
+            image.buffer_size(),
+            move |result: wgpu::BufferMapAsyncResult<&[u8]>| {
+                if let Ok(mapping) = result {
+                    let mut pixels = Vec::with_capacity(row_length * height);
+
+                    for row in mapping.data.chunks(row_pitch) {
+                        pixels.extend_from_slice(&row[..row_length]);
+                    }
+
+                    let _ = sender.send(pixels);
+                }
+            },
+        );
+
+        // Block until the buffer is mapped
+        self.device.poll(true);
+
+        image.buffer.unmap();
+
+        receiver.try_recv().map_err(|_| Error::ReadFailed)
+    }
+}
+
+/// An error that occurred while rendering an image with an [`Offscreen`]
+/// renderer.
+///
+/// [`Offscreen`]: struct.Offscreen.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// Pixels were read before drawing any image.
+    NothingDrawn,
+
+    /// The pixels of the image could not be copied back from the GPU.
+    ReadFailed,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NothingDrawn => write!(f, "no offscreen image was drawn"),
+            Error::ReadFailed => {
+                write!(f, "could not read the offscreen image from the GPU")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Image {
+    fn new(device: &wgpu::Device, width: u32, height: u32) -> Image {
+        let row_pitch = {
+            let unpadded = 4 * width;
+            let padding =
+                (ROW_ALIGNMENT - unpadded % ROW_ALIGNMENT) % ROW_ALIGNMENT;
+
+            unpadded + padding
+        };
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth: 1,
+            },
+            array_layer_count: 1,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT
+                | wgpu::TextureUsage::COPY_SRC,
+        });
+
+        let view = texture.create_default_view();
+
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            size: u64::from(row_pitch) * u64::from(height),
+            usage: wgpu::BufferUsage::MAP_READ | wgpu::BufferUsage::COPY_DST,
+        });
+
+        Image {
+            width,
+            height,
+            row_pitch,
+            texture,
+            view,
+            buffer,
+        }
+    }
+
+    fn extent(&self) -> wgpu::Extent3d {
+        wgpu::Extent3d {
+            width: self.width,
+            height: self.height,
+            depth: 1,
+        }
+    }
+
+    fn buffer_size(&self) -> u64 {
+        u64::from(self.row_pitch) * u64::from(self.height)
+    }
+}
@@ -0,0 +1,297 @@
+use crate::{
+    backend, image, quad, text, triangle, Layer, Settings, Target,
+    Transformation,
+};
+use iced_native::{Font, HorizontalAlignment, Size, VerticalAlignment};
+
+/// The [`wgpu`] backend of the [`Renderer`].
+///
+/// [`wgpu`]: https://github.com/gfx-rs/wgpu-rs
+/// [`Renderer`]: type.Renderer.html
+#[derive(Debug)]
+pub struct Pipeline {
+    quad_pipeline: quad::Pipeline,
+    image_pipeline: image::Pipeline,
+    text_pipeline: text::Pipeline,
+    triangle_pipeline: triangle::Pipeline,
+}
+
+impl Pipeline {
+    /// Creates a new [`Pipeline`].
+    ///
+    /// [`Pipeline`]: struct.Pipeline.html
+    pub fn new(device: &mut wgpu::Device, settings: Settings) -> Self {
+        let text_pipeline =
+            text::Pipeline::new(device, settings.format, settings.default_font);
+        let quad_pipeline = quad::Pipeline::new(device, settings.format);
+        let image_pipeline = image::Pipeline::new(device, settings.format);
+        let triangle_pipeline = triangle::Pipeline::new(
+            device,
+            settings.format,
+            settings.antialiasing,
+        );
+
+        Self {
+            quad_pipeline,
+            image_pipeline,
+            text_pipeline,
+            triangle_pipeline,
+        }
+    }
+
+    /// Draws the provided layers in the given [`Target`].
+    ///
+    /// It returns the amount of draw calls issued.
+    ///
+    /// [`Target`]: struct.Target.html
+    pub fn draw(
+        &mut self,
+        device: &mut wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        target: Target<'_>,
+        layers: &[Layer<'_>],
+        scale_factor: f64,
+    ) -> usize {
+        let (width, height) = target.viewport.dimensions();
+        let scale_factor = scale_factor as f32;
+        let transformation = target.viewport.transformation();
+        let mut draw_calls = 0;
+
+        for layer in layers {
+            draw_calls += self.flush(
+                device,
+                scale_factor,
+                transformation,
+                layer,
+                encoder,
+                target.texture,
+                width,
+                height,
+            );
+        }
+
+        self.image_pipeline.trim_cache();
+        self.triangle_pipeline.trim_cache();
+
+        draw_calls
+    }
+
+    fn flush(
+        &mut self,
+        device: &mut wgpu::Device,
+        scale_factor: f32,
+        transformation: Transformation,
+        layer: &Layer<'_>,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        target_width: u32,
+        target_height: u32,
+    ) -> usize {
+        let bounds = layer.bounds * scale_factor;
+        let mut draw_calls = 0;
+
+        if layer.meshes.len() > 0 {
+            draw_calls += layer.meshes.len();
+
+            let translated = transformation
+                * Transformation::scale(scale_factor, scale_factor)
+                * Transformation::translate(
+                    -(layer.offset.x as f32),
+                    -(layer.offset.y as f32),
+                );
+
+            self.triangle_pipeline.draw(
+                device,
+                encoder,
+                target,
+                target_width,
+                target_height,
+                translated,
+                &layer.meshes,
+                bounds,
+            );
+        }
+
+        if layer.quads.len() > 0 {
+            draw_calls += quad::Pipeline::batches(layer.quads.len());
+
+            self.quad_pipeline.draw(
+                device,
+                encoder,
+                &layer.quads,
+                transformation,
+                scale_factor,
+                bounds,
+                target,
+            );
+        }
+
+        if layer.images.len() > 0 {
+            draw_calls += layer.images.len();
+
+            let translated_and_scaled = transformation
+                * Transformation::scale(scale_factor, scale_factor)
+                * Transformation::translate(
+                    -(layer.offset.x as f32),
+                    -(layer.offset.y as f32),
+                );
+
+            self.image_pipeline.draw(
+                device,
+                encoder,
+                &layer.images,
+                translated_and_scaled,
+                bounds,
+                target,
+                scale_factor,
+            );
+        }
+
+        if layer.text.len() > 0 {
+            draw_calls += 1;
+
+            for text in layer.text.iter() {
+                let x = match text.horizontal_alignment {
+                    HorizontalAlignment::Left => text.bounds.x,
+                    HorizontalAlignment::Center => {
+                        text.bounds.x + text.bounds.width / 2.0
+                    }
+                    HorizontalAlignment::Right => {
+                        text.bounds.x + text.bounds.width
+                    }
+                };
+
+                let y = match text.vertical_alignment {
+                    VerticalAlignment::Top => text.bounds.y,
+                    VerticalAlignment::Center => {
+                        text.bounds.y + text.bounds.height / 2.0
+                    }
+                    VerticalAlignment::Bottom => {
+                        text.bounds.y + text.bounds.height
+                    }
+                };
+
+                // Target physical coordinates directly to avoid blurry text
+                let text = wgpu_glyph::Section {
+                    text: text.content,
+                    // TODO: We `round` here to avoid rerasterizing text when
+                    // its position changes slightly. This can make text feel a
+                    // bit "jumpy". We may be able to do better once we improve
+                    // our text rendering/caching pipeline.
+                    screen_position: (
+                        (x * scale_factor).round(),
+                        (y * scale_factor).round(),
+                    ),
+                    // TODO: Fix precision issues with some scale factors.
+                    //
+                    // The `ceil` here can cause some words to render on the
+                    // same line when they should not.
+                    //
+                    // Ideally, `wgpu_glyph` should be able to compute layout
+                    // using logical positions, and then apply the proper
+                    // scaling when rendering. This would ensure that both
+                    // measuring and rendering follow the same layout rules.
+                    bounds: (
+                        (text.bounds.width * scale_factor).ceil(),
+                        (text.bounds.height * scale_factor).ceil(),
+                    ),
+                    scale: wgpu_glyph::Scale {
+                        x: text.size * scale_factor,
+                        y: text.size * scale_factor,
+                    },
+                    color: text.color,
+                    font_id: self.text_pipeline.find_font(text.font),
+                    layout: wgpu_glyph::Layout::default()
+                        .h_align(match text.horizontal_alignment {
+                            HorizontalAlignment::Left => {
+                                wgpu_glyph::HorizontalAlign::Left
+                            }
+                            HorizontalAlignment::Center => {
+                                wgpu_glyph::HorizontalAlign::Center
+                            }
+                            HorizontalAlignment::Right => {
+                                wgpu_glyph::HorizontalAlign::Right
+                            }
+                        })
+                        .v_align(match text.vertical_alignment {
+                            VerticalAlignment::Top => {
+                                wgpu_glyph::VerticalAlign::Top
+                            }
+                            VerticalAlignment::Center => {
+                                wgpu_glyph::VerticalAlign::Center
+                            }
+                            VerticalAlignment::Bottom => {
+                                wgpu_glyph::VerticalAlign::Bottom
+                            }
+                        }),
+                    ..Default::default()
+                };
+
+                self.text_pipeline.queue(text);
+            }
+
+            self.text_pipeline.draw_queued(
+                device,
+                encoder,
+                target,
+                transformation,
+                wgpu_glyph::Region {
+                    x: bounds.x,
+                    y: bounds.y,
+                    width: bounds.width,
+                    height: bounds.height,
+                },
+            );
+        }
+
+        draw_calls
+    }
+}
+
+impl backend::Backend for Pipeline {
+    fn measure(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure(contents, size, font, bounds)
+    }
+
+    fn space_width(&self, size: f32) -> f32 {
+        self.text_pipeline.space_width(size)
+    }
+
+    fn trim_measurements(&mut self) {
+        self.text_pipeline.clear_measurement_cache();
+    }
+
+    fn buffer_sizes(&self) -> Vec<(&'static str, u64)> {
+        vec![
+            ("Quads", self.quad_pipeline.buffer_size()),
+            ("Meshes", self.triangle_pipeline.buffer_size()),
+        ]
+    }
+}
+
+#[cfg(feature = "image")]
+impl backend::Image for Pipeline {
+    fn dimensions(&self, handle: &iced_native::image::Handle) -> (u32, u32) {
+        self.image_pipeline.dimensions(handle)
+    }
+
+    fn load(&self, handle: &iced_native::image::Handle) -> Option<(u32, u32)> {
+        self.image_pipeline.load(handle)
+    }
+}
+
+#[cfg(feature = "svg")]
+impl backend::Svg for Pipeline {
+    fn viewport_dimensions(
+        &self,
+        handle: &iced_native::svg::Handle,
+    ) -> (u32, u32) {
+        self.image_pipeline.viewport_dimensions(handle)
+    }
+}
@@ -1,8 +1,10 @@
-use crate::Transformation;
+use crate::{layer::Quad, Transformation};
 use iced_native::Rectangle;
 
 use std::mem;
 
+const MAX_INSTANCES: usize = 100_000;
+
 #[derive(Debug)]
 pub struct Pipeline {
     pipeline: wgpu::RenderPipeline,
@@ -158,7 +160,7 @@ impl Pipeline {
             .fill_from_slice(&QUAD_INDICES);
 
         let instances = device.create_buffer(&wgpu::BufferDescriptor {
-            size: mem::size_of::<Quad>() as u64 * Quad::MAX as u64,
+            size: mem::size_of::<Quad>() as u64 * MAX_INSTANCES as u64,
             usage: wgpu::BufferUsage::VERTEX | wgpu::BufferUsage::COPY_DST,
         });
 
@@ -200,7 +202,7 @@ impl Pipeline {
         let total = instances.len();
 
         while i < total {
-            let end = (i + Quad::MAX).min(total);
+            let end = (i + MAX_INSTANCES).min(total);
             let amount = end - i;
 
             let instance_buffer = device
@@ -257,9 +259,17 @@ impl Pipeline {
                 );
             }
 
-            i += Quad::MAX;
+            i += MAX_INSTANCES;
         }
     }
+
+    pub fn batches(amount: usize) -> usize {
+        (amount + MAX_INSTANCES - 1) / MAX_INSTANCES
+    }
+
+    pub fn buffer_size(&self) -> u64 {
+        (mem::size_of::<Quad>() * MAX_INSTANCES) as u64
+    }
 }
 
 #[repr(C)]
@@ -285,21 +295,6 @@ const QUAD_VERTS: [Vertex; 4] = [
     },
 ];
 
-#[repr(C)]
-#[derive(Debug, Clone, Copy)]
-pub struct Quad {
-    pub position: [f32; 2],
-    pub scale: [f32; 2],
-    pub color: [f32; 4],
-    pub border_color: [f32; 4],
-    pub border_radius: f32,
-    pub border_width: f32,
-}
-
-impl Quad {
-    const MAX: usize = 100_000;
-}
-
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 struct Uniforms {
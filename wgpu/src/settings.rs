@@ -1,15 +1,15 @@
 //! Configure a [`Renderer`].
 //!
-//! [`Renderer`]: struct.Renderer.html
+//! [`Renderer`]: type.Renderer.html
 
 /// The settings of a [`Renderer`].
 ///
-/// [`Renderer`]: ../struct.Renderer.html
+/// [`Renderer`]: ../type.Renderer.html
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Settings {
     /// The output format of the [`Renderer`].
     ///
-    /// [`Renderer`]: ../struct.Renderer.html
+    /// [`Renderer`]: ../type.Renderer.html
     pub format: wgpu::TextureFormat,
 
     /// The bytes of the font that will be used by default.
@@ -4,13 +4,6 @@ use crate::Transformation;
 
 use std::{cell::RefCell, collections::HashMap};
 
-pub const BUILTIN_ICONS: iced_native::Font = iced_native::Font::External {
-    name: "iced_wgpu icons",
-    bytes: include_bytes!("text/icons.ttf"),
-};
-
-pub const CHECKMARK_ICON: char = '\u{F00C}';
-
 const FALLBACK_FONT: &[u8] = include_bytes!("../fonts/Lato-Regular.ttf");
 
 #[derive(Debug)]
@@ -19,6 +12,57 @@ pub struct Pipeline {
     draw_font_map: RefCell<HashMap<String, wgpu_glyph::FontId>>,
 
     measure_brush: RefCell<glyph_brush::GlyphBrush<'static, ()>>,
+    measurements: RefCell<Measurements>,
+}
+
+/// The measured bounds of some text, keyed by its size, font, and bounds, and
+/// then by its content.
+///
+/// Splitting the key lets us look up measurements with a borrowed content.
+/// Only the measurements used during the last layout are kept.
+#[derive(Debug, Default)]
+struct Measurements {
+    map: HashMap<Key, HashMap<String, Measurement>>,
+}
+
+/// The size, font id, width, and height of some measured text.
+///
+/// Floats are stored as their bits, so they can be hashed and compared.
+type Key = (u32, usize, u32, u32);
+
+#[derive(Debug, Clone, Copy)]
+struct Measurement {
+    bounds: (f32, f32),
+    is_used: bool,
+}
+
+impl Measurements {
+    fn get(&mut self, key: Key, content: &str) -> Option<(f32, f32)> {
+        let measurement = self.map.get_mut(&key)?.get_mut(content)?;
+        measurement.is_used = true;
+
+        Some(measurement.bounds)
+    }
+
+    fn insert(&mut self, key: Key, content: &str, bounds: (f32, f32)) {
+        let _ = self.map.entry(key).or_default().insert(
+            String::from(content),
+            Measurement {
+                bounds,
+                is_used: true,
+            },
+        );
+    }
+
+    fn trim(&mut self) {
+        self.map.retain(|_, measurements| {
+            measurements.retain(|_, measurement| {
+                std::mem::replace(&mut measurement.is_used, false)
+            });
+
+            !measurements.is_empty()
+        });
+    }
 }
 
 impl Pipeline {
@@ -65,6 +109,7 @@ impl Pipeline {
             draw_font_map: RefCell::new(HashMap::new()),
 
             measure_brush: RefCell::new(measure_brush),
+            measurements: RefCell::new(Measurements::default()),
         }
     }
 
@@ -107,6 +152,19 @@ impl Pipeline {
 
         let wgpu_glyph::FontId(font_id) = self.find_font(font);
 
+        let key = (
+            size.to_bits(),
+            font_id,
+            bounds.width.to_bits(),
+            bounds.height.to_bits(),
+        );
+
+        let mut measurements = self.measurements.borrow_mut();
+
+        if let Some(measurement) = measurements.get(key, content) {
+            return measurement;
+        }
+
         let section = wgpu_glyph::Section {
             text: content,
             scale: wgpu_glyph::Scale { x: size, y: size },
@@ -115,13 +173,17 @@ impl Pipeline {
             ..Default::default()
         };
 
-        if let Some(bounds) =
+        let measurement = if let Some(bounds) =
             self.measure_brush.borrow_mut().glyph_bounds(section)
         {
             (bounds.width().ceil(), bounds.height().ceil())
         } else {
             (0.0, 0.0)
-        }
+        };
+
+        measurements.insert(key, content, measurement);
+
+        measurement
     }
 
     pub fn space_width(&self, size: f32) -> f32 {
@@ -160,6 +222,8 @@ impl Pipeline {
                 }
             }
         }
+
+        self.measurements.borrow_mut().trim();
     }
 
     pub fn find_font(&self, font: iced_native::Font) -> wgpu_glyph::FontId {
@@ -186,3 +250,40 @@ impl Pipeline {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key() -> Key {
+        (20.0f32.to_bits(), 0, 0, 0)
+    }
+
+    #[test]
+    fn measurements_are_keyed_by_their_whole_key() {
+        let mut measurements = Measurements::default();
+
+        measurements.insert(key(), "Hello", (40.0, 20.0));
+
+        assert_eq!(measurements.get(key(), "Hello"), Some((40.0, 20.0)));
+        assert_eq!(measurements.get(key(), "World"), None);
+        assert_eq!(measurements.get((0, 0, 0, 0), "Hello"), None);
+    }
+
+    #[test]
+    fn trimming_keeps_only_the_measurements_used_since_the_last_trim() {
+        let mut measurements = Measurements::default();
+
+        // Both measurements are used during the first layout...
+        measurements.insert(key(), "Kept", (30.0, 20.0));
+        measurements.insert(key(), "Evicted", (50.0, 20.0));
+        measurements.trim();
+
+        // ...but only one of them during the second one
+        assert_eq!(measurements.get(key(), "Kept"), Some((30.0, 20.0)));
+        measurements.trim();
+
+        assert_eq!(measurements.get(key(), "Kept"), Some((30.0, 20.0)));
+        assert_eq!(measurements.get(key(), "Evicted"), None);
+    }
+}
@@ -1,21 +1,68 @@
 //! Draw meshes of triangles.
 use crate::{settings, Transformation};
 use iced_native::{Point, Rectangle};
-use std::{mem, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+    sync::Arc,
+};
 
 mod msaa;
 
+pub use iced_graphics::triangle::{Mesh2D, Shader, Vertex2D};
+
 const UNIFORM_BUFFER_SIZE: usize = 100;
 const VERTEX_BUFFER_SIZE: usize = 100_000;
 const INDEX_BUFFER_SIZE: usize = 100_000;
 
+const VERTEX_BUFFER: wgpu::VertexBufferDescriptor<'static> =
+    wgpu::VertexBufferDescriptor {
+        stride: mem::size_of::<Vertex2D>() as u64,
+        step_mode: wgpu::InputStepMode::Vertex,
+        attributes: &[
+            // Position
+            wgpu::VertexAttributeDescriptor {
+                shader_location: 0,
+                format: wgpu::VertexFormat::Float2,
+                offset: 0,
+            },
+            // Color
+            wgpu::VertexAttributeDescriptor {
+                shader_location: 1,
+                format: wgpu::VertexFormat::Float4,
+                offset: 4 * 2,
+            },
+        ],
+    };
+
+const ATTRIBUTE_BUFFER: wgpu::VertexBufferDescriptor<'static> =
+    wgpu::VertexBufferDescriptor {
+        stride: mem::size_of::<[f32; 2]>() as u64,
+        step_mode: wgpu::InputStepMode::Vertex,
+        attributes: &[
+            // Attribute
+            wgpu::VertexAttributeDescriptor {
+                shader_location: 2,
+                format: wgpu::VertexFormat::Float2,
+                offset: 0,
+            },
+        ],
+    };
+
 #[derive(Debug)]
 pub(crate) struct Pipeline {
     pipeline: wgpu::RenderPipeline,
+    custom_pipelines: HashMap<Shader, wgpu::RenderPipeline>,
+    custom_pipeline_hits: HashSet<Shader>,
+    layout: wgpu::PipelineLayout,
+    attribute_module: wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
     blit: Option<msaa::Blit>,
     constants: wgpu::BindGroup,
     uniforms_buffer: Buffer<Uniforms>,
     vertex_buffer: Buffer<Vertex2D>,
+    attribute_buffer: Buffer<[f32; 2]>,
     index_buffer: Buffer<u32>,
 }
 
@@ -56,6 +103,10 @@ impl<T> Buffer<T> {
             self.size = size;
         }
     }
+
+    pub fn byte_size(&self) -> u64 {
+        (std::mem::size_of::<T>() * self.size) as u64
+    }
 }
 
 impl Pipeline {
@@ -108,68 +159,32 @@ impl Pipeline {
                 .expect("Read triangle fragment shader as SPIR-V"),
         );
 
-        let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                layout: &layout,
-                vertex_stage: wgpu::ProgrammableStageDescriptor {
-                    module: &vs_module,
-                    entry_point: "main",
-                },
-                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
-                    module: &fs_module,
-                    entry_point: "main",
-                }),
-                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
-                    front_face: wgpu::FrontFace::Cw,
-                    cull_mode: wgpu::CullMode::None,
-                    depth_bias: 0,
-                    depth_bias_slope_scale: 0.0,
-                    depth_bias_clamp: 0.0,
-                }),
-                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
-                color_states: &[wgpu::ColorStateDescriptor {
-                    format,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    alpha_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    write_mask: wgpu::ColorWrite::ALL,
-                }],
-                depth_stencil_state: None,
-                index_format: wgpu::IndexFormat::Uint32,
-                vertex_buffers: &[wgpu::VertexBufferDescriptor {
-                    stride: mem::size_of::<Vertex2D>() as u64,
-                    step_mode: wgpu::InputStepMode::Vertex,
-                    attributes: &[
-                        // Position
-                        wgpu::VertexAttributeDescriptor {
-                            shader_location: 0,
-                            format: wgpu::VertexFormat::Float2,
-                            offset: 0,
-                        },
-                        // Color
-                        wgpu::VertexAttributeDescriptor {
-                            shader_location: 1,
-                            format: wgpu::VertexFormat::Float4,
-                            offset: 4 * 2,
-                        },
-                    ],
-                }],
-                sample_count: antialiasing
-                    .map(|a| a.sample_count())
-                    .unwrap_or(1),
-                sample_mask: !0,
-                alpha_to_coverage_enabled: false,
-            });
+        let attribute_vs = include_bytes!("shader/triangle_attribute.vert.spv");
+        let attribute_module = device.create_shader_module(
+            &wgpu::read_spirv(std::io::Cursor::new(&attribute_vs[..]))
+                .expect("Read triangle attribute vertex shader as SPIR-V"),
+        );
+
+        let sample_count = antialiasing.map(|a| a.sample_count()).unwrap_or(1);
+
+        let pipeline = build_pipeline(
+            device,
+            &layout,
+            &vs_module,
+            &fs_module,
+            format,
+            sample_count,
+            &[VERTEX_BUFFER],
+        );
 
         Pipeline {
             pipeline,
+            custom_pipelines: HashMap::new(),
+            custom_pipeline_hits: HashSet::new(),
+            layout,
+            attribute_module,
+            format,
+            sample_count,
             blit: antialiasing.map(|a| msaa::Blit::new(device, format, a)),
             constants: constant_bind_group,
             uniforms_buffer: constants_buffer,
@@ -178,6 +193,11 @@ impl Pipeline {
                 VERTEX_BUFFER_SIZE,
                 wgpu::BufferUsage::VERTEX | wgpu::BufferUsage::COPY_DST,
             ),
+            attribute_buffer: Buffer::new(
+                device,
+                VERTEX_BUFFER_SIZE,
+                wgpu::BufferUsage::VERTEX | wgpu::BufferUsage::COPY_DST,
+            ),
             index_buffer: Buffer::new(
                 device,
                 INDEX_BUFFER_SIZE,
@@ -207,19 +227,36 @@ impl Pipeline {
                 (total_v + v, total_i + i)
             });
 
+        let total_attributes = meshes
+            .iter()
+            .filter(|(_, mesh)| mesh.shader().is_some())
+            .map(|(_, mesh)| mesh.vertices.len())
+            .sum();
+
         // Then we ensure the current buffers are big enough, resizing if
         // necessary
         self.uniforms_buffer.ensure_capacity(device, meshes.len());
         self.vertex_buffer.ensure_capacity(device, total_vertices);
+        self.attribute_buffer
+            .ensure_capacity(device, total_attributes);
         self.index_buffer.ensure_capacity(device, total_indices);
 
+        // Meshes with a custom shader need their own pipeline
+        for (_, mesh) in meshes {
+            if let Some(shader) = mesh.shader() {
+                self.prepare_shader(device, shader);
+            }
+        }
+
         let mut uniforms: Vec<Uniforms> = Vec::with_capacity(meshes.len());
         let mut offsets: Vec<(
             wgpu::BufferAddress,
             wgpu::BufferAddress,
             usize,
+            Option<(&Shader, wgpu::BufferAddress)>,
         )> = Vec::with_capacity(meshes.len());
         let mut last_vertex = 0;
+        let mut last_attribute = 0;
         let mut last_index = 0;
 
         // We upload everything upfront
@@ -260,11 +297,42 @@ impl Pipeline {
                 (std::mem::size_of::<u32>() * mesh.indices.len()) as u64,
             );
 
+            let attributes = if let Some(shader) = mesh.shader() {
+                let attribute_offset =
+                    (std::mem::size_of::<[f32; 2]>() * last_attribute) as u64;
+
+                // Missing attributes default to zero
+                let mut attributes = mesh.attributes().to_vec();
+                attributes.resize(mesh.vertices.len(), [0.0, 0.0]);
+
+                let attribute_buffer = device
+                    .create_buffer_mapped(
+                        attributes.len(),
+                        wgpu::BufferUsage::COPY_SRC,
+                    )
+                    .fill_from_slice(&attributes);
+
+                encoder.copy_buffer_to_buffer(
+                    &attribute_buffer,
+                    0,
+                    &self.attribute_buffer.raw,
+                    attribute_offset,
+                    (std::mem::size_of::<[f32; 2]>() * attributes.len()) as u64,
+                );
+
+                last_attribute += attributes.len();
+
+                Some((shader, attribute_offset))
+            } else {
+                None
+            };
+
             uniforms.push(transform);
             offsets.push((
                 last_vertex as u64,
                 last_index as u64,
                 mesh.indices.len(),
+                attributes,
             ));
 
             last_vertex += mesh.vertices.len();
@@ -313,10 +381,30 @@ impl Pipeline {
                     depth_stencil_attachment: None,
                 });
 
-            for (i, (vertex_offset, index_offset, indices)) in
+            for (i, (vertex_offset, index_offset, indices, attributes)) in
                 offsets.drain(..).enumerate()
             {
-                render_pass.set_pipeline(&self.pipeline);
+                match attributes {
+                    Some((shader, attribute_offset)) => {
+                        render_pass
+                            .set_pipeline(&self.custom_pipelines[shader]);
+                        render_pass.set_vertex_buffers(
+                            0,
+                            &[
+                                (&self.vertex_buffer.raw, vertex_offset),
+                                (&self.attribute_buffer.raw, attribute_offset),
+                            ],
+                        );
+                    }
+                    None => {
+                        render_pass.set_pipeline(&self.pipeline);
+                        render_pass.set_vertex_buffers(
+                            0,
+                            &[(&self.vertex_buffer.raw, vertex_offset)],
+                        );
+                    }
+                }
+
                 render_pass.set_bind_group(
                     0,
                     &self.constants,
@@ -324,10 +412,6 @@ impl Pipeline {
                 );
                 render_pass
                     .set_index_buffer(&self.index_buffer.raw, index_offset);
-                render_pass.set_vertex_buffers(
-                    0,
-                    &[(&self.vertex_buffer.raw, vertex_offset)],
-                );
                 render_pass.set_scissor_rect(
                     bounds.x,
                     bounds.y,
@@ -343,6 +427,93 @@ impl Pipeline {
             blit.draw(encoder, target);
         }
     }
+
+    pub fn buffer_size(&self) -> u64 {
+        self.uniforms_buffer.byte_size()
+            + self.vertex_buffer.byte_size()
+            + self.attribute_buffer.byte_size()
+            + self.index_buffer.byte_size()
+    }
+
+    pub fn trim_cache(&mut self) {
+        let hits = &self.custom_pipeline_hits;
+
+        self.custom_pipelines
+            .retain(|shader, _| hits.contains(shader));
+        self.custom_pipeline_hits.clear();
+    }
+
+    fn prepare_shader(&mut self, device: &wgpu::Device, shader: &Shader) {
+        let _ = self.custom_pipeline_hits.insert(shader.clone());
+
+        if self.custom_pipelines.contains_key(shader) {
+            return;
+        }
+
+        let fs_module = device.create_shader_module(shader.spirv());
+
+        let pipeline = build_pipeline(
+            device,
+            &self.layout,
+            &self.attribute_module,
+            &fs_module,
+            self.format,
+            self.sample_count,
+            &[VERTEX_BUFFER, ATTRIBUTE_BUFFER],
+        );
+
+        let _ = self.custom_pipelines.insert(shader.clone(), pipeline);
+    }
+}
+
+fn build_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    vs_module: &wgpu::ShaderModule,
+    fs_module: &wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    vertex_buffers: &[wgpu::VertexBufferDescriptor<'_>],
+) -> wgpu::RenderPipeline {
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        layout,
+        vertex_stage: wgpu::ProgrammableStageDescriptor {
+            module: vs_module,
+            entry_point: "main",
+        },
+        fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+            module: fs_module,
+            entry_point: "main",
+        }),
+        rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+            front_face: wgpu::FrontFace::Cw,
+            cull_mode: wgpu::CullMode::None,
+            depth_bias: 0,
+            depth_bias_slope_scale: 0.0,
+            depth_bias_clamp: 0.0,
+        }),
+        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+        color_states: &[wgpu::ColorStateDescriptor {
+            format,
+            color_blend: wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::SrcAlpha,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+            alpha_blend: wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+            write_mask: wgpu::ColorWrite::ALL,
+        }],
+        depth_stencil_state: None,
+        index_format: wgpu::IndexFormat::Uint32,
+        vertex_buffers,
+        sample_count,
+        sample_mask: !0,
+        alpha_to_coverage_enabled: false,
+    })
 }
 
 #[repr(C)]
@@ -358,26 +529,3 @@ impl Default for Uniforms {
         }
     }
 }
-
-/// A two-dimensional vertex with some color in __linear__ RGBA.
-#[repr(C)]
-#[derive(Copy, Clone, Debug)]
-pub struct Vertex2D {
-    /// The vertex position
-    pub position: [f32; 2],
-    /// The vertex color in __linear__ RGBA.
-    pub color: [f32; 4],
-}
-
-/// A set of [`Vertex2D`] and indices representing a list of triangles.
-///
-/// [`Vertex2D`]: struct.Vertex2D.html
-#[derive(Clone, Debug)]
-pub struct Mesh2D {
-    /// The vertices of the mesh
-    pub vertices: Vec<Vertex2D>,
-    /// The list of vertex indices that defines the triangles of the mesh.
-    ///
-    /// Therefore, this list should always have a length that is a multiple of 3.
-    pub indices: Vec<u32>,
-}
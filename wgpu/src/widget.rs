@@ -12,6 +12,7 @@ pub mod checkbox;
 pub mod container;
 pub mod progress_bar;
 pub mod radio;
+pub mod radio_group;
 pub mod scrollable;
 pub mod slider;
 pub mod text_input;
@@ -27,6 +28,8 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use radio_group::RadioGroup;
+#[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
@@ -1,9 +1,19 @@
 //! Show toggle controls using checkboxes.
 use crate::Renderer;
 
+pub use iced_native::checkbox::State;
 pub use iced_style::checkbox::{Style, StyleSheet};
 
 /// A box that can be checked.
 ///
 /// This is an alias of an `iced_native` checkbox with an `iced_wgpu::Renderer`.
 pub type Checkbox<Message> = iced_native::Checkbox<Message, Renderer>;
+
+/// A [`Checkbox`] that can be focused and toggled with the keyboard.
+///
+/// This is an alias of an `iced_native` focusable checkbox with an
+/// `iced_wgpu::Renderer`.
+///
+/// [`Checkbox`]: type.Checkbox.html
+pub type Focusable<'a, Message> =
+    iced_native::checkbox::Focusable<'a, Message, Renderer>;
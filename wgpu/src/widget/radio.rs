@@ -1,6 +1,7 @@
 //! Create choices using radio buttons.
 use crate::Renderer;
 
+pub use iced_native::radio::State;
 pub use iced_style::radio::{Style, StyleSheet};
 
 /// A circular button representing a choice.
@@ -8,3 +9,12 @@ pub use iced_style::radio::{Style, StyleSheet};
 /// This is an alias of an `iced_native` radio button with an
 /// `iced_wgpu::Renderer`.
 pub type Radio<Message> = iced_native::Radio<Message, Renderer>;
+
+/// A [`Radio`] button that can be focused and selected with the keyboard.
+///
+/// This is an alias of an `iced_native` focusable radio button with an
+/// `iced_wgpu::Renderer`.
+///
+/// [`Radio`]: type.Radio.html
+pub type Focusable<'a, Message> =
+    iced_native::radio::Focusable<'a, Message, Renderer>;
@@ -0,0 +1,11 @@
+//! Let users choose a value out of a set of options.
+use crate::Renderer;
+
+pub use iced_native::radio_group::{Direction, State};
+
+/// A set of radio buttons representing the options of a single choice.
+///
+/// This is an alias of an `iced_native` radio group with an
+/// `iced_wgpu::Renderer`.
+pub type RadioGroup<'a, T, Message> =
+    iced_native::RadioGroup<'a, T, Message, Renderer>;
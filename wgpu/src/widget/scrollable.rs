@@ -1,8 +1,10 @@
 //! Navigate an endless amount of content with a scrollbar.
 use crate::Renderer;
 
-pub use iced_native::scrollable::State;
-pub use iced_style::scrollable::{Scrollbar, Scroller, StyleSheet};
+pub use iced_native::scrollable::{State, Visibility};
+pub use iced_style::scrollable::{
+    Scrollbar, Scroller, StyleSheet, DEFAULT_MARGIN, DEFAULT_WIDTH,
+};
 
 /// A widget that can vertically display an infinite amount of content
 /// with a scrollbar.
@@ -1,6 +1,9 @@
-use crate::{window::SwapChain, Renderer, Settings, Target};
+use crate::{window::SwapChain, Pipeline, Renderer, Settings, Target};
 
-use iced_native::MouseCursor;
+use iced_native::{
+    window::{Overlay, Statistics},
+    Element, MouseCursor,
+};
 use raw_window_handle::HasRawWindowHandle;
 
 /// A window graphics backend for iced powered by `wgpu`.
@@ -36,7 +39,7 @@ impl iced_native::window::Backend for Backend {
                 limits: wgpu::Limits { max_bind_groups: 2 },
             });
 
-        let renderer = Renderer::new(&mut device, settings);
+        let renderer = Renderer::new(Pipeline::new(&mut device, settings));
 
         (
             Backend {
@@ -64,13 +67,13 @@ impl iced_native::window::Backend for Backend {
         SwapChain::new(&self.device, surface, self.format, width, height)
     }
 
-    fn draw<T: AsRef<str>>(
+    fn draw(
         &mut self,
         renderer: &mut Self::Renderer,
         swap_chain: &mut SwapChain,
         output: &<Self::Renderer as iced_native::Renderer>::Output,
         scale_factor: f64,
-        overlay: &[T],
+        overlay: &Overlay,
     ) -> MouseCursor {
         let (frame, viewport) = swap_chain.next_frame();
 
@@ -94,20 +97,45 @@ impl iced_native::window::Backend for Backend {
             depth_stencil_attachment: None,
         });
 
+        let device = &mut self.device;
+
         let mouse_cursor = renderer.draw(
-            &mut self.device,
-            &mut encoder,
-            Target {
-                texture: &frame.view,
-                viewport,
-            },
             output,
-            scale_factor,
+            viewport.dimensions(),
             overlay,
+            |pipeline, layers| {
+                pipeline.draw(
+                    device,
+                    &mut encoder,
+                    Target {
+                        texture: &frame.view,
+                        viewport,
+                    },
+                    layers,
+                    scale_factor,
+                )
+            },
         );
 
-        self.queue.submit(&[encoder.finish()]);
+        {
+            #[cfg(feature = "trace")]
+            let span = tracing::info_span!("submit");
+            #[cfg(feature = "trace")]
+            let _guard = span.enter();
+
+            self.queue.submit(&[encoder.finish()]);
+        }
 
         mouse_cursor
     }
+
+    fn statistics(&self, renderer: &Renderer) -> Statistics {
+        renderer.statistics().clone()
+    }
+
+    fn inspect<'a, Message: 'a>(
+        element: Element<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        element.inspect()
+    }
 }
@@ -1,7 +1,12 @@
 use crate::{
-    conversion, size::Size, window, Cache, Clipboard, Command, Debug, Element,
-    Executor, Mode, MouseCursor, Proxy, Runtime, Settings, Subscription,
-    UserInterface,
+    conversion,
+    input::{keyboard, ButtonState},
+    size::Size,
+    sleep, window,
+    window_state::WindowState,
+    Cache, Clipboard, Command, Debug, Element, Event, Executor, Mode,
+    MouseCursor, Proxy, Redraw, Runtime, Settings, Subscription,
+    TaskbarProgress, UserInterface,
 };
 
 /// An interactive, native cross-platform application.
@@ -90,6 +95,189 @@ pub trait Application: Sized {
         Mode::Windowed
     }
 
+    /// Returns the current [`Redraw`] strategy of the [`Application`].
+    ///
+    /// The runtime will adapt its event loop when a new strategy is returned.
+    /// For instance, you can redraw continuously while a game is running and
+    /// go back to idling when it is paused.
+    ///
+    /// By default, an application redraws on demand.
+    ///
+    /// [`Redraw`]: enum.Redraw.html
+    /// [`Application`]: trait.Application.html
+    fn redraw(&self) -> Redraw {
+        Redraw::OnDemand
+    }
+
+    /// Returns whether the cursor should be grabbed by the window of the
+    /// [`Application`].
+    ///
+    /// A grabbed cursor is confined to the window and cannot leave it. This is
+    /// normally combined with [`cursor_visible`] and the raw mouse motion
+    /// events to implement mouse-look.
+    ///
+    /// The runtime will automatically grab or release the cursor when the
+    /// returned value changes.
+    ///
+    /// By default, the cursor is not grabbed.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`cursor_visible`]: #method.cursor_visible
+    fn cursor_grabbed(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the cursor should be visible when it is over the window
+    /// of the [`Application`].
+    ///
+    /// By default, the cursor is visible.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn cursor_visible(&self) -> bool {
+        true
+    }
+
+    /// Returns whether the window of the [`Application`] should be visible.
+    ///
+    /// The runtime checks this method right after the [`Application`] is
+    /// created and after every update, and shows or hides the window when the
+    /// returned value differs from the current visibility of the window. This
+    /// can be combined with a hidden window in the [`Settings`] to show the
+    /// window only once the application has finished loading.
+    ///
+    /// By default, it returns `None` and the window keeps the visibility
+    /// given in the [`Settings`].
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`Settings`]: settings/struct.Window.html
+    fn visible(&self) -> Option<bool> {
+        None
+    }
+
+    /// Returns whether the window of the [`Application`] should stay on top of
+    /// other windows.
+    ///
+    /// The runtime will automatically pin or unpin the window when the
+    /// returned value changes.
+    ///
+    /// By default, the window is not kept on top.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn always_on_top(&self) -> bool {
+        false
+    }
+
+    /// Returns the index of the monitor that the window of the [`Application`]
+    /// should target.
+    ///
+    /// The available monitors are notified with a
+    /// [`window::Event::MonitorsDetected`] when the application starts.
+    ///
+    /// The runtime will center the window on the chosen monitor, which will
+    /// also be used when the application enters fullscreen mode.
+    ///
+    /// By default, it returns `None` and the window will use the monitor
+    /// chosen by the system.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`window::Event::MonitorsDetected`]: window/enum.Event.html#variant.MonitorsDetected
+    fn monitor(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns whether the window of the [`Application`] should be minimized.
+    ///
+    /// The runtime will automatically minimize or restore the window when the
+    /// returned value changes. The user may still restore the window at any
+    /// time, so you may want to listen to window events to keep your state in
+    /// sync.
+    ///
+    /// By default, the window is not minimized.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn minimized(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the window of the [`Application`] should be maximized.
+    ///
+    /// The runtime will automatically maximize or restore the window when the
+    /// returned value changes.
+    ///
+    /// By default, the window is not maximized.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn maximized(&self) -> bool {
+        false
+    }
+
+    /// Returns the progress of the current long-running operation of the
+    /// [`Application`], if any.
+    ///
+    /// The progress will be shown in the taskbar icon of the application.
+    /// The runtime will automatically update it when the returned value
+    /// changes.
+    ///
+    /// On Windows, the progress is shown in the taskbar button of the window.
+    /// On Linux and BSD, it is shown in the launcher entry of the
+    /// application, using the Unity launcher API, when the `launcher` feature
+    /// is enabled. This requires setting an application id in the platform
+    /// specific settings of the window, which must match the name of the
+    /// `.desktop` file of the application.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn taskbar_progress(&self) -> Option<TaskbarProgress> {
+        None
+    }
+
+    /// Returns whether the display should be prevented from going to sleep
+    /// while the [`Application`] is running.
+    ///
+    /// This is useful while playing a video or running a long job. The
+    /// runtime will automatically inhibit or allow sleep when the returned
+    /// value changes.
+    ///
+    /// By default, sleep is not inhibited.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn inhibit_sleep(&self) -> bool {
+        false
+    }
+
+    /// Returns the exit code of the [`Application`], if it should be
+    /// terminated.
+    ///
+    /// Once an exit code is returned, the runtime will close every active
+    /// subscription, stop the event loop, and terminate the process with the
+    /// given code.
+    ///
+    /// The event loop of `winit` 0.21 never returns, so the process is
+    /// terminated with [`std::process::exit`] right after the window closes.
+    /// The [`Application`] and its runtime are dropped before that happens,
+    /// but the window and the renderer are not.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`std::process::exit`]: https://doc.rust-lang.org/std/process/fn.exit.html
+    fn exit_code(&self) -> Option<i32> {
+        None
+    }
+
+    /// Handles the creation of the window of the [`Application`].
+    ///
+    /// The given [`RawWindowHandle`] targets the same native surface that
+    /// iced draws to. You can keep it around to let external renderers, video
+    /// decoders, or native dialogs interact with the window.
+    ///
+    /// By default, this method does nothing.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`RawWindowHandle`]: window/enum.RawWindowHandle.html
+    fn window_created(&mut self, _handle: window::RawWindowHandle) {}
+
     /// Runs the [`Application`].
     ///
     /// This method will take control of the current thread and __will NOT
@@ -104,7 +292,7 @@ pub trait Application: Sized {
     ) where
         Self: 'static,
     {
-        use window::Backend as _;
+        use window::{Backend as _, HasRawWindowHandle as _};
         use winit::{
             event::{self, WindowEvent},
             event_loop::{ControlFlow, EventLoop},
@@ -131,6 +319,17 @@ pub trait Application: Sized {
 
         let mut title = application.title();
         let mut mode = application.mode();
+        let mut redraw = application.redraw();
+        let mut cursor_grabbed = application.cursor_grabbed();
+        let mut cursor_visible = application.cursor_visible();
+        let mut minimized = application.minimized();
+        let mut maximized = application.maximized();
+        let mut monitor = application.monitor();
+        let mut visible =
+            application.visible().unwrap_or(settings.window.visible);
+        let mut always_on_top = application.always_on_top();
+        let mut taskbar_progress = application.taskbar_progress();
+        let mut inhibit_sleep = application.inhibit_sleep();
 
         let window = {
             let mut window_builder = WindowBuilder::new();
@@ -141,9 +340,16 @@ pub trait Application: Sized {
                 .with_title(&title)
                 .with_inner_size(winit::dpi::LogicalSize { width, height })
                 .with_resizable(settings.window.resizable)
+                .with_visible(visible)
+                .with_always_on_top(always_on_top)
+                .with_maximized(maximized)
                 .with_decorations(settings.window.decorations)
                 .with_fullscreen(conversion::fullscreen(
-                    event_loop.primary_monitor(),
+                    target_monitor(
+                        event_loop.available_monitors(),
+                        monitor,
+                        event_loop.primary_monitor(),
+                    ),
                     mode,
                 ));
 
@@ -156,9 +362,117 @@ pub trait Application: Sized {
                 }
             }
 
+            #[cfg(all(
+                feature = "launcher",
+                any(
+                    target_os = "linux",
+                    target_os = "dragonfly",
+                    target_os = "freebsd",
+                    target_os = "netbsd",
+                    target_os = "openbsd"
+                )
+            ))]
+            {
+                use winit::platform::unix::WindowBuilderExtUnix;
+
+                let platform_specific = settings.window.platform_specific;
+
+                if let Some(application_id) = platform_specific.application_id {
+                    window_builder = window_builder
+                        .with_app_id(String::from(application_id));
+                }
+
+                if let Some((class, instance)) = platform_specific.class {
+                    window_builder = window_builder.with_class(
+                        String::from(class),
+                        String::from(instance),
+                    );
+                }
+            }
+
+            #[cfg(target_os = "macos")]
+            {
+                use winit::platform::macos::WindowBuilderExtMacOS;
+
+                let platform_specific = settings.window.platform_specific;
+
+                window_builder = window_builder
+                    .with_title_hidden(platform_specific.title_hidden)
+                    .with_titlebar_transparent(
+                        platform_specific.titlebar_transparent,
+                    )
+                    .with_fullsize_content_view(
+                        platform_specific.fullsize_content_view,
+                    );
+            }
+
             window_builder.build(&event_loop).expect("Open window")
         };
 
+        if cursor_grabbed {
+            if let Err(error) = window.set_cursor_grab(true) {
+                log::warn!("Could not grab cursor: {}", error);
+            }
+        }
+
+        window.set_cursor_visible(cursor_visible);
+
+        if minimized {
+            window.set_minimized(true);
+        }
+
+        if monitor.is_some() {
+            center_window(&window, monitor);
+        }
+
+        #[cfg(target_os = "windows")]
+        let taskbar = crate::taskbar::Taskbar::new();
+
+        #[cfg(target_os = "windows")]
+        {
+            if let Some(taskbar) = &taskbar {
+                taskbar.set_progress(&window, taskbar_progress);
+            }
+        }
+
+        #[cfg(all(
+            feature = "launcher",
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            )
+        ))]
+        let launcher = crate::launcher::Launcher::new(
+            settings.window.platform_specific.application_id,
+        );
+
+        #[cfg(all(
+            feature = "launcher",
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            )
+        ))]
+        {
+            if let Some(launcher) = &launcher {
+                launcher.set_progress(taskbar_progress);
+            }
+        }
+
+        let mut sleep_inhibitor = sleep::Inhibitor::default();
+
+        if inhibit_sleep {
+            sleep_inhibitor.set(&window, true);
+        }
+
+        application.window_created(window.raw_window_handle());
+
         let mut size = Size::new(window.inner_size(), window.scale_factor());
         let mut resized = false;
 
@@ -189,212 +503,590 @@ pub trait Application: Sized {
         let mut primitive = user_interface.draw(&mut renderer);
         debug.draw_finished();
 
+        let mut next_redraw = user_interface.next_redraw();
+        let mut force_redraw = false;
         let mut cache = Some(user_interface.into_cache());
-        let mut events = Vec::new();
+        let mut events = vec![Event::Window(window::Event::MonitorsDetected(
+            window
+                .available_monitors()
+                .map(|monitor| conversion::monitor(&monitor))
+                .collect(),
+        ))];
         let mut mouse_cursor = MouseCursor::OutOfBounds;
         let mut modifiers = winit::event::ModifiersState::default();
+        let mut focused = true;
+        let mut window_state = WindowState::new(minimized, maximized);
+        let mut pressed_keys = std::collections::HashSet::new();
+        let mut exit_code = 0;
+
+        #[cfg(feature = "record")]
+        let mut recorder = std::env::var_os(crate::recording::RECORD_VARIABLE)
+            .and_then(|path| {
+                crate::recording::Recorder::create(&path)
+                    .map_err(|error| {
+                        log::error!(
+                            "Could not record events to {:?}: {}",
+                            path,
+                            error
+                        )
+                    })
+                    .ok()
+            });
+
+        #[cfg(feature = "record")]
+        let mut replay = std::env::var_os(crate::recording::REPLAY_VARIABLE)
+            .and_then(|path| {
+                crate::recording::Replay::load(&path)
+                    .map_err(|error| {
+                        log::error!(
+                            "Could not replay events from {:?}: {}",
+                            path,
+                            error
+                        )
+                    })
+                    .ok()
+            });
+
+        #[cfg(feature = "record")]
+        {
+            if let Some(replay) = &replay {
+                let _ = crate::time::set_clock(replay.clock().clone());
+            }
+        }
+
         debug.startup_finished();
 
         window.request_redraw();
 
-        event_loop.run(move |event, _, control_flow| match event {
-            event::Event::MainEventsCleared => {
-                if events.is_empty() && external_messages.is_empty() {
-                    return;
-                }
+        // The event loop never returns, so it takes ownership of the
+        // application and the runtime. They are dropped explicitly before
+        // the process exits.
+        let mut running = Some((application, runtime));
+
+        event_loop.run(move |event, _, control_flow| {
+            let (application, runtime) = match &mut running {
+                Some((application, runtime)) => (application, runtime),
+                None => return,
+            };
+
+            match event {
+                event::Event::NewEvents(cause) => match cause {
+                    event::StartCause::ResumeTimeReached {
+                        requested_resume,
+                        ..
+                    } => {
+                        #[cfg(feature = "record")]
+                        {
+                            if let Some(replay) = &replay {
+                                replay.clock().advance_to(requested_resume);
+                            }
+                        }
+
+                        #[cfg(not(feature = "record"))]
+                        let _ = requested_resume;
+
+                        force_redraw = true;
+                    }
+                    event::StartCause::Poll => {
+                        force_redraw = true;
+                    }
+                    _ => {}
+                },
+                event::Event::MainEventsCleared => {
+                    #[cfg(feature = "record")]
+                    {
+                        if let Some(recorder) = &mut recorder {
+                            if let Err(error) = recorder.record(&events) {
+                                log::error!(
+                                    "Failed to record events: {}",
+                                    error
+                                );
+                            }
+                        }
+
+                        if let Some(replay) = &mut replay {
+                            replay.drain_due(&mut events);
+                        }
+                    }
 
-                // TODO: We should be able to keep a user interface alive
-                // between events once we remove state references.
-                //
-                // This will allow us to rebuild it only when a message is
-                // handled.
-                let mut user_interface = build_user_interface(
-                    &mut application,
-                    cache.take().unwrap(),
-                    &mut renderer,
-                    size.logical(),
-                    &mut debug,
-                );
-
-                debug.event_processing_started();
-                events
-                    .iter()
-                    .cloned()
-                    .for_each(|event| runtime.broadcast(event));
-
-                let mut messages = user_interface.update(
-                    events.drain(..),
-                    clipboard
-                        .as_ref()
-                        .map(|c| c as &dyn iced_native::Clipboard),
-                    &renderer,
-                );
-                messages.extend(external_messages.drain(..));
-                debug.event_processing_finished();
-
-                if messages.is_empty() {
-                    debug.draw_started();
-                    primitive = user_interface.draw(&mut renderer);
-                    debug.draw_finished();
-
-                    cache = Some(user_interface.into_cache());
-                } else {
-                    // When there are messages, we are forced to rebuild twice
-                    // for now :^)
-                    let temp_cache = user_interface.into_cache();
-
-                    for message in messages {
-                        log::debug!("Updating");
-
-                        debug.log_message(&message);
-
-                        debug.update_started();
-                        let command =
-                            runtime.enter(|| application.update(message));
-                        runtime.spawn(command);
-                        debug.update_finished();
+                    if events.is_empty()
+                        && external_messages.is_empty()
+                        && !force_redraw
+                    {
+                        return;
                     }
 
-                    let subscription = application.subscription();
-                    runtime.track(subscription);
+                    force_redraw = false;
+
+                    #[cfg(feature = "trace")]
+                    let span = tracing::info_span!("frame");
+                    #[cfg(feature = "trace")]
+                    let _guard = span.enter();
+
+                    // TODO: We should be able to keep a user interface alive
+                    // between events once we remove state references.
+                    //
+                    // This will allow us to rebuild it only when a message is
+                    // handled.
+                    let mut user_interface = build_user_interface(
+                        application,
+                        cache.take().unwrap(),
+                        &mut renderer,
+                        size.logical(),
+                        &mut debug,
+                    );
 
-                    // Update window title
-                    let new_title = application.title();
+                    debug.event_processing_started();
+                    events
+                        .iter()
+                        .cloned()
+                        .for_each(|event| runtime.broadcast(event));
+
+                    let mut messages = user_interface.update(
+                        events.drain(..),
+                        clipboard
+                            .as_ref()
+                            .map(|c| c as &dyn iced_native::Clipboard),
+                        &renderer,
+                    );
+                    messages.append(&mut external_messages);
+                    debug.event_processing_finished();
 
-                    if title != new_title {
-                        window.set_title(&new_title);
+                    if messages.is_empty() {
+                        debug.draw_started();
+                        primitive = user_interface.draw(&mut renderer);
+                        debug.draw_finished();
 
-                        title = new_title;
-                    }
+                        next_redraw = user_interface.next_redraw();
+                        cache = Some(user_interface.into_cache());
+                    } else {
+                        // When there are messages, we are forced to rebuild twice
+                        // for now :^)
+                        let temp_cache = user_interface.into_cache();
 
-                    // Update window mode
-                    let new_mode = application.mode();
+                        for message in messages {
+                            log::debug!("Updating");
 
-                    if mode != new_mode {
-                        window.set_fullscreen(conversion::fullscreen(
-                            window.current_monitor(),
-                            new_mode,
-                        ));
+                            debug.log_message(&message);
+
+                            debug.update_started();
+                            #[cfg(feature = "trace")]
+                            let span = tracing::info_span!("update");
+                            #[cfg(feature = "trace")]
+                            let _guard = span.enter();
 
-                        mode = new_mode;
+                            let command =
+                                runtime.enter(|| application.update(message));
+                            runtime.spawn(command);
+                            debug.update_finished();
+                        }
+
+                        // Exit if requested
+                        if let Some(code) = application.exit_code() {
+                            exit_code = code;
+                            cache = Some(temp_cache);
+
+                            *control_flow = ControlFlow::Exit;
+                            return;
+                        }
+
+                        let subscription = application.subscription();
+                        runtime.track(subscription);
+
+                        // Update window title
+                        let new_title = application.title();
+
+                        if title != new_title {
+                            window.set_title(&new_title);
+
+                            title = new_title;
+                        }
+
+                        // Update window monitor
+                        let new_monitor = application.monitor();
+
+                        if monitor != new_monitor {
+                            if mode == Mode::Windowed {
+                                center_window(&window, new_monitor);
+                            }
+
+                            monitor = new_monitor;
+                        }
+
+                        redraw = application.redraw();
+
+                        // Update window mode
+                        let new_mode = application.mode();
+
+                        if mode != new_mode {
+                            window.set_fullscreen(conversion::fullscreen(
+                                target_monitor(
+                                    window.available_monitors(),
+                                    monitor,
+                                    window.current_monitor(),
+                                ),
+                                new_mode,
+                            ));
+
+                            mode = new_mode;
+                        }
+
+                        // Update cursor grab and visibility
+                        let new_cursor_grabbed = application.cursor_grabbed();
+
+                        if cursor_grabbed != new_cursor_grabbed {
+                            if let Err(error) =
+                                window.set_cursor_grab(new_cursor_grabbed)
+                            {
+                                log::warn!("Could not grab cursor: {}", error);
+                            }
+
+                            cursor_grabbed = new_cursor_grabbed;
+                        }
+
+                        let new_cursor_visible = application.cursor_visible();
+
+                        if cursor_visible != new_cursor_visible {
+                            window.set_cursor_visible(new_cursor_visible);
+
+                            cursor_visible = new_cursor_visible;
+                        }
+
+                        // Update window visibility
+                        if let Some(new_visible) = application.visible() {
+                            if visible != new_visible {
+                                window.set_visible(new_visible);
+
+                                // Hidden windows cannot be minimized in some
+                                // platforms
+                                if new_visible && window_state.is_minimized() {
+                                    window.set_minimized(true);
+                                }
+
+                                visible = new_visible;
+                            }
+                        }
+
+                        // Update window level
+                        let new_always_on_top = application.always_on_top();
+
+                        if always_on_top != new_always_on_top {
+                            window.set_always_on_top(new_always_on_top);
+
+                            always_on_top = new_always_on_top;
+                        }
+
+                        // Update window state
+                        //
+                        // The window is only changed when the application asks
+                        // for a different state, so it does not fight the user
+                        // when the window is minimized or maximized from outside.
+                        let new_minimized = application.minimized();
+                        let new_maximized = application.maximized();
+
+                        if minimized != new_minimized
+                            || maximized != new_maximized
+                        {
+                            if new_minimized != window_state.is_minimized() {
+                                window.set_minimized(new_minimized);
+                            }
+
+                            if new_maximized != window_state.is_maximized() {
+                                window.set_maximized(new_maximized);
+                            }
+
+                            // Not every platform resizes the window when its
+                            // state changes, so we report the change right away
+                            if let Some(event) = window_state
+                                .requested(new_minimized, new_maximized)
+                            {
+                                events.push(Event::Window(event));
+                            }
+
+                            minimized = new_minimized;
+                            maximized = new_maximized;
+                        }
+
+                        // Update taskbar progress
+                        let new_taskbar_progress =
+                            application.taskbar_progress();
+
+                        if taskbar_progress != new_taskbar_progress {
+                            #[cfg(target_os = "windows")]
+                            {
+                                if let Some(taskbar) = &taskbar {
+                                    taskbar.set_progress(
+                                        &window,
+                                        new_taskbar_progress,
+                                    );
+                                }
+                            }
+
+                            #[cfg(all(
+                                feature = "launcher",
+                                any(
+                                    target_os = "linux",
+                                    target_os = "dragonfly",
+                                    target_os = "freebsd",
+                                    target_os = "netbsd",
+                                    target_os = "openbsd"
+                                )
+                            ))]
+                            {
+                                if let Some(launcher) = &launcher {
+                                    launcher.set_progress(new_taskbar_progress);
+                                }
+                            }
+
+                            taskbar_progress = new_taskbar_progress;
+                        }
+
+                        // Update sleep inhibition
+                        let new_inhibit_sleep = application.inhibit_sleep();
+
+                        if inhibit_sleep != new_inhibit_sleep {
+                            sleep_inhibitor.set(&window, new_inhibit_sleep);
+
+                            inhibit_sleep = new_inhibit_sleep;
+                        }
+
+                        let user_interface = build_user_interface(
+                            application,
+                            temp_cache,
+                            &mut renderer,
+                            size.logical(),
+                            &mut debug,
+                        );
+
+                        debug.draw_started();
+                        primitive = user_interface.draw(&mut renderer);
+                        debug.draw_finished();
+
+                        next_redraw = user_interface.next_redraw();
+                        cache = Some(user_interface.into_cache());
                     }
 
-                    let user_interface = build_user_interface(
-                        &mut application,
-                        temp_cache,
-                        &mut renderer,
-                        size.logical(),
-                        &mut debug,
-                    );
+                    window.request_redraw();
+                }
+                event::Event::LoopDestroyed => {
+                    runtime.track(Subscription::none());
 
-                    debug.draw_started();
-                    primitive = user_interface.draw(&mut renderer);
-                    debug.draw_finished();
+                    if inhibit_sleep {
+                        sleep_inhibitor.set(&window, false);
+                    }
 
-                    cache = Some(user_interface.into_cache());
-                }
+                    // Exiting the process skips destructors, so we drop the
+                    // application and the runtime first
+                    running = None;
 
-                window.request_redraw();
-            }
-            event::Event::UserEvent(message) => {
-                external_messages.push(message);
-            }
-            event::Event::RedrawRequested(_) => {
-                debug.render_started();
+                    std::process::exit(exit_code);
+                }
+                event::Event::UserEvent(message) => {
+                    external_messages.push(message);
+                }
+                event::Event::RedrawRequested(_) => {
+                    #[cfg(feature = "trace")]
+                    let span = tracing::info_span!("render");
+                    #[cfg(feature = "trace")]
+                    let _guard = span.enter();
 
-                if resized {
-                    let physical_size = size.physical();
+                    debug.render_started();
 
-                    swap_chain = backend.create_swap_chain(
-                        &surface,
-                        physical_size.width,
-                        physical_size.height,
-                    );
+                    if resized {
+                        let physical_size = size.physical();
 
-                    resized = false;
-                }
+                        swap_chain = backend.create_swap_chain(
+                            &surface,
+                            physical_size.width,
+                            physical_size.height,
+                        );
 
-                let new_mouse_cursor = backend.draw(
-                    &mut renderer,
-                    &mut swap_chain,
-                    &primitive,
-                    size.scale_factor(),
-                    &debug.overlay(),
-                );
+                        resized = false;
+                    }
 
-                debug.render_finished();
+                    let new_mouse_cursor = backend.draw(
+                        &mut renderer,
+                        &mut swap_chain,
+                        &primitive,
+                        size.scale_factor(),
+                        &debug.overlay(),
+                    );
 
-                if new_mouse_cursor != mouse_cursor {
-                    window.set_cursor_icon(conversion::mouse_cursor(
-                        new_mouse_cursor,
-                    ));
+                    debug.render_finished();
+                    debug.record_statistics(backend.statistics(&renderer));
 
-                    mouse_cursor = new_mouse_cursor;
-                }
+                    if new_mouse_cursor != mouse_cursor {
+                        window.set_cursor_icon(conversion::mouse_cursor(
+                            new_mouse_cursor,
+                        ));
 
-                // TODO: Handle animations!
-                // Maybe we can use `ControlFlow::WaitUntil` for this.
-            }
-            event::Event::WindowEvent {
-                event: window_event,
-                ..
-            } => {
-                match window_event {
-                    WindowEvent::Resized(new_size) => {
-                        size = Size::new(new_size, window.scale_factor());
-                        resized = true;
+                        mouse_cursor = new_mouse_cursor;
                     }
-                    WindowEvent::CloseRequested => {
-                        *control_flow = ControlFlow::Exit;
+                }
+                event::Event::RedrawEventsCleared => {
+                    #[cfg(feature = "record")]
+                    let next_redraw = {
+                        let next_replay = replay
+                            .as_ref()
+                            .and_then(|replay| replay.next_instant());
+
+                        match (next_redraw, next_replay) {
+                            (Some(a), Some(b)) => Some(a.min(b)),
+                            (a, b) => a.or(b),
+                        }
+                    };
+
+                    *control_flow = match redraw {
+                        // Events produced while updating must be processed
+                        _ if !events.is_empty() => ControlFlow::Poll,
+                        Redraw::Continuous => ControlFlow::Poll,
+                        Redraw::OnDemand => match next_redraw {
+                            Some(instant) => ControlFlow::WaitUntil(instant),
+                            None => ControlFlow::Wait,
+                        },
+                    };
+                }
+                event::Event::WindowEvent {
+                    event: window_event,
+                    ..
+                } => {
+                    match window_event {
+                        WindowEvent::Resized(new_size) => {
+                            if let Some(event) = window_state.resized(new_size)
+                            {
+                                events.push(Event::Window(event));
+                            }
+
+                            size = Size::new(new_size, window.scale_factor());
+                            resized = true;
+                        }
+                        WindowEvent::CloseRequested => {
+                            *control_flow = ControlFlow::Exit;
+                        }
+                        WindowEvent::Focused(is_focused) => {
+                            focused = is_focused;
+
+                            // Keys released while unfocused are not reported
+                            if !focused {
+                                pressed_keys.clear();
+                            }
+                        }
+                        #[cfg(target_os = "macos")]
+                        WindowEvent::KeyboardInput {
+                            input:
+                                winit::event::KeyboardInput {
+                                    virtual_keycode:
+                                        Some(winit::event::VirtualKeyCode::Q),
+                                    state: winit::event::ElementState::Pressed,
+                                    ..
+                                },
+                            ..
+                        } if modifiers.logo() => {
+                            *control_flow = ControlFlow::Exit;
+                        }
+                        #[cfg(feature = "debug")]
+                        WindowEvent::KeyboardInput {
+                            input:
+                                winit::event::KeyboardInput {
+                                    virtual_keycode:
+                                        Some(winit::event::VirtualKeyCode::F12),
+                                    state: winit::event::ElementState::Pressed,
+                                    ..
+                                },
+                            ..
+                        } => debug.toggle(),
+                        #[cfg(feature = "debug")]
+                        WindowEvent::KeyboardInput {
+                            input:
+                                winit::event::KeyboardInput {
+                                    virtual_keycode:
+                                        Some(winit::event::VirtualKeyCode::I),
+                                    state: winit::event::ElementState::Pressed,
+                                    ..
+                                },
+                            ..
+                        } if modifiers.ctrl() && modifiers.shift() => {
+                            debug.toggle_inspector()
+                        }
+                        _ => {}
                     }
-                    #[cfg(target_os = "macos")]
-                    WindowEvent::KeyboardInput {
-                        input:
-                            winit::event::KeyboardInput {
-                                virtual_keycode:
-                                    Some(winit::event::VirtualKeyCode::Q),
-                                state: winit::event::ElementState::Pressed,
-                                ..
-                            },
-                        ..
-                    } if modifiers.logo() => {
-                        *control_flow = ControlFlow::Exit;
+
+                    if let Some(mut event) = conversion::window_event(
+                        window_event,
+                        size.scale_factor(),
+                        modifiers,
+                    ) {
+                        // Detect key auto-repeat
+                        if let Event::Keyboard(keyboard::Event::Input {
+                            scan_code,
+                            state,
+                            repeat,
+                            ..
+                        }) = &mut event
+                        {
+                            match state {
+                                ButtonState::Pressed => {
+                                    *repeat = !pressed_keys.insert(*scan_code);
+                                }
+                                ButtonState::Released => {
+                                    let _ = pressed_keys.remove(scan_code);
+                                }
+                            }
+                        }
+
+                        events.push(event);
                     }
-                    #[cfg(feature = "debug")]
-                    WindowEvent::KeyboardInput {
-                        input:
-                            winit::event::KeyboardInput {
-                                virtual_keycode:
-                                    Some(winit::event::VirtualKeyCode::F12),
-                                state: winit::event::ElementState::Pressed,
-                                ..
-                            },
-                        ..
-                    } => debug.toggle(),
-                    _ => {}
                 }
-
-                if let Some(event) = conversion::window_event(
-                    window_event,
-                    size.scale_factor(),
-                    modifiers,
-                ) {
-                    events.push(event);
+                event::Event::DeviceEvent {
+                    event: event::DeviceEvent::ModifiersChanged(new_modifiers),
+                    ..
+                } => {
+                    modifiers = new_modifiers;
                 }
-            }
-            event::Event::DeviceEvent {
-                event: event::DeviceEvent::ModifiersChanged(new_modifiers),
-                ..
-            } => {
-                modifiers = new_modifiers;
-            }
-            _ => {
-                *control_flow = ControlFlow::Wait;
+                event::Event::DeviceEvent {
+                    event: device_event,
+                    ..
+                } if focused => {
+                    if let Some(event) = conversion::device_event(device_event)
+                    {
+                        events.push(event);
+                    }
+                }
+                _ => {}
             }
         })
     }
 }
 
+fn target_monitor(
+    mut available_monitors: impl Iterator<Item = winit::monitor::MonitorHandle>,
+    index: Option<usize>,
+    fallback: winit::monitor::MonitorHandle,
+) -> winit::monitor::MonitorHandle {
+    index
+        .and_then(|index| available_monitors.nth(index))
+        .unwrap_or(fallback)
+}
+
+fn center_window(window: &winit::window::Window, index: Option<usize>) {
+    let monitor = target_monitor(
+        window.available_monitors(),
+        index,
+        window.current_monitor(),
+    );
+
+    let monitor_position = monitor.position();
+    let monitor_size = monitor.size();
+    let window_size = window.outer_size();
+
+    window.set_outer_position(winit::dpi::PhysicalPosition {
+        x: monitor_position.x
+            + (monitor_size.width as i32 - window_size.width as i32) / 2,
+        y: monitor_position.y
+            + (monitor_size.height as i32 - window_size.height as i32) / 2,
+    });
+}
+
 fn build_user_interface<'a, A: Application>(
     application: &'a mut A,
     cache: Cache,
@@ -403,9 +1095,23 @@ fn build_user_interface<'a, A: Application>(
     debug: &mut Debug,
 ) -> UserInterface<'a, A::Message, <A::Backend as window::Backend>::Renderer> {
     debug.view_started();
-    let view = application.view();
+    let view = {
+        #[cfg(feature = "trace")]
+        let span = tracing::info_span!("view");
+        #[cfg(feature = "trace")]
+        let _guard = span.enter();
+
+        application.view()
+    };
     debug.view_finished();
 
+    #[cfg(feature = "debug")]
+    let view = if debug.is_inspecting() {
+        <A::Backend as window::Backend>::inspect(view)
+    } else {
+        view
+    };
+
     debug.layout_started();
     let user_interface = UserInterface::build(
         view,
@@ -11,6 +11,11 @@ use crate::{
 };
 
 /// Converts a winit window event into an iced event.
+///
+/// Keyboard events are never marked as repeated, as [`winit`] does not report
+/// auto-repeat. Shells need to keep track of the pressed keys to detect it.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
 pub fn window_event(
     event: winit::event::WindowEvent<'_>,
     scale_factor: f64,
@@ -27,6 +32,11 @@ pub fn window_event(
                 height: logical_size.height,
             }))
         }
+        WindowEvent::Focused(focused) => Some(Event::Window(if focused {
+            window::Event::Focused
+        } else {
+            window::Event::Unfocused
+        })),
         WindowEvent::CursorMoved { position, .. } => {
             let position = position.to_logical::<f64>(scale_factor);
 
@@ -39,6 +49,7 @@ pub fn window_event(
             Some(Event::Mouse(mouse::Event::Input {
                 button: mouse_button(button),
                 state: button_state(state),
+                modifiers: modifiers_state(modifiers),
             }))
         }
         WindowEvent::MouseWheel { delta, .. } => match delta {
@@ -66,12 +77,15 @@ pub fn window_event(
             input:
                 winit::event::KeyboardInput {
                     virtual_keycode: Some(virtual_keycode),
+                    scancode,
                     state,
                     ..
                 },
             ..
         } => Some(Event::Keyboard(keyboard::Event::Input {
             key_code: key_code(virtual_keycode),
+            scan_code: scancode,
+            repeat: false,
             state: button_state(state),
             modifiers: modifiers_state(modifiers),
         })),
@@ -88,6 +102,21 @@ pub fn window_event(
     }
 }
 
+/// Converts a winit device event into an iced event.
+pub fn device_event(event: winit::event::DeviceEvent) -> Option<Event> {
+    use winit::event::DeviceEvent;
+
+    match event {
+        DeviceEvent::MouseMotion { delta: (x, y) } => {
+            Some(Event::Mouse(mouse::Event::Motion {
+                delta_x: x as f32,
+                delta_y: y as f32,
+            }))
+        }
+        _ => None,
+    }
+}
+
 /// Converts a [`Mode`] to a [`winit`] fullscreen mode.
 ///
 /// [`Mode`]:
@@ -103,6 +132,27 @@ pub fn fullscreen(
     }
 }
 
+/// Converts a [`winit`] monitor handle to an [`iced_native`] monitor.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn monitor(monitor: &winit::monitor::MonitorHandle) -> window::Monitor {
+    let size = monitor.size();
+    let position = monitor.position();
+
+    window::Monitor {
+        name: monitor.name(),
+        size: (size.width, size.height),
+        position: (position.x, position.y),
+        scale_factor: monitor.scale_factor(),
+        refresh_rate: monitor
+            .video_modes()
+            .filter(|mode| mode.size() == size)
+            .map(|mode| mode.refresh_rate())
+            .max(),
+    }
+}
+
 /// Converts a `MouseCursor` from [`iced_native`] to a [`winit`] cursor icon.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
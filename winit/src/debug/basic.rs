@@ -1,8 +1,15 @@
+use crate::window;
+
 use std::{collections::VecDeque, time};
 
+/// The longest interval between two frames that still counts as continuous
+/// redrawing. Longer gaps mean the application was idle and reset the FPS.
+const IDLE_THRESHOLD: time::Duration = time::Duration::from_millis(250);
+
 #[derive(Debug)]
 pub struct Debug {
     is_enabled: bool,
+    is_inspecting: bool,
 
     startup_start: time::Instant,
     startup_duration: time::Duration,
@@ -25,6 +32,11 @@ pub struct Debug {
     render_start: time::Instant,
     render_durations: TimeBuffer,
 
+    last_frame: Option<time::Instant>,
+    frame_durations: TimeBuffer,
+
+    statistics: window::Statistics,
+
     message_count: usize,
     last_messages: VecDeque<String>,
 }
@@ -35,6 +47,7 @@ impl Debug {
 
         Self {
             is_enabled: false,
+            is_inspecting: false,
             startup_start: now,
             startup_duration: time::Duration::from_secs(0),
 
@@ -56,6 +69,11 @@ impl Debug {
             render_start: now,
             render_durations: TimeBuffer::new(50),
 
+            last_frame: None,
+            frame_durations: TimeBuffer::new(120),
+
+            statistics: window::Statistics::default(),
+
             message_count: 0,
             last_messages: VecDeque::new(),
         }
@@ -65,6 +83,14 @@ impl Debug {
         self.is_enabled = !self.is_enabled;
     }
 
+    pub fn toggle_inspector(&mut self) {
+        self.is_inspecting = !self.is_inspecting;
+    }
+
+    pub fn is_inspecting(&self) -> bool {
+        self.is_inspecting
+    }
+
     pub fn startup_started(&mut self) {
         self.startup_start = time::Instant::now();
     }
@@ -119,7 +145,20 @@ impl Debug {
     }
 
     pub fn render_started(&mut self) {
-        self.render_start = time::Instant::now();
+        let now = time::Instant::now();
+
+        if let Some(last_frame) = self.last_frame {
+            let interval = now - last_frame;
+
+            if interval < IDLE_THRESHOLD {
+                self.frame_durations.push(interval);
+            } else {
+                self.frame_durations.clear();
+            }
+        }
+
+        self.last_frame = Some(now);
+        self.render_start = now;
     }
 
     pub fn render_finished(&mut self) {
@@ -127,6 +166,10 @@ impl Debug {
             .push(time::Instant::now() - self.render_start);
     }
 
+    pub fn record_statistics(&mut self, statistics: window::Statistics) {
+        self.statistics = statistics;
+    }
+
     pub fn log_message<Message: std::fmt::Debug>(&mut self, message: &Message) {
         self.last_messages.push_back(format!("{:?}", message));
 
@@ -137,9 +180,9 @@ impl Debug {
         self.message_count += 1;
     }
 
-    pub fn overlay(&self) -> Vec<String> {
+    pub fn overlay(&self) -> window::Overlay {
         if !self.is_enabled {
-            return Vec::new();
+            return window::Overlay::default();
         }
 
         let mut lines = Vec::new();
@@ -154,6 +197,11 @@ impl Debug {
             env!("CARGO_PKG_VERSION"),
             env!("CARGO_PKG_REPOSITORY"),
         ));
+        lines.push(if self.frame_durations.is_empty() {
+            String::from("FPS: idle")
+        } else {
+            format!("FPS: {:.1}", self.frame_durations.rate())
+        });
         lines.push(key_value("Startup:", self.startup_duration));
         lines.push(key_value("Update:", self.update_durations.average()));
         lines.push(key_value("View:", self.view_durations.average()));
@@ -167,13 +215,41 @@ impl Debug {
             self.draw_durations.average(),
         ));
         lines.push(key_value("Render:", self.render_durations.average()));
+        lines.push(format!(
+            "Primitives: {}",
+            self.statistics
+                .primitives
+                .iter()
+                .map(|(name, amount)| format!("{} {}", name, amount))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+        lines.push(key_value("Draw calls:", self.statistics.draw_calls));
+        lines.push(format!(
+            "Buffers: {}",
+            self.statistics
+                .buffers
+                .iter()
+                .map(|(name, size)| {
+                    format!("{} {:.1} KiB", name, *size as f64 / 1024.0)
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
         lines.push(key_value("Message count:", self.message_count));
         lines.push(String::from("Last messages:"));
         lines.extend(
             self.last_messages.iter().map(|msg| format!("    {}", msg)),
         );
 
-        lines
+        window::Overlay {
+            lines,
+            graph: self
+                .frame_durations
+                .iter()
+                .map(|duration| duration.as_secs_f32() * 1000.0)
+                .collect(),
+        }
     }
 }
 
@@ -199,6 +275,19 @@ impl TimeBuffer {
         self.size = (self.size + 1).min(self.contents.len());
     }
 
+    fn clear(&mut self) {
+        self.head = 0;
+        self.size = 0;
+
+        for duration in self.contents.iter_mut() {
+            *duration = time::Duration::from_secs(0);
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
     fn average(&self) -> time::Duration {
         let sum: time::Duration = if self.size == self.contents.len() {
             self.contents[..].iter().sum()
@@ -208,4 +297,25 @@ impl TimeBuffer {
 
         sum / self.size.max(1) as u32
     }
+
+    fn rate(&self) -> f64 {
+        let average = self.average().as_secs_f64();
+
+        if average > 0.0 {
+            1.0 / average
+        } else {
+            0.0
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = time::Duration> + '_ {
+        let oldest = if self.size == self.contents.len() {
+            self.head + 1
+        } else {
+            1
+        };
+
+        (0..self.size)
+            .map(move |i| self.contents[(oldest + i) % self.contents.len()])
+    }
 }
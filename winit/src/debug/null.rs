@@ -1,3 +1,5 @@
+use crate::window;
+
 #[derive(Debug)]
 pub struct Debug;
 
@@ -40,7 +42,9 @@ impl Debug {
     ) {
     }
 
-    pub fn overlay(&self) -> Vec<String> {
-        Vec::new()
+    pub fn record_statistics(&mut self, _statistics: window::Statistics) {}
+
+    pub fn overlay(&self) -> window::Overlay {
+        window::Overlay::default()
     }
 }
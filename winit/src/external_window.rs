@@ -0,0 +1,48 @@
+#![allow(unsafe_code)]
+use crate::window::{HasRawWindowHandle, RawWindowHandle};
+
+/// A native surface owned by another toolkit, like a child window of a Qt,
+/// Win32, or Cocoa application, or the editor view of an audio plugin.
+///
+/// It can be given to a [`window::Backend`] to create a surface, letting iced
+/// render into it. In this mode, the host application owns the event loop and
+/// is responsible for feeding events to a [`program::State`].
+///
+/// The helpers in the [`conversion`] module only convert `winit` events.
+/// Hosts built on other toolkits, like Qt or Win32, must construct an
+/// [`Event`] from their own input events instead. Take a look at
+/// [`program::State::queue_event`] for an example.
+///
+/// On Windows, an iced window can also be created as a child of an existing
+/// one by setting the `parent` field of the platform specific [`Settings`].
+///
+/// [`window::Backend`]: window/trait.Backend.html
+/// [`program::State`]: program/struct.State.html
+/// [`conversion`]: conversion/index.html
+/// [`Event`]: enum.Event.html
+/// [`program::State::queue_event`]: program/struct.State.html#method.queue_event
+/// [`Settings`]: settings/struct.PlatformSpecific.html
+#[derive(Debug, Clone, Copy)]
+pub struct ExternalWindow {
+    handle: RawWindowHandle,
+}
+
+impl ExternalWindow {
+    /// Creates a new [`ExternalWindow`] from the given [`RawWindowHandle`].
+    ///
+    /// # Safety
+    /// The handle must point to a valid native window or view, and it must
+    /// outlive any surface created from the [`ExternalWindow`].
+    ///
+    /// [`ExternalWindow`]: struct.ExternalWindow.html
+    /// [`RawWindowHandle`]: window/enum.RawWindowHandle.html
+    pub unsafe fn new(handle: RawWindowHandle) -> Self {
+        ExternalWindow { handle }
+    }
+}
+
+unsafe impl HasRawWindowHandle for ExternalWindow {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        self.handle
+    }
+}
@@ -0,0 +1,98 @@
+#![cfg(all(
+    feature = "launcher",
+    any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    )
+))]
+use crate::TaskbarProgress;
+
+use std::collections::HashMap;
+use zvariant::Value;
+
+const INTERFACE: &str = "com.canonical.Unity.LauncherEntry";
+
+/// The launcher entry of the application, as described by the Unity launcher
+/// API.
+///
+/// It is supported by the docks of most desktop environments, as long as the
+/// application installs a `.desktop` file named after its application id.
+pub struct Launcher {
+    connection: zbus::Connection,
+    app_uri: String,
+    path: String,
+}
+
+impl Launcher {
+    pub fn new(application_id: Option<&str>) -> Option<Launcher> {
+        let application_id = application_id?;
+
+        let connection = match zbus::Connection::new_session() {
+            Ok(connection) => connection,
+            Err(error) => {
+                log::warn!("Could not connect to the session bus: {}", error);
+
+                return None;
+            }
+        };
+
+        Some(Launcher {
+            connection,
+            app_uri: format!("application://{}.desktop", application_id),
+            path: format!(
+                "/com/canonical/unity/launcherentry/{}",
+                object_path_id(application_id)
+            ),
+        })
+    }
+
+    pub fn set_progress(&self, progress: Option<TaskbarProgress>) {
+        let (value, urgent) = match progress {
+            None | Some(TaskbarProgress::Indeterminate) => (None, false),
+            Some(TaskbarProgress::Normal(value))
+            | Some(TaskbarProgress::Paused(value)) => (Some(value), false),
+            Some(TaskbarProgress::Error(value)) => (Some(value), true),
+        };
+
+        let mut properties: HashMap<&str, Value<'_>> = HashMap::new();
+
+        let _ =
+            properties.insert("progress-visible", Value::from(value.is_some()));
+        let _ = properties.insert(
+            "progress",
+            Value::from(f64::from(value.unwrap_or(0.0).max(0.0).min(1.0))),
+        );
+        let _ = properties.insert("urgent", Value::from(urgent));
+
+        let result = self.connection.emit_signal(
+            None,
+            &self.path,
+            INTERFACE,
+            "Update",
+            &(self.app_uri.as_str(), properties),
+        );
+
+        if let Err(error) = result {
+            log::warn!("Could not update launcher entry: {}", error);
+        }
+    }
+}
+
+impl std::fmt::Debug for Launcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Launcher")
+            .field("app_uri", &self.app_uri)
+            .finish()
+    }
+}
+
+/// Turns the application id into a valid element of a D-Bus object path.
+fn object_path_id(application_id: &str) -> String {
+    application_id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
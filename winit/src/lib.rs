@@ -18,7 +18,7 @@
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
-#![forbid(unsafe_code)]
+#![deny(unsafe_code)]
 #![forbid(rust_2018_idioms)]
 
 #[doc(no_inline)]
@@ -28,11 +28,23 @@ pub use winit;
 pub mod conversion;
 pub mod settings;
 
+#[cfg(feature = "record")]
+pub mod recording;
+
 mod application;
 mod clipboard;
+mod external_window;
+mod launcher;
 mod mode;
 mod proxy;
+mod redraw;
 mod size;
+mod sleep;
+mod taskbar_progress;
+mod window_state;
+
+#[cfg(target_os = "windows")]
+mod taskbar;
 
 // We disable debug capabilities on release builds unless the `debug` feature
 // is explicitly enabled.
@@ -45,8 +57,11 @@ mod debug;
 
 pub use application::Application;
 pub use clipboard::Clipboard;
+pub use external_window::ExternalWindow;
 pub use mode::Mode;
+pub use redraw::Redraw;
 pub use settings::Settings;
+pub use taskbar_progress::TaskbarProgress;
 
 use debug::Debug;
 use proxy::Proxy;
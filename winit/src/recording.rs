@@ -0,0 +1,255 @@
+//! Record the events of an application and replay them later.
+//!
+//! When the `record` feature is enabled, an [`Application`] will:
+//!
+//! - record every incoming event to the file at the path stored in the
+//!   `ICED_RECORD` environment variable, if present.
+//! - replay the events stored in the file at the path stored in the
+//!   `ICED_REPLAY` environment variable, if present.
+//!
+//! Recordings are stored as one JSON object per line, containing the event
+//! and the time it was received, in microseconds since startup.
+//!
+//! Replayed events do not change the actual window. Therefore, a recording
+//! should be replayed using the same window size it was recorded with.
+//!
+//! While replaying, the application runs with a manual [`Clock`] that only
+//! moves forward to the time of the replayed events and to the redraws
+//! requested by the application. This way, animations and time-based
+//! subscriptions observe the same times on every replay.
+//!
+//! [`Application`]: ../trait.Application.html
+//! [`Clock`]: ../../iced_native/time/struct.Clock.html
+use crate::{time::Clock, Event};
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// The environment variable containing the path where events are recorded.
+pub const RECORD_VARIABLE: &str = "ICED_RECORD";
+
+/// The environment variable containing the path of the events to replay.
+pub const REPLAY_VARIABLE: &str = "ICED_REPLAY";
+
+#[derive(Serialize, Deserialize)]
+struct Entry<E> {
+    time: u64,
+    event: E,
+}
+
+/// A writer of timestamped events.
+#[derive(Debug)]
+pub struct Recorder {
+    writer: BufWriter<File>,
+    start: Instant,
+}
+
+impl Recorder {
+    /// Creates a new [`Recorder`] that writes to the file at the given path,
+    /// truncating it if it already exists.
+    ///
+    /// Events are timestamped relative to the moment the [`Recorder`] is
+    /// created.
+    ///
+    /// [`Recorder`]: struct.Recorder.html
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Recorder {
+            writer: BufWriter::new(File::create(path)?),
+            start: Instant::now(),
+        })
+    }
+
+    /// Records the given events as received now.
+    pub fn record(&mut self, events: &[Event]) -> io::Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let time = self.start.elapsed().as_micros() as u64;
+
+        for event in events {
+            serde_json::to_writer(&mut self.writer, &Entry { time, event })?;
+            self.writer.write_all(b"\n")?;
+        }
+
+        self.writer.flush()
+    }
+}
+
+/// A reader of timestamped events that yields them as time passes.
+#[derive(Debug)]
+pub struct Replay {
+    entries: VecDeque<(Duration, Event)>,
+    start: Instant,
+    clock: Clock,
+}
+
+impl Replay {
+    /// Loads the events recorded in the file at the given path.
+    ///
+    /// The clock of the [`Replay`] starts when it is loaded.
+    ///
+    /// [`Replay`]: struct.Replay.html
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut entries = VecDeque::new();
+
+        for line in reader.lines() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let entry: Entry<Event> = serde_json::from_str(&line)?;
+
+            entries.push_back((Duration::from_micros(entry.time), entry.event));
+        }
+
+        let start = Instant::now();
+
+        Ok(Replay {
+            entries,
+            start,
+            clock: Clock::manual(start),
+        })
+    }
+
+    /// Returns the manual [`Clock`] of the [`Replay`].
+    ///
+    /// It should be installed as the clock of the thread running the
+    /// application.
+    ///
+    /// [`Clock`]: ../../iced_native/time/struct.Clock.html
+    /// [`Replay`]: struct.Replay.html
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Returns true if all the events have been replayed.
+    pub fn is_finished(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the [`Instant`] at which the next event is due, if any.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    pub fn next_instant(&self) -> Option<Instant> {
+        self.entries.front().map(|(time, _)| self.start + *time)
+    }
+
+    /// Removes the events that are due and appends them to the given list.
+    ///
+    /// The [`Clock`] of the [`Replay`] is moved forward to the time of the
+    /// last removed event.
+    ///
+    /// [`Clock`]: ../../iced_native/time/struct.Clock.html
+    /// [`Replay`]: struct.Replay.html
+    pub fn drain_due(&mut self, events: &mut Vec<Event>) {
+        let elapsed = self.start.elapsed();
+
+        while let Some((time, _)) = self.entries.front() {
+            if *time > elapsed {
+                break;
+            }
+
+            if let Some((time, event)) = self.entries.pop_front() {
+                self.clock.advance_to(self.start + time);
+
+                events.push(event);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{keyboard, mouse, ButtonState};
+    use crate::window;
+
+    fn events() -> Vec<Event> {
+        vec![
+            Event::Keyboard(keyboard::Event::Input {
+                state: ButtonState::Pressed,
+                key_code: keyboard::KeyCode::A,
+                scan_code: 30,
+                repeat: false,
+                modifiers: keyboard::ModifiersState {
+                    shift: true,
+                    ..Default::default()
+                },
+            }),
+            Event::Keyboard(keyboard::Event::CharacterReceived('ñ')),
+            Event::Mouse(mouse::Event::CursorMoved { x: 12.5, y: -3.0 }),
+            Event::Mouse(mouse::Event::Input {
+                state: ButtonState::Released,
+                button: mouse::Button::Other(8),
+                modifiers: keyboard::ModifiersState::default(),
+            }),
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Pixels { x: 0.0, y: 42.0 },
+            }),
+            Event::Window(window::Event::Resized {
+                width: 1024,
+                height: 768,
+            }),
+            Event::Window(window::Event::MonitorsDetected(vec![
+                window::Monitor {
+                    name: Some(String::from("Primary")),
+                    size: (1920, 1080),
+                    position: (0, 0),
+                    scale_factor: 1.5,
+                    refresh_rate: None,
+                },
+            ])),
+            Event::Window(window::Event::FileDropped("a/b.txt".into())),
+        ]
+    }
+
+    #[test]
+    fn event_round_trip() {
+        for event in events() {
+            let json = serde_json::to_string(&Entry {
+                time: 7,
+                event: &event,
+            })
+            .expect("Serialize entry");
+
+            let entry: Entry<Event> =
+                serde_json::from_str(&json).expect("Deserialize entry");
+
+            assert_eq!(entry.time, 7);
+            assert_eq!(entry.event, event);
+        }
+    }
+
+    #[test]
+    fn recording_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "iced_recording_round_trip_{}.jsonl",
+            std::process::id()
+        ));
+
+        let mut recorder = Recorder::create(&path).expect("Create recorder");
+        recorder.record(&events()).expect("Record events");
+        drop(recorder);
+
+        let replay = Replay::load(&path).expect("Load replay");
+        let _ = std::fs::remove_file(&path);
+
+        let replayed: Vec<Event> =
+            replay.entries.into_iter().map(|(_, event)| event).collect();
+
+        assert_eq!(replayed, events());
+    }
+
+    #[test]
+    fn missing_recording() {
+        assert!(Replay::load("/nonexistent/iced/recording.jsonl").is_err());
+    }
+}
@@ -0,0 +1,24 @@
+#![cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+//! Platform specific settings for Linux and BSD.
+
+/// The platform specific window settings of an application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PlatformSpecific {
+    /// The application id of the window on Wayland.
+    ///
+    /// Desktop environments use it to find the `.desktop` file, icon, and
+    /// window rules of the application.
+    pub application_id: Option<&'static str>,
+
+    /// The `WM_CLASS` of the window on X11, as a `(class, instance)` pair.
+    ///
+    /// Window managers use it to group windows in the taskbar and apply
+    /// window rules.
+    pub class: Option<(&'static str, &'static str)>,
+}
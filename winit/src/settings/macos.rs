@@ -0,0 +1,18 @@
+#![cfg(target_os = "macos")]
+//! Platform specific settings for macOS.
+
+/// The platform specific window settings of an application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PlatformSpecific {
+    /// Whether the title of the window should be hidden.
+    pub title_hidden: bool,
+
+    /// Whether the title bar of the window should be transparent.
+    pub titlebar_transparent: bool,
+
+    /// Whether the content of the window should extend below the title bar.
+    ///
+    /// Combined with a transparent title bar, this lets an application draw
+    /// a unified toolbar under the traffic-light buttons.
+    pub fullsize_content_view: bool,
+}
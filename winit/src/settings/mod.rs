@@ -2,8 +2,28 @@
 #[cfg(target_os = "windows")]
 #[path = "windows.rs"]
 mod platform;
-#[cfg(not(target_os = "windows"))]
-#[path = "not_windows.rs"]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+#[path = "linux.rs"]
+mod platform;
+#[cfg(target_os = "macos")]
+#[path = "macos.rs"]
+mod platform;
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+#[path = "other.rs"]
 mod platform;
 
 pub use platform::PlatformSpecific;
@@ -37,6 +57,15 @@ pub struct Window {
     /// Whether the window should have a border, a title bar, etc.
     pub decorations: bool,
 
+    /// Whether the window should be visible when created.
+    ///
+    /// A hidden window will be shown as soon as [`Application::visible`]
+    /// returns `Some(true)`. This allows an application to finish
+    /// loading before showing its window.
+    ///
+    /// [`Application::visible`]: ../trait.Application.html#method.visible
+    pub visible: bool,
+
     /// Platform specific settings.
     pub platform_specific: platform::PlatformSpecific,
 }
@@ -47,6 +76,7 @@ impl Default for Window {
             size: (1024, 768),
             resizable: true,
             decorations: true,
+            visible: true,
             platform_specific: Default::default(),
         }
     }
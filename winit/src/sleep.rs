@@ -0,0 +1,130 @@
+//! Prevent the display from going to sleep.
+//!
+//! Every platform uses a different mechanism:
+//!
+//! - On Windows, the execution state of the event loop thread is changed.
+//! - On macOS, a `caffeinate` process is kept alive.
+//! - On Linux and BSD, `xdg-screensaver` suspends the screensaver for the
+//!   window. Only X11 windows are supported.
+
+/// Keeps track of the sleep inhibition of a window.
+#[derive(Debug, Default)]
+pub struct Inhibitor {
+    #[cfg(target_os = "macos")]
+    caffeinate: Option<std::process::Child>,
+}
+
+impl Inhibitor {
+    /// Inhibits or allows the display from sleeping while the given window
+    /// is open.
+    pub fn set(&mut self, window: &winit::window::Window, inhibit: bool) {
+        #[cfg(target_os = "windows")]
+        {
+            let _ = window;
+
+            set_thread_execution_state(inhibit);
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            let _ = window;
+
+            if inhibit {
+                if self.caffeinate.is_none() {
+                    self.caffeinate = std::process::Command::new("caffeinate")
+                        .args(&["-d", "-i", "-w"])
+                        .arg(std::process::id().to_string())
+                        .spawn()
+                        .map_err(|error| {
+                            log::warn!("Could not inhibit sleep: {}", error)
+                        })
+                        .ok();
+                }
+            } else if let Some(mut caffeinate) = self.caffeinate.take() {
+                let _ = caffeinate.kill();
+                let _ = caffeinate.wait();
+            }
+        }
+
+        #[cfg(any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
+        {
+            use crate::window::{HasRawWindowHandle, RawWindowHandle};
+
+            match window.raw_window_handle() {
+                RawWindowHandle::Xlib(handle) => {
+                    let result = std::process::Command::new("xdg-screensaver")
+                        .arg(if inhibit { "suspend" } else { "resume" })
+                        .arg(handle.window.to_string())
+                        .spawn();
+
+                    match result {
+                        Ok(child) => reap(child),
+                        Err(error) => {
+                            log::warn!("Could not inhibit sleep: {}", error);
+                        }
+                    }
+                }
+                _ => {
+                    log::warn!("Sleep inhibition is only supported on X11");
+                }
+            }
+        }
+
+        #[cfg(not(any(
+            target_os = "windows",
+            target_os = "macos",
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        )))]
+        {
+            let _ = (window, inhibit);
+        }
+    }
+}
+
+/// Waits for `xdg-screensaver` to exit in a separate thread, so the event
+/// loop is not blocked.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn reap(mut child: std::process::Child) {
+    let _ = std::thread::spawn(move || match child.wait() {
+        Ok(status) if !status.success() => {
+            log::warn!("Could not inhibit sleep: xdg-screensaver {}", status);
+        }
+        Ok(_) => {}
+        Err(error) => {
+            log::warn!("Could not inhibit sleep: {}", error);
+        }
+    });
+}
+
+#[cfg(target_os = "windows")]
+#[allow(unsafe_code)]
+fn set_thread_execution_state(inhibit: bool) {
+    use winapi::um::{
+        winbase::SetThreadExecutionState,
+        winnt::{ES_CONTINUOUS, ES_DISPLAY_REQUIRED, ES_SYSTEM_REQUIRED},
+    };
+
+    let flags = if inhibit {
+        ES_CONTINUOUS | ES_DISPLAY_REQUIRED | ES_SYSTEM_REQUIRED
+    } else {
+        ES_CONTINUOUS
+    };
+
+    let _ = unsafe { SetThreadExecutionState(flags) };
+}
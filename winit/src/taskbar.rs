@@ -0,0 +1,93 @@
+#![cfg(target_os = "windows")]
+#![allow(unsafe_code)]
+use crate::TaskbarProgress;
+
+use winapi::{
+    shared::{windef::HWND, winerror::SUCCEEDED},
+    um::{
+        combaseapi::{CoCreateInstance, CLSCTX_ALL},
+        shobjidl_core::{
+            CLSID_TaskbarList, ITaskbarList3, TBPF_ERROR, TBPF_INDETERMINATE,
+            TBPF_NOPROGRESS, TBPF_NORMAL, TBPF_PAUSED,
+        },
+    },
+    Interface,
+};
+
+/// The total amount of steps reported to the taskbar.
+const TOTAL: u64 = 1000;
+
+pub struct Taskbar {
+    raw: *mut ITaskbarList3,
+}
+
+impl Taskbar {
+    pub fn new() -> Option<Taskbar> {
+        let mut raw: *mut ITaskbarList3 = std::ptr::null_mut();
+
+        let result = unsafe {
+            CoCreateInstance(
+                &CLSID_TaskbarList,
+                std::ptr::null_mut(),
+                CLSCTX_ALL,
+                &ITaskbarList3::uuidof(),
+                &mut raw as *mut *mut ITaskbarList3 as *mut _,
+            )
+        };
+
+        if !SUCCEEDED(result) || raw.is_null() {
+            log::warn!("Could not create taskbar list: {:#x}", result);
+
+            return None;
+        }
+
+        if !SUCCEEDED(unsafe { (*raw).HrInit() }) {
+            let _ = unsafe { (*raw).Release() };
+
+            return None;
+        }
+
+        Some(Taskbar { raw })
+    }
+
+    pub fn set_progress(
+        &self,
+        window: &winit::window::Window,
+        progress: Option<TaskbarProgress>,
+    ) {
+        use winit::platform::windows::WindowExtWindows;
+
+        let hwnd = window.hwnd() as HWND;
+
+        let (state, value) = match progress {
+            None => (TBPF_NOPROGRESS, None),
+            Some(TaskbarProgress::Indeterminate) => (TBPF_INDETERMINATE, None),
+            Some(TaskbarProgress::Normal(value)) => (TBPF_NORMAL, Some(value)),
+            Some(TaskbarProgress::Paused(value)) => (TBPF_PAUSED, Some(value)),
+            Some(TaskbarProgress::Error(value)) => (TBPF_ERROR, Some(value)),
+        };
+
+        unsafe {
+            let _ = (*self.raw).SetProgressState(hwnd, state);
+
+            if let Some(value) = value {
+                let completed =
+                    (value.max(0.0).min(1.0) * TOTAL as f32).round() as u64;
+
+                let _ = (*self.raw).SetProgressValue(hwnd, completed, TOTAL);
+            }
+        }
+    }
+}
+
+impl Drop for Taskbar {
+    fn drop(&mut self) {
+        let _ = unsafe { (*self.raw).Release() };
+    }
+}
+
+impl std::fmt::Debug for Taskbar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Taskbar").finish()
+    }
+}
@@ -0,0 +1,17 @@
+/// The progress of a long-running operation, shown in the taskbar icon of
+/// the application.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TaskbarProgress {
+    /// The operation is running, but its progress is unknown.
+    Indeterminate,
+
+    /// The operation is running and has completed the given fraction of its
+    /// work, from `0.0` to `1.0`.
+    Normal(f32),
+
+    /// The operation is paused at the given fraction of its work.
+    Paused(f32),
+
+    /// The operation has failed at the given fraction of its work.
+    Error(f32),
+}
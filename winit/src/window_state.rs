@@ -0,0 +1,139 @@
+use iced_native::window;
+
+/// The minimized and maximized state of a window.
+///
+/// winit does not report when the user minimizes or maximizes a window.
+/// Therefore, minimization is derived from the size of the window every time
+/// it is resized. There is no reliable way to tell a maximized window apart
+/// from a large one, so maximization only follows the requests of the
+/// application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowState {
+    minimized: bool,
+    maximized: bool,
+}
+
+impl WindowState {
+    pub fn new(minimized: bool, maximized: bool) -> Self {
+        WindowState {
+            minimized,
+            maximized,
+        }
+    }
+
+    pub fn is_minimized(&self) -> bool {
+        self.minimized
+    }
+
+    pub fn is_maximized(&self) -> bool {
+        self.maximized
+    }
+
+    /// Derives the state of the window after it was resized to the given
+    /// size, returning the event describing the change, if any.
+    pub fn resized(
+        &mut self,
+        new_size: winit::dpi::PhysicalSize<u32>,
+    ) -> Option<window::Event> {
+        // Some platforms report minimized windows as having a size of zero
+        let minimized = new_size.width == 0 && new_size.height == 0;
+
+        self.transition(minimized, self.maximized)
+    }
+
+    /// Records the state requested by the application, returning the event
+    /// describing the change, if any.
+    pub fn requested(
+        &mut self,
+        minimized: bool,
+        maximized: bool,
+    ) -> Option<window::Event> {
+        self.transition(minimized, maximized)
+    }
+
+    fn transition(
+        &mut self,
+        minimized: bool,
+        maximized: bool,
+    ) -> Option<window::Event> {
+        let previous = *self;
+
+        self.minimized = minimized;
+        self.maximized = maximized;
+
+        if *self == previous {
+            None
+        } else if minimized {
+            // Maximizing or restoring a minimized window is only noticeable
+            // once it is not minimized anymore
+            if previous.minimized {
+                None
+            } else {
+                Some(window::Event::Minimized)
+            }
+        } else if maximized {
+            Some(window::Event::Maximized)
+        } else {
+            Some(window::Event::Restored)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winit::dpi::PhysicalSize;
+
+    #[test]
+    fn resizing_never_maximizes() {
+        let mut state = WindowState::new(false, false);
+
+        assert_eq!(state.resized(PhysicalSize::new(3840, 2160)), None);
+        assert!(!state.is_maximized());
+    }
+
+    #[test]
+    fn resizing_to_zero_minimizes() {
+        let mut state = WindowState::new(false, false);
+
+        assert_eq!(
+            state.resized(PhysicalSize::new(0, 0)),
+            Some(window::Event::Minimized)
+        );
+        assert_eq!(state.resized(PhysicalSize::new(0, 0)), None);
+        assert_eq!(
+            state.resized(PhysicalSize::new(800, 600)),
+            Some(window::Event::Restored)
+        );
+    }
+
+    #[test]
+    fn restoring_keeps_requested_maximization() {
+        let mut state = WindowState::new(false, false);
+
+        assert_eq!(
+            state.requested(false, true),
+            Some(window::Event::Maximized)
+        );
+        assert_eq!(
+            state.resized(PhysicalSize::new(0, 0)),
+            Some(window::Event::Minimized)
+        );
+        assert_eq!(
+            state.resized(PhysicalSize::new(1920, 1080)),
+            Some(window::Event::Maximized)
+        );
+    }
+
+    #[test]
+    fn requests_while_minimized_are_silent() {
+        let mut state = WindowState::new(true, false);
+
+        assert_eq!(state.requested(true, true), None);
+        assert!(state.is_maximized());
+        assert_eq!(
+            state.requested(false, true),
+            Some(window::Event::Maximized)
+        );
+    }
+}